@@ -0,0 +1,61 @@
+// Compares the interpreter with translated code on the long-running part of day 9
+//
+// Run with `cargo bench -p intcode`. Each program is run several times, and the fastest run is
+// reported. When the translator was added, the interpreter took about 19ms and the translated
+// code about 2.4ms, an 8x speedup. Branching directly between blocks made no measurable difference
+// here, as day 9 spends most of its time returning from recursive calls through computed jumps.
+
+use {
+    intcode::{translate::CompiledProgram, Program},
+    std::{
+        hint::black_box,
+        time::{Duration, Instant},
+    },
+};
+
+mod boost {
+    include!("../tests/translated/9.rs");
+}
+
+const RUNS: usize = 10;
+
+fn fastest(mut run: impl FnMut() -> Vec<i64>) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    let source = include_str!("../../src/bin/input/9");
+    let expected = {
+        let mut program = Program::new(source);
+        program.set_input(&[2]);
+        program.run()
+    };
+
+    let interpreted = fastest(|| {
+        let mut program = Program::new(source);
+        program.set_input(&[2]);
+        program.run()
+    });
+    let translated = fastest(|| {
+        let mut program = CompiledProgram::new(&boost::PROGRAM);
+        program.set_input(&[2]);
+        let output = program.run();
+        assert_eq!(expected, output);
+        output
+    });
+
+    println!("Day 9 part 2, fastest of {RUNS} runs:");
+    println!("  interpreted {interpreted:>10.2?}");
+    println!("  translated  {translated:>10.2?}");
+    println!(
+        "  speedup     {:>9.1}x",
+        interpreted.as_secs_f64() / translated.as_secs_f64()
+    );
+}
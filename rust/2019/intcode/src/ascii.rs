@@ -0,0 +1,283 @@
+//! Text input and output for programs that communicate in ASCII
+//!
+//! Some programs read and write lines of ASCII text, one character per value, and report their
+//! final result as a single value outside of the ASCII range. An [AsciiProgram] reads from and
+//! writes to a program directly, while a [Terminal] is a [Device] that can be attached to one.
+//! Both split output into lines with an [AsciiDecoder], and encode input with [encode_line].
+
+use {
+    super::{
+        device::{Control, Device},
+        Event, IntcodeError, Program, Value,
+    },
+    std::{collections::VecDeque, mem},
+};
+
+/// Output read from an [AsciiProgram]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AsciiOutput {
+    /// A line of text, without its trailing newline
+    Line(String),
+    /// A value that isn't an ASCII character
+    Value(Value),
+}
+
+/// The values of a line of text followed by a newline
+pub fn encode_line(line: &str) -> impl Iterator<Item = Value> + '_ {
+    line.chars().chain(Some('\n')).map(|c| c as Value)
+}
+
+/// Splits a program's output values into lines of text and non-ASCII values
+#[derive(Clone, Debug, Default)]
+pub struct AsciiDecoder {
+    line: String,
+}
+
+impl AsciiDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decodes an output value, returning the output that it completes
+    ///
+    /// A newline completes a line of text. A non-ASCII value is returned as it is, after any
+    /// text before it that isn't terminated by a newline.
+    pub fn decode(&mut self, value: Value) -> impl Iterator<Item = AsciiOutput> {
+        let (text, value) = match u8::try_from(value) {
+            Ok(b'\n') => (Some(AsciiOutput::Line(mem::take(&mut self.line))), None),
+            Ok(c) if c.is_ascii() => {
+                self.line.push(c as char);
+                (None, None)
+            }
+            _ => (self.flush(), Some(AsciiOutput::Value(value))),
+        };
+        text.into_iter().chain(value)
+    }
+
+    /// Returns any text that isn't terminated by a newline, e.g. a prompt
+    pub fn flush(&mut self) -> Option<AsciiOutput> {
+        (!self.line.is_empty()).then(|| AsciiOutput::Line(mem::take(&mut self.line)))
+    }
+}
+
+/// Wraps a [Program] that reads and writes lines of ASCII text
+pub struct AsciiProgram {
+    program: Program,
+    decoder: AsciiDecoder,
+    /// Decoded output that hasn't been read yet
+    output: VecDeque<AsciiOutput>,
+    halted: bool,
+}
+
+impl AsciiProgram {
+    pub fn new(program: Program) -> Self {
+        Self {
+            program,
+            decoder: AsciiDecoder::new(),
+            output: VecDeque::new(),
+            halted: false,
+        }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn program_mut(&mut self) -> &mut Program {
+        &mut self.program
+    }
+
+    pub fn into_inner(self) -> Program {
+        self.program
+    }
+
+    /// True once the program has halted
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Adds a line of text to the program's input, followed by a newline
+    pub fn send_line(&mut self, line: &str) {
+        let input: Vec<Value> = encode_line(line).collect();
+        self.program.add_input(&input);
+    }
+
+    /// Runs the program until it outputs a line of text or a non-ASCII value
+    ///
+    /// Text that isn't terminated by a newline, e.g. a prompt, is returned as a line when the
+    /// program waits for input, halts, or outputs a non-ASCII value. Returns None if the program
+    /// is waiting for input or has halted, with no more output to read.
+    pub fn read_line(&mut self) -> Result<Option<AsciiOutput>, IntcodeError> {
+        loop {
+            if let Some(output) = self.output.pop_front() {
+                return Ok(Some(output));
+            }
+            match self.program.run_until_event()? {
+                Event::Output(value) => self.output.extend(self.decoder.decode(value)),
+                event => {
+                    self.halted = event == Event::Halted;
+                    return Ok(self.decoder.flush());
+                }
+            }
+        }
+    }
+
+    /// Reads output until the program waits for input or halts
+    pub fn read_until_prompt(&mut self) -> Result<Vec<AsciiOutput>, IntcodeError> {
+        let mut output = Vec::new();
+        while let Some(line) = self.read_line()? {
+            output.push(line);
+        }
+        Ok(output)
+    }
+}
+
+/// A device that collects a program's ASCII output and provides it with lines of input
+#[derive(Clone, Debug, Default)]
+pub struct Terminal {
+    output: Vec<AsciiOutput>,
+    decoder: AsciiDecoder,
+    input: VecDeque<Value>,
+}
+
+impl Terminal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a line of text to be read by the program, followed by a newline
+    pub fn send_line(&mut self, line: &str) {
+        self.input.extend(encode_line(line));
+    }
+
+    /// Takes the output received so far, including any text that isn't terminated by a newline
+    pub fn take_output(&mut self) -> Vec<AsciiOutput> {
+        self.output.extend(self.decoder.flush());
+        mem::take(&mut self.output)
+    }
+}
+
+impl Device for Terminal {
+    fn frame_size(&self) -> usize {
+        1
+    }
+
+    fn output(&mut self, frame: &[Value]) -> Control {
+        self.output.extend(self.decoder.decode(frame[0]));
+        Control::Continue
+    }
+
+    fn input(&mut self) -> Option<Value> {
+        self.input.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{
+            assembler::assemble,
+            device::{Bus, Stop},
+        },
+        *,
+    };
+
+    fn line(text: &str) -> Option<AsciiOutput> {
+        Some(AsciiOutput::Line(text.to_string()))
+    }
+
+    #[test]
+    fn test_prompt() {
+        // Greets, then echoes each character of a line after a prompt, finishing with a result
+        let source = "
+                out #72
+                out #105
+                out #10
+            prompt:
+                out #63
+                in [c]
+                eq [c], #10, [t]
+                jt [t], #done
+                out [c]
+                jt #1, #prompt
+            done:
+                out #10
+                out #12345
+                hlt
+            c: data 0
+            t: data 0
+        ";
+        let mut program = AsciiProgram::new(Program::new(&assemble(source).unwrap()));
+        assert_eq!(Ok(line("Hi")), program.read_line());
+        assert_eq!(Ok(line("?")), program.read_line());
+        assert_eq!(Ok(None), program.read_line());
+        assert!(!program.is_halted());
+
+        program.send_line("ab");
+        assert_eq!(
+            Ok(vec![
+                AsciiOutput::Line("a?b?".to_string()),
+                AsciiOutput::Value(12345)
+            ]),
+            program.read_until_prompt()
+        );
+        assert!(program.is_halted());
+    }
+
+    #[test]
+    fn test_unterminated_text() {
+        let mut program = AsciiProgram::new(Program::new("104,65,104,1000,104,-1,104,66,99"));
+        assert_eq!(Ok(line("A")), program.read_line());
+        assert_eq!(Ok(Some(AsciiOutput::Value(1000))), program.read_line());
+        assert_eq!(Ok(Some(AsciiOutput::Value(-1))), program.read_line());
+        assert_eq!(Ok(line("B")), program.read_line());
+        assert_eq!(Ok(None), program.read_line());
+        assert!(program.is_halted());
+    }
+
+    #[test]
+    fn test_decoder() {
+        let mut decoder = AsciiDecoder::new();
+        let mut output = Vec::new();
+        for value in [72, 105, 10, 10, 62, 1000, 63] {
+            output.extend(decoder.decode(value));
+        }
+        assert_eq!(
+            vec![
+                line("Hi").unwrap(),
+                line("").unwrap(),
+                line(">").unwrap(),
+                AsciiOutput::Value(1000)
+            ],
+            output
+        );
+        assert_eq!(line("?"), decoder.flush());
+        assert_eq!(None, decoder.flush());
+        assert_eq!(vec![65, 10], encode_line("A").collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_terminal() {
+        let mut bus = Bus::new(
+            Program::new("104,65,104,10,3,20,4,20,104,1000,104,66,99"),
+            Terminal::new(),
+        );
+        assert_eq!(Ok(Stop::NeedsInput), bus.run());
+        assert_eq!(
+            vec![AsciiOutput::Line("A".to_string())],
+            bus.device_mut().take_output()
+        );
+
+        bus.device_mut().send_line("x");
+        assert_eq!(Ok(Stop::Halted), bus.run());
+        assert_eq!(
+            vec![
+                line("x").unwrap(),
+                AsciiOutput::Value(1000),
+                line("B").unwrap()
+            ],
+            bus.device_mut().take_output()
+        );
+        assert_eq!(Some(10), bus.device_mut().input());
+    }
+}
@@ -0,0 +1,650 @@
+//! An assembler for Intcode programs
+//!
+//! Each line contains an optional label, followed by an instruction or directive, with `;`
+//! starting a comment. Lines can be prefixed with their address (e.g. `  12: add ...`) as
+//! produced by the disassembler, in which case the address is checked against the assembled
+//! position.
+//!
+//! Operands specify their mode explicitly:
+//!   - `[x]` - position mode
+//!   - `#x` - immediate mode
+//!   - `rb+x` / `rb-x` / `rb` - relative mode
+//!
+//! where `x` is a number, a label, `$` (the address of the current line), or a sum of these,
+//! e.g. `[counter]`, `#loop`, `#$+9`, `[table+3]`.
+//!
+//! Along with the instruction mnemonics there are the following directives:
+//!   - `data x, y, ...` - places values directly in memory
+//!   - `push a` - stores `a` at the relative base and then increments it
+//!   - `pop a` - decrements the relative base and then stores its value in `a`
+//!   - `call a` - pushes the return address and jumps to `a`
+//!   - `ret` - pops the return address and jumps to it
+
+use {
+    super::{
+        instruction::{Instruction, Opcode, ParameterMode},
+        Address, Value,
+    },
+    std::{collections::HashMap, error, fmt},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AssemblyErrorKind {
+    UnknownMnemonic(String),
+    OperandCount {
+        mnemonic: String,
+        expected: usize,
+        found: usize,
+    },
+    InvalidOperand(String),
+    ImmediateModeWrite(String),
+    InvalidLabel(String),
+    DuplicateLabel(String),
+    UndefinedLabel(String),
+    AddressMismatch {
+        expected: Address,
+        found: Address,
+    },
+    /// An expression's value doesn't fit in a [Value]
+    Overflow,
+}
+
+/// An error encountered while assembling, along with the 1-based line where it occurred
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssemblyError {
+    pub line: usize,
+    pub kind: AssemblyErrorKind,
+}
+
+impl fmt::Display for AssemblyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use AssemblyErrorKind::*;
+
+        write!(f, "Line {}: ", self.line)?;
+        match &self.kind {
+            UnknownMnemonic(mnemonic) => write!(f, "Unknown mnemonic '{mnemonic}'"),
+            OperandCount {
+                mnemonic,
+                expected,
+                found,
+            } => write!(
+                f,
+                "'{mnemonic}' expects {expected} operand(s), found {found}"
+            ),
+            InvalidOperand(operand) => write!(f, "Invalid operand '{operand}'"),
+            ImmediateModeWrite(operand) => {
+                write!(
+                    f,
+                    "Immediate mode can't be used for output operand '{operand}'"
+                )
+            }
+            InvalidLabel(label) => write!(f, "Invalid label '{label}'"),
+            DuplicateLabel(label) => write!(f, "Label '{label}' is already defined"),
+            UndefinedLabel(label) => write!(f, "Undefined label '{label}'"),
+            AddressMismatch { expected, found } => write!(
+                f,
+                "Line is marked with address {expected}, but is at address {found}"
+            ),
+            Overflow => write!(f, "Expression overflows"),
+        }
+    }
+}
+
+impl error::Error for AssemblyError {}
+
+#[derive(Clone, Debug)]
+enum Term {
+    Number(Value),
+    Label(String),
+    Here,
+}
+
+impl Term {
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        if s == "$" {
+            Some(Term::Here)
+        } else if let Ok(n) = s.parse::<Value>() {
+            Some(Term::Number(n))
+        } else if is_label(s) {
+            Some(Term::Label(s.to_string()))
+        } else {
+            None
+        }
+    }
+}
+
+/// A sum of terms, resolved once all labels are known
+#[derive(Clone, Debug)]
+struct Expression(Vec<(Value, Term)>);
+
+impl Expression {
+    fn number(n: Value) -> Self {
+        Self(vec![(1, Term::Number(n))])
+    }
+
+    fn here_plus(n: Value) -> Self {
+        Self(vec![(1, Term::Here), (1, Term::Number(n))])
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let mut rest = s.trim();
+        let mut sign = 1;
+        if let Some(remainder) = rest.strip_prefix('-') {
+            sign = -1;
+            rest = remainder;
+        } else if let Some(remainder) = rest.strip_prefix('+') {
+            rest = remainder;
+        }
+
+        let mut terms = Vec::new();
+        loop {
+            let end = rest.find(['+', '-']).unwrap_or(rest.len());
+            terms.push((sign, Term::parse(&rest[..end])?));
+            if end == rest.len() {
+                break;
+            }
+            sign = if rest[end..].starts_with('-') { -1 } else { 1 };
+            rest = &rest[end + 1..];
+        }
+
+        Some(Self(terms))
+    }
+
+    fn resolve(
+        &self,
+        here: Address,
+        labels: &HashMap<String, Address>,
+    ) -> Result<Value, AssemblyErrorKind> {
+        self.0.iter().try_fold(0, |result: Value, (sign, term)| {
+            let value = match term {
+                Term::Number(n) => *n,
+                Term::Here => here as Value,
+                Term::Label(label) => *labels
+                    .get(label)
+                    .ok_or_else(|| AssemblyErrorKind::UndefinedLabel(label.clone()))?
+                    as Value,
+            };
+            sign.checked_mul(value)
+                .and_then(|value| result.checked_add(value))
+                .ok_or(AssemblyErrorKind::Overflow)
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Operand {
+    mode: ParameterMode,
+    expression: Expression,
+}
+
+impl Operand {
+    fn parse(s: &str) -> Result<Self, AssemblyErrorKind> {
+        let s = s.trim();
+        let invalid = || AssemblyErrorKind::InvalidOperand(s.to_string());
+
+        let (mode, expression) = if let Some(inner) = s.strip_prefix('[') {
+            let inner = inner.strip_suffix(']').ok_or_else(invalid)?;
+            (ParameterMode::Position, Expression::parse(inner))
+        } else if let Some(value) = s.strip_prefix('#') {
+            (ParameterMode::Immediate, Expression::parse(value))
+        } else if let Some(offset) = s.strip_prefix("rb") {
+            let offset = offset.trim();
+            let expression = if offset.is_empty() {
+                Some(Expression::number(0))
+            } else if offset.starts_with('+') || offset.starts_with('-') {
+                Expression::parse(offset)
+            } else {
+                None
+            };
+            (ParameterMode::Relative, expression)
+        } else {
+            return Err(invalid());
+        };
+
+        Ok(Self {
+            mode,
+            expression: expression.ok_or_else(invalid)?,
+        })
+    }
+
+    fn immediate(expression: Expression) -> Self {
+        Self {
+            mode: ParameterMode::Immediate,
+            expression,
+        }
+    }
+
+    fn relative(offset: Value) -> Self {
+        Self {
+            mode: ParameterMode::Relative,
+            expression: Expression::number(offset),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Item {
+    Instruction(Opcode, Vec<Operand>),
+    Data(Vec<Expression>),
+}
+
+impl Item {
+    fn size(&self) -> usize {
+        match self {
+            Item::Instruction(opcode, _) => opcode.parameter_count() + 1,
+            Item::Data(values) => values.len(),
+        }
+    }
+}
+
+struct Statement {
+    line: usize,
+    address: Address,
+    items: Vec<Item>,
+}
+
+fn is_label(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && s != "rb"
+}
+
+fn split_operands(s: &str) -> Vec<&str> {
+    if s.trim().is_empty() {
+        Vec::new()
+    } else {
+        s.split(',').map(|operand| operand.trim()).collect()
+    }
+}
+
+fn parse_operands(
+    mnemonic: &str,
+    operands: &[&str],
+    expected: usize,
+) -> Result<Vec<Operand>, AssemblyErrorKind> {
+    if operands.len() != expected {
+        return Err(AssemblyErrorKind::OperandCount {
+            mnemonic: mnemonic.to_string(),
+            expected,
+            found: operands.len(),
+        });
+    }
+    operands
+        .iter()
+        .map(|operand| Operand::parse(operand))
+        .collect()
+}
+
+fn check_writable(operand: &Operand, source: &str) -> Result<(), AssemblyErrorKind> {
+    if operand.mode == ParameterMode::Immediate {
+        Err(AssemblyErrorKind::ImmediateModeWrite(source.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+fn parse_items(mnemonic: &str, rest: &str) -> Result<Vec<Item>, AssemblyErrorKind> {
+    use {Item::Instruction as I, Opcode::*};
+
+    let operands = split_operands(rest);
+
+    let items = match mnemonic {
+        "data" => {
+            let values = operands
+                .iter()
+                .map(|value| {
+                    Expression::parse(value)
+                        .ok_or_else(|| AssemblyErrorKind::InvalidOperand(value.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if values.is_empty() {
+                return Err(AssemblyErrorKind::OperandCount {
+                    mnemonic: mnemonic.to_string(),
+                    expected: 1,
+                    found: 0,
+                });
+            }
+            vec![Item::Data(values)]
+        }
+        "push" => {
+            let a = parse_operands(mnemonic, &operands, 1)?.remove(0);
+            vec![
+                I(
+                    Add,
+                    vec![
+                        a,
+                        Operand::immediate(Expression::number(0)),
+                        Operand::relative(0),
+                    ],
+                ),
+                I(
+                    AdjustRelativeBase,
+                    vec![Operand::immediate(Expression::number(1))],
+                ),
+            ]
+        }
+        "pop" => {
+            let a = parse_operands(mnemonic, &operands, 1)?.remove(0);
+            check_writable(&a, operands[0])?;
+            vec![
+                I(
+                    AdjustRelativeBase,
+                    vec![Operand::immediate(Expression::number(-1))],
+                ),
+                I(
+                    Add,
+                    vec![
+                        Operand::relative(0),
+                        Operand::immediate(Expression::number(0)),
+                        a,
+                    ],
+                ),
+            ]
+        }
+        "call" => {
+            let a = parse_operands(mnemonic, &operands, 1)?.remove(0);
+            // The return address follows the add (4), arb (2), and jt (3) instructions
+            vec![
+                I(
+                    Add,
+                    vec![
+                        Operand::immediate(Expression::here_plus(9)),
+                        Operand::immediate(Expression::number(0)),
+                        Operand::relative(0),
+                    ],
+                ),
+                I(
+                    AdjustRelativeBase,
+                    vec![Operand::immediate(Expression::number(1))],
+                ),
+                I(
+                    JumpIfTrue,
+                    vec![Operand::immediate(Expression::number(1)), a],
+                ),
+            ]
+        }
+        "ret" => {
+            parse_operands(mnemonic, &operands, 0)?;
+            vec![
+                I(
+                    AdjustRelativeBase,
+                    vec![Operand::immediate(Expression::number(-1))],
+                ),
+                I(
+                    JumpIfTrue,
+                    vec![
+                        Operand::immediate(Expression::number(1)),
+                        Operand::relative(0),
+                    ],
+                ),
+            ]
+        }
+        _ => {
+            let opcode = Opcode::from_mnemonic(mnemonic)
+                .ok_or_else(|| AssemblyErrorKind::UnknownMnemonic(mnemonic.to_string()))?;
+            let parsed = parse_operands(mnemonic, &operands, opcode.parameter_count())?;
+            for (i, operand) in parsed.iter().enumerate() {
+                if opcode.writes_parameter(i + 1) {
+                    check_writable(operand, operands[i])?;
+                }
+            }
+            vec![I(opcode, parsed)]
+        }
+    };
+
+    Ok(items)
+}
+
+/// Assembles source into a sequence of program values
+pub fn assemble_values(source: &str) -> Result<Vec<Value>, AssemblyError> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    let mut address: usize = 0;
+
+    // First pass: parse each line, collecting labels and the addresses of statements
+    for (i, line) in source.lines().enumerate() {
+        let line_number = i + 1;
+        let error = |kind| AssemblyError {
+            line: line_number,
+            kind,
+        };
+
+        let mut rest = line.split(';').next().unwrap().trim();
+
+        while let Some((prefix, remainder)) = rest.split_once(':') {
+            let prefix = prefix.trim();
+            if let Ok(expected) = prefix.parse::<Address>() {
+                if expected as usize != address {
+                    return Err(error(AssemblyErrorKind::AddressMismatch {
+                        expected,
+                        found: address as Address,
+                    }));
+                }
+            } else if is_label(prefix) {
+                if labels
+                    .insert(prefix.to_string(), address as Address)
+                    .is_some()
+                {
+                    return Err(error(AssemblyErrorKind::DuplicateLabel(prefix.to_string())));
+                }
+            } else {
+                return Err(error(AssemblyErrorKind::InvalidLabel(prefix.to_string())));
+            }
+            rest = remainder.trim();
+        }
+
+        if rest.is_empty() {
+            continue;
+        }
+
+        let (mnemonic, operands) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let items = parse_items(mnemonic, operands).map_err(error)?;
+
+        let statement = Statement {
+            line: line_number,
+            address: address as Address,
+            items,
+        };
+        address += statement.items.iter().map(Item::size).sum::<usize>();
+        statements.push(statement);
+    }
+
+    // Second pass: encode the statements now that all labels are known
+    let mut result = Vec::with_capacity(address);
+    for statement in statements.iter() {
+        let error = |kind| AssemblyError {
+            line: statement.line,
+            kind,
+        };
+        let resolve = |expression: &Expression| {
+            expression
+                .resolve(statement.address, &labels)
+                .map_err(error)
+        };
+
+        for item in statement.items.iter() {
+            match item {
+                Item::Instruction(opcode, operands) => {
+                    let mut modes = [ParameterMode::Position; 3];
+                    for (mode, operand) in modes.iter_mut().zip(operands) {
+                        *mode = operand.mode;
+                    }
+                    let instruction = Instruction {
+                        opcode: *opcode,
+                        modes,
+                    };
+                    result.push(instruction.encode());
+                    for operand in operands {
+                        result.push(resolve(&operand.expression)?);
+                    }
+                }
+                Item::Data(values) => {
+                    for value in values {
+                        result.push(resolve(value)?);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Assembles source into comma-separated program text, as accepted by [super::Program::new]
+pub fn assemble(source: &str) -> Result<String, AssemblyError> {
+    Ok(assemble_values(source)?
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{disassembler::disassemble, memory::Memory, Program},
+        *,
+    };
+
+    #[test]
+    fn test_modes() {
+        assert_eq!(
+            Ok("1002,4,3,4,33".to_string()),
+            assemble(
+                "mul [4], #3, [4]
+                 data 33"
+            )
+        );
+        assert_eq!(
+            Ok(vec![109, 1, 204, -1, 22201, 0, 2, 3]),
+            assemble_values("arb #1\nout rb-1\nadd rb, rb+2, rb+3")
+        );
+    }
+
+    #[test]
+    fn test_labels() {
+        let source = "
+            ; Counts down from 3, outputting each value
+            start:
+                out [counter]
+                add [counter], #-1, [counter]
+                jt [counter], #start
+                hlt
+            counter: data 3
+        ";
+        assert_eq!(
+            Ok(vec![4, 10, 1001, 10, -1, 10, 1005, 10, 0, 99, 3]),
+            assemble_values(source)
+        );
+        assert_eq!(
+            vec![3, 2, 1],
+            Program::new(&assemble(source).unwrap()).run()
+        );
+    }
+
+    #[test]
+    fn test_stack_helpers() {
+        let source = "
+                arb #stack
+                in [x]
+                call #double
+                out [x]
+                hlt
+            double:
+                push [x]
+                pop [y]
+                mul [y], #2, [x]
+                ret
+            x: data 0
+            y: data 0
+            stack: data 0
+        ";
+        let mut program = Program::new(&assemble(source).unwrap());
+        program.set_input(&[21]);
+        assert_eq!(vec![42], program.run());
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |line, kind| Err(AssemblyError { line, kind });
+
+        assert_eq!(
+            error(2, AssemblyErrorKind::UnknownMnemonic("mov".to_string())),
+            assemble("hlt\nmov [1], [2]")
+        );
+        assert_eq!(
+            error(
+                1,
+                AssemblyErrorKind::OperandCount {
+                    mnemonic: "add".to_string(),
+                    expected: 3,
+                    found: 2
+                }
+            ),
+            assemble("add [1], [2]")
+        );
+        assert_eq!(
+            error(1, AssemblyErrorKind::InvalidOperand("4".to_string())),
+            assemble("out 4")
+        );
+        assert_eq!(
+            error(1, AssemblyErrorKind::ImmediateModeWrite("#2".to_string())),
+            assemble("in #2")
+        );
+        assert_eq!(
+            error(3, AssemblyErrorKind::UndefinedLabel("end".to_string())),
+            assemble("\nstart: hlt\njt #1, #end")
+        );
+        assert_eq!(
+            error(2, AssemblyErrorKind::DuplicateLabel("a".to_string())),
+            assemble("a: hlt\na: hlt")
+        );
+        assert_eq!(
+            error(
+                2,
+                AssemblyErrorKind::AddressMismatch {
+                    expected: 3,
+                    found: 2
+                }
+            ),
+            assemble("0: out #1\n3: hlt")
+        );
+        assert_eq!(
+            error(2, AssemblyErrorKind::Overflow),
+            assemble("hlt\ndata 9223372036854775807 + 1")
+        );
+        assert_eq!(
+            error(1, AssemblyErrorKind::Overflow),
+            assemble("out #-9223372036854775807 - 2")
+        );
+    }
+
+    fn assert_round_trip(memory: &[Value]) {
+        let listing = disassemble(&Memory::from(memory), 0..Address::MAX)
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(Ok(memory.to_vec()), assemble_values(&listing));
+    }
+
+    #[test]
+    fn test_disassembly_round_trip() {
+        assert_round_trip(&[
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0,
+            0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4,
+            20, 1105, 1, 46, 98, 99,
+        ]);
+
+        for input in [
+            include_str!("../../src/bin/input/9"),
+            include_str!("../../src/bin/input/17"),
+        ] {
+            let program = Program::new(input);
+            let memory = program.memory();
+            assert_round_trip(&memory.read_range(0..memory.len()));
+        }
+    }
+}
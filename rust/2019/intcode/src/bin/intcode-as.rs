@@ -0,0 +1,22 @@
+// Assembles an Intcode program, printing it in the comma-separated form used by puzzle inputs
+//
+// Usage: intcode-as SOURCE
+
+use {
+    intcode::{assembler, cli::exit_with_error},
+    std::{env, fs},
+};
+
+fn main() {
+    let Some(path) = env::args().nth(1) else {
+        exit_with_error("Usage: intcode-as SOURCE");
+    };
+
+    let source = fs::read_to_string(&path)
+        .unwrap_or_else(|error| exit_with_error(&format!("Unable to read '{path}': {error}")));
+
+    match assembler::assemble(&source) {
+        Ok(program) => println!("{program}"),
+        Err(error) => exit_with_error(&format!("{path}: {error}")),
+    }
+}
@@ -0,0 +1,48 @@
+// Prints the control-flow graph of an Intcode program in Graphviz's DOT format
+//
+// Usage: intcode-cfg PROGRAM [INPUT]
+//
+// The program is run with the comma-separated INPUT values until it halts or runs out of input,
+// and the jump targets that were observed during the run are added to the graph.
+//
+// e.g. intcode-cfg program.txt | dot -Tsvg > program.svg
+
+use {
+    intcode::{cli::exit_with_error, control_flow::ControlFlowGraph, parse_values, Event, Program},
+    std::{env, fs},
+};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        exit_with_error("Usage: intcode-cfg PROGRAM [INPUT]");
+    };
+
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|error| exit_with_error(&format!("Unable to read '{path}': {error}")));
+    let mut program =
+        Program::try_new(&source).unwrap_or_else(|error| exit_with_error(&error.to_string()));
+    let memory = program.memory().clone();
+
+    if let Some(input) = args.get(1) {
+        let input = parse_values(input).unwrap_or_else(|error| exit_with_error(&error.to_string()));
+        program.set_input(&input);
+    }
+
+    program.start_profiling();
+    loop {
+        match program.run_until_event() {
+            Ok(Event::Output(_)) => {}
+            Ok(Event::NeedsInput | Event::Halted) => break,
+            Err(error) => {
+                eprintln!("Stopped with an error: {error}");
+                break;
+            }
+        }
+    }
+
+    print!(
+        "{}",
+        ControlFlowGraph::build(&memory, program.profile()).to_dot()
+    );
+}
@@ -0,0 +1,226 @@
+// An interactive debugger for Intcode programs
+//
+// Usage: intcode-debug PROGRAM [INPUT,...]
+//
+// Enter `help` at the prompt for a list of commands.
+
+use {
+    intcode::{
+        cli::exit_with_error,
+        debugger::{Breakpoint, Debugger, Stop},
+        disassembler,
+        instruction::Opcode,
+        parse_values,
+        patch::{Patch, PatchError},
+        Address, Program,
+    },
+    std::{
+        env, fs,
+        io::{self, BufRead, Write},
+        ops::Range,
+    },
+};
+
+const HELP: &str = "\
+Commands:
+  s, step [N]           Execute N instructions (default 1)
+  c, continue           Run until a breakpoint, watchpoint, input request, or halt
+  b, break ADDR|MNEM    Break at an address, or on an opcode mnemonic (e.g. `b in`)
+  w, watch ADDR         Stop when the value at an address changes
+  d, delete ADDR|MNEM   Remove a breakpoint, or the watchpoint at an address
+  i, input V[,V...]     Provide input to the program
+  r, regs               Show the VM state and the next instruction
+  l, list [RANGE]       Disassemble memory (default: around ip)
+  m, mem RANGE          Show memory values
+  info                  List breakpoints and watchpoints
+  patch PATH            Apply a patch file of `address=value` lines
+  save PATH             Save a snapshot of the VM's state
+  load PATH             Replace the VM's state with a saved snapshot
+  q, quit               Exit the debugger
+
+Ranges are in the form `start..end`, or a single address.";
+
+fn parse_address(s: &str) -> Result<Address, String> {
+    s.parse().map_err(|_| format!("Invalid address: '{s}'"))
+}
+
+fn parse_range(s: &str) -> Result<Range<Address>, String> {
+    match s.split_once("..") {
+        Some((start, end)) => Ok(parse_address(start)?..parse_address(end)?),
+        None => {
+            let address = parse_address(s)?;
+            Ok(address..address.saturating_add(1))
+        }
+    }
+}
+
+fn parse_breakpoint(s: &str) -> Result<Breakpoint, String> {
+    match Opcode::from_mnemonic(s) {
+        Some(opcode) => Ok(Breakpoint::Opcode(opcode)),
+        None => Ok(Breakpoint::Address(parse_address(s)?)),
+    }
+}
+
+fn print_state(program: &Program) {
+    println!(
+        "ip: {}  rb: {}  pending input: {:?}",
+        program.ip(),
+        program.relative_base(),
+        program.pending_inputs()
+    );
+    println!("{}", program.next_instruction());
+}
+
+fn print_stop(debugger: &mut Debugger, stop: Stop) {
+    for value in debugger.take_output() {
+        println!("output: {value}");
+    }
+
+    match stop {
+        Stop::Stepped => {}
+        Stop::Breakpoint(breakpoint) => println!("Stopped at breakpoint ({breakpoint})"),
+        Stop::Watchpoint { address, old, new } => {
+            println!("Watchpoint: [{address}] changed from {old} to {new}")
+        }
+        Stop::NeedsInput => println!("Waiting for input"),
+        Stop::Halted => println!("Halted"),
+    }
+
+    print_state(debugger.program());
+}
+
+fn run_command(
+    debugger: &mut Debugger,
+    command: &str,
+    argument: Option<&str>,
+) -> Result<(), String> {
+    match (command, argument) {
+        ("s" | "step", count) => {
+            let count = match count {
+                Some(count) => count
+                    .parse()
+                    .map_err(|_| format!("Invalid count: '{count}'"))?,
+                None => 1,
+            };
+            let mut stop = Stop::Stepped;
+            for _ in 0..count {
+                stop = debugger.step().map_err(|error| error.to_string())?;
+                if stop != Stop::Stepped {
+                    break;
+                }
+            }
+            print_stop(debugger, stop);
+        }
+        ("c" | "continue", None) => {
+            let stop = debugger.resume().map_err(|error| error.to_string())?;
+            print_stop(debugger, stop);
+        }
+        ("b" | "break", Some(target)) => debugger.add_breakpoint(parse_breakpoint(target)?),
+        ("w" | "watch", Some(address)) => debugger.add_watchpoint(parse_address(address)?),
+        ("d" | "delete", Some(target)) => {
+            let removed = debugger.remove_breakpoint(parse_breakpoint(target)?)
+                || parse_address(target).is_ok_and(|a| debugger.remove_watchpoint(a));
+            if !removed {
+                return Err(format!("No breakpoint or watchpoint at '{target}'"));
+            }
+        }
+        ("i" | "input", Some(values)) => debugger
+            .program_mut()
+            .add_input(&parse_values(values).map_err(|error| error.to_string())?),
+        ("r" | "regs", None) => print_state(debugger.program()),
+        ("l" | "list", range) => {
+            let range = match range {
+                Some(range) => parse_range(range)?,
+                None => {
+                    let ip = debugger.program().ip();
+                    ip..ip.saturating_add(20)
+                }
+            };
+            for line in disassembler::disassemble(debugger.program().memory(), range) {
+                let marker = if line.address() == debugger.program().ip() {
+                    ">"
+                } else {
+                    " "
+                };
+                println!("{marker}{line}");
+            }
+        }
+        ("m" | "mem", Some(range)) => {
+            print!("{}", debugger.program().dump(parse_range(range)?, 10));
+        }
+        ("info", None) => {
+            for breakpoint in debugger.breakpoints() {
+                println!("breakpoint: {breakpoint}");
+            }
+            for address in debugger.watchpoints() {
+                println!("watchpoint: {address}");
+            }
+        }
+        ("patch", Some(path)) => {
+            let patch: Patch = fs::read_to_string(path)
+                .map_err(|error| format!("Unable to read '{path}': {error}"))?
+                .parse()
+                .map_err(|error: PatchError| error.to_string())?;
+            debugger.program_mut().apply_patch(&patch);
+        }
+        ("save", Some(path)) => debugger
+            .program()
+            .save_snapshot(path)
+            .map_err(|error| error.to_string())?,
+        ("load", Some(path)) => {
+            *debugger.program_mut() =
+                Program::load_snapshot(path).map_err(|error| error.to_string())?;
+            print_state(debugger.program());
+        }
+        ("help", None) => println!("{HELP}"),
+        _ => {
+            return Err(format!(
+                "Unknown command: '{command}', enter `help` for a list"
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        exit_with_error("Usage: intcode-debug PROGRAM [INPUT,...]");
+    };
+
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|error| exit_with_error(&format!("Unable to read '{path}': {error}")));
+    let mut program =
+        Program::try_new(&source).unwrap_or_else(|error| exit_with_error(&error.to_string()));
+    if let Some(input) = args.get(1) {
+        program.set_input(
+            &parse_values(input).unwrap_or_else(|error| exit_with_error(&error.to_string())),
+        );
+    }
+
+    let mut debugger = Debugger::new(program);
+    print_state(debugger.program());
+
+    let stdin = io::stdin();
+    loop {
+        print!("(icdb) ");
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            break;
+        }
+
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            continue;
+        };
+        if command == "q" || command == "quit" {
+            break;
+        }
+        if let Err(error) = run_command(&mut debugger, command, words.next()) {
+            println!("{error}");
+        }
+    }
+}
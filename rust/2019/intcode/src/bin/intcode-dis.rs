@@ -0,0 +1,64 @@
+// Disassembles an Intcode program
+//
+// Usage: intcode-dis PROGRAM [RANGE]...
+//
+// Ranges are addresses in the form `start..end`, `start..`, `..end`, or `address`.
+
+use {
+    intcode::{cli::exit_with_error, disassembler, Address, Program},
+    std::{env, fs, ops::Range},
+};
+
+fn parse_range(range: &str) -> Option<Range<Address>> {
+    match range.split_once("..") {
+        Some((start, end)) => {
+            let start = if start.is_empty() {
+                0
+            } else {
+                start.parse().ok()?
+            };
+            let end = if end.is_empty() {
+                Address::MAX
+            } else {
+                end.parse().ok()?
+            };
+            Some(start..end)
+        }
+        None => {
+            let address: Address = range.parse().ok()?;
+            Some(address..address.saturating_add(1))
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        exit_with_error("Usage: intcode-dis PROGRAM [RANGE]...");
+    };
+
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|error| exit_with_error(&format!("Unable to read '{path}': {error}")));
+    let program =
+        Program::try_new(&source).unwrap_or_else(|error| exit_with_error(&error.to_string()));
+
+    let mut ranges: Vec<Range<Address>> = args[1..]
+        .iter()
+        .map(|arg| {
+            parse_range(arg)
+                .unwrap_or_else(|| exit_with_error(&format!("Invalid address range: '{arg}'")))
+        })
+        .collect();
+    if ranges.is_empty() {
+        ranges.push(0..Address::MAX);
+    }
+
+    for (i, range) in ranges.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        for line in disassembler::disassemble(program.memory(), range) {
+            println!("{line}");
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Runs an Intcode program and prints a profile of its execution
+//
+// Usage: intcode-prof PROGRAM [INPUT] [LOOPS]
+//
+// INPUT is a comma-separated list of input values, and LOOPS is the number of hot loops to list
+// (10 by default).
+
+use {
+    intcode::{cli::exit_with_error, parse_values, Program},
+    std::{env, fs},
+};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        exit_with_error("Usage: intcode-prof PROGRAM [INPUT] [LOOPS]");
+    };
+
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|error| exit_with_error(&format!("Unable to read '{path}': {error}")));
+    let mut program =
+        Program::try_new(&source).unwrap_or_else(|error| exit_with_error(&error.to_string()));
+
+    if let Some(input) = args.get(1) {
+        let input = parse_values(input).unwrap_or_else(|error| exit_with_error(&error.to_string()));
+        program.set_input(&input);
+    }
+
+    let max_loops = match args.get(2) {
+        Some(loops) => loops
+            .parse()
+            .unwrap_or_else(|_| exit_with_error(&format!("Invalid loop count: '{loops}'"))),
+        None => 10,
+    };
+
+    program.start_profiling();
+    let result = program.try_run();
+    let profile = program.stop_profiling().unwrap();
+
+    match result {
+        Ok(output) => match output.last() {
+            Some(last) => println!("Output {} values, the last being {last}\n", output.len()),
+            None => println!("No output\n"),
+        },
+        Err(error) => println!("Stopped with an error: {error}\n"),
+    }
+    print!("{}", profile.report(max_loops));
+}
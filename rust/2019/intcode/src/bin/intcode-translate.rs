@@ -0,0 +1,47 @@
+// Translates an Intcode program into Rust, see intcode::translate
+//
+// Usage: intcode-translate PROGRAM [INPUT]
+//
+// If INPUT is given then the program is first run with the comma-separated input values, and
+// the jump targets that were observed during the run are translated along with the code that's
+// found statically.
+
+use {
+    intcode::{cli::exit_with_error, parse_values, translate, Event, Program},
+    std::{env, fs},
+};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        exit_with_error("Usage: intcode-translate PROGRAM [INPUT]");
+    };
+
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|error| exit_with_error(&format!("Unable to read '{path}': {error}")));
+    let mut program =
+        Program::try_new(&source).unwrap_or_else(|error| exit_with_error(&error.to_string()));
+    let memory = program.memory().clone();
+
+    let Some(input) = args.get(1) else {
+        print!("{}", translate::translate(&memory, None));
+        return;
+    };
+
+    let input = parse_values(input).unwrap_or_else(|error| exit_with_error(&error.to_string()));
+    program.set_input(&input);
+
+    program.start_profiling();
+    loop {
+        match program.run_until_event() {
+            Ok(Event::Output(_)) => {}
+            Ok(Event::NeedsInput | Event::Halted) => break,
+            Err(error) => {
+                eprintln!("Stopped with an error: {error}");
+                break;
+            }
+        }
+    }
+
+    print!("{}", translate::translate(&memory, program.profile()));
+}
@@ -0,0 +1,146 @@
+// Runs an Intcode program
+//
+// Usage: intcode [--input V,...] [--patch ADDR=V,...] [--ascii] PROGRAM
+//
+// Options:
+//   --input V,...       Input values for the program, read before any input from stdin
+//   --patch ADDR=V,...  Values to write to memory before running, e.g. `--patch 1=12,2=2`
+//   --ascii             Print output as text, and send each line read from stdin as text
+//
+// Output values are printed one per line. In ASCII mode, output is printed a line of text at a
+// time, with values outside of the ASCII range printed as numbers on their own line, and text
+// that isn't terminated by a newline (e.g. a prompt) is ended when the program waits for input.
+// When the program runs out of input, a line of values (or of text in ASCII mode) is read from
+// stdin.
+//
+// Program files contain comma-separated values, which can also be separated by whitespace or
+// newlines, and can contain comments starting with `#`.
+
+use {
+    intcode::{
+        ascii::{AsciiOutput, AsciiProgram},
+        cli::exit_with_error,
+        parse_values,
+        patch::Patch,
+        Event, Program,
+    },
+    std::{
+        env, fs,
+        io::{self, BufRead, Write},
+    },
+};
+
+const USAGE: &str = "Usage: intcode [--input V,...] [--patch ADDR=V,...] [--ascii] PROGRAM";
+
+/// Reads a line from stdin for a program that's waiting for input, without its line ending
+fn read_input_line(stdout: &mut impl Write) -> io::Result<String> {
+    stdout.flush()?;
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line)? == 0 {
+        exit_with_error("The program is waiting for input, but stdin has ended");
+    }
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Runs the program until it halts, reading values from stdin when it needs more input
+fn run(program: &mut Program) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    loop {
+        match program.run_until_event() {
+            Ok(Event::Output(value)) => writeln!(stdout, "{value}")?,
+            Ok(Event::NeedsInput) => {
+                let line = read_input_line(&mut stdout)?;
+                program.add_input(
+                    &parse_values(&line).unwrap_or_else(|e| exit_with_error(&e.to_string())),
+                );
+            }
+            Ok(Event::Halted) => return Ok(()),
+            Err(error) => {
+                stdout.flush()?;
+                exit_with_error(&error.to_string());
+            }
+        }
+    }
+}
+
+/// Runs the program until it halts, reading lines of text from stdin when it needs more input
+fn run_ascii(program: &mut AsciiProgram) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    loop {
+        match program.read_line() {
+            Ok(Some(AsciiOutput::Line(line))) => writeln!(stdout, "{line}")?,
+            Ok(Some(AsciiOutput::Value(value))) => writeln!(stdout, "{value}")?,
+            Ok(None) if program.is_halted() => return Ok(()),
+            Ok(None) => {
+                let line = read_input_line(&mut stdout)?;
+                program.send_line(&line);
+            }
+            Err(error) => {
+                stdout.flush()?;
+                exit_with_error(&error.to_string());
+            }
+        }
+    }
+}
+
+fn main() {
+    let mut input = Vec::new();
+    let mut patch = Patch::new();
+    let mut ascii = false;
+    let mut path = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "--patch" => {
+                let Some(value) = args.next() else {
+                    exit_with_error(&format!("Missing value for {arg}\n{USAGE}"));
+                };
+                if arg == "--input" {
+                    input.extend(
+                        parse_values(&value).unwrap_or_else(|e| exit_with_error(&e.to_string())),
+                    );
+                } else {
+                    let values: Patch = value.replace(',', "\n").parse().unwrap_or_else(|error| {
+                        exit_with_error(&format!("Invalid patch: {error}"))
+                    });
+                    for &(address, value) in values.values() {
+                        patch.set(address, value);
+                    }
+                }
+            }
+            "--ascii" => ascii = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            _ if arg.starts_with('-') => {
+                exit_with_error(&format!("Unknown option: {arg}\n{USAGE}"))
+            }
+            _ if path.is_none() => path = Some(arg),
+            _ => exit_with_error(USAGE),
+        }
+    }
+    let Some(path) = path else {
+        exit_with_error(USAGE);
+    };
+
+    let source = fs::read_to_string(&path)
+        .unwrap_or_else(|error| exit_with_error(&format!("Unable to read '{path}': {error}")));
+    let mut program =
+        Program::try_new(&source).unwrap_or_else(|error| exit_with_error(&error.to_string()));
+    program.apply_patch(&patch);
+    program.set_input(&input);
+
+    let result = if ascii {
+        run_ascii(&mut AsciiProgram::new(program))
+    } else {
+        run(&mut program)
+    };
+    match result {
+        // Output is no longer needed, e.g. after piping to `head`
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => {}
+        Err(error) => exit_with_error(&error.to_string()),
+        Ok(()) => {}
+    }
+}
@@ -0,0 +1,9 @@
+//! Helpers shared by the command-line tools
+
+use std::process;
+
+/// Prints the message to stderr and exits with a failure status
+pub fn exit_with_error(message: &str) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
@@ -0,0 +1,394 @@
+//! Control-flow graphs of basic blocks
+//!
+//! Instructions are found by decoding from address 0 and following each jump. The targets of jumps
+//! that aren't in immediate mode are usually computed, so they can't be found statically, but
+//! the targets that were observed during a run can be taken from a [Profile]. Blocks are split at
+//! each jump target and after each jump, and the graph can be exported in Graphviz's DOT format.
+
+use {
+    super::{
+        disassembler::{self, Line},
+        instruction::{Instruction, Opcode, ParameterMode},
+        memory::Memory,
+        profile::Profile,
+        Address,
+    },
+    std::{
+        collections::{BTreeMap, BTreeSet},
+        fmt::Write,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EdgeKind {
+    /// Execution continues into the next block
+    Next,
+    /// A jump to an immediate mode target
+    Taken,
+    /// A conditional jump that wasn't taken
+    NotTaken,
+    /// A jump to a computed target that was observed during a run
+    Observed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Edge {
+    pub target: Address,
+    pub kind: EdgeKind,
+}
+
+/// A sequence of instructions that's only entered at its start and only left at its end
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BasicBlock {
+    pub start: Address,
+    pub lines: Vec<Line>,
+    pub successors: Vec<Edge>,
+}
+
+impl BasicBlock {
+    /// The address following the block's last instruction
+    pub fn end(&self) -> Address {
+        self.lines
+            .last()
+            .map_or(self.start, |line| line.address() + line.size())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ControlFlowGraph {
+    blocks: BTreeMap<Address, BasicBlock>,
+}
+
+/// Whether the line is a jump or a halt, which end a block
+fn ends_block(line: &Line) -> bool {
+    matches!(
+        line,
+        Line::Instruction { instruction, .. }
+            if matches!(
+                instruction.opcode,
+                Opcode::JumpIfTrue | Opcode::JumpIfFalse | Opcode::Halt
+            )
+    )
+}
+
+/// The instruction's static jump target, and whether it can continue to the next instruction
+fn static_successors(line: &Line) -> (Option<Address>, bool) {
+    let Line::Instruction {
+        instruction: Instruction { opcode, .. },
+        operands,
+        ..
+    } = line
+    else {
+        return (None, false);
+    };
+
+    let taken_when = match opcode {
+        Opcode::JumpIfTrue => true,
+        Opcode::JumpIfFalse => false,
+        Opcode::Halt => return (None, false),
+        _ => return (None, true),
+    };
+    let immediate = |i: usize| {
+        let operand = operands[i];
+        (operand.mode == ParameterMode::Immediate).then_some(operand.value)
+    };
+
+    let target = immediate(1)
+        .filter(|&target| target >= 0)
+        .map(|target| target as Address);
+    match immediate(0) {
+        Some(condition) if (condition != 0) == taken_when => (target, false),
+        Some(_) => (None, true),
+        None => (target, true),
+    }
+}
+
+impl ControlFlowGraph {
+    /// Builds the graph for a program, adding jump targets that were observed in the profile
+    ///
+    /// The memory should be the program's initial memory, or the program's state when profiling
+    /// started if the program modifies its code.
+    pub fn build(memory: &Memory, profile: Option<&Profile>) -> Self {
+        Self::build_with_entries(memory, profile, &[])
+    }
+
+    /// Builds the graph as with [ControlFlowGraph::build], also starting blocks at the entry points
+    ///
+    /// Entry points can be used for addresses that are known to be jumped to, such as the return
+    /// addresses of subroutine calls.
+    pub fn build_with_entries(
+        memory: &Memory,
+        profile: Option<&Profile>,
+        entries: &[Address],
+    ) -> Self {
+        let observed: BTreeMap<Address, BTreeSet<Address>> = profile
+            .map(|profile| profile.jump_targets().clone())
+            .unwrap_or_default();
+
+        // Decode every reachable instruction, noting where blocks start
+        let mut lines = BTreeMap::new();
+        let mut leaders = BTreeSet::from([0]);
+        leaders.extend(observed.values().flatten());
+        leaders.extend(entries);
+        let mut pending: Vec<Address> = leaders.iter().copied().collect();
+        while let Some(address) = pending.pop() {
+            if lines.contains_key(&address) {
+                continue;
+            }
+            let line = disassembler::decode_line(memory, address);
+            if let Line::Data { .. } = line {
+                continue;
+            }
+
+            let next = address + line.size();
+            let (target, continues) = static_successors(&line);
+            if let Some(target) = target {
+                leaders.insert(target);
+                pending.push(target);
+            }
+            if ends_block(&line) {
+                leaders.insert(next);
+            }
+            if continues {
+                pending.push(next);
+            }
+            lines.insert(address, line);
+        }
+
+        // Group the instructions into blocks
+        let mut blocks = BTreeMap::new();
+        for &start in leaders.iter().filter(|start| lines.contains_key(start)) {
+            let mut block_lines = Vec::new();
+            let mut address = start;
+            let successors = loop {
+                let line = lines[&address].clone();
+                let next = address + line.size();
+                let (target, continues) = static_successors(&line);
+                let jump_targets = observed.get(&address);
+                let ends = ends_block(&line);
+                block_lines.push(line);
+
+                if ends {
+                    let mut successors: Vec<Edge> = target
+                        .map(|target| Edge {
+                            target,
+                            kind: EdgeKind::Taken,
+                        })
+                        .into_iter()
+                        .chain(
+                            jump_targets
+                                .into_iter()
+                                .flatten()
+                                .filter(|&&observed| Some(observed) != target)
+                                .map(|&target| Edge {
+                                    target,
+                                    kind: EdgeKind::Observed,
+                                }),
+                        )
+                        .collect();
+                    if continues {
+                        successors.push(Edge {
+                            target: next,
+                            kind: EdgeKind::NotTaken,
+                        });
+                    }
+                    break successors;
+                }
+                if leaders.contains(&next) {
+                    break vec![Edge {
+                        target: next,
+                        kind: EdgeKind::Next,
+                    }];
+                }
+                if !lines.contains_key(&next) {
+                    // The next value isn't a valid instruction
+                    break Vec::new();
+                }
+                address = next;
+            };
+
+            blocks.insert(
+                start,
+                BasicBlock {
+                    start,
+                    lines: block_lines,
+                    successors,
+                },
+            );
+        }
+
+        Self { blocks }
+    }
+
+    /// The graph's blocks, in address order
+    pub fn blocks(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.values()
+    }
+
+    /// The block that starts at the address
+    pub fn block(&self, start: Address) -> Option<&BasicBlock> {
+        self.blocks.get(&start)
+    }
+
+    /// The block that contains the instruction at the address
+    pub fn block_containing(&self, address: Address) -> Option<&BasicBlock> {
+        self.blocks
+            .range(..=address)
+            .rev()
+            .map(|(_, block)| block)
+            .find(|block| block.lines.iter().any(|line| line.address() == address))
+    }
+
+    /// Renders the graph in Graphviz's DOT format, with each block listing its instructions
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph intcode {\n");
+        dot.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+
+        for block in self.blocks() {
+            let listing: String = block
+                .lines
+                .iter()
+                .map(|line| format!("{}\\l", line.to_string().trim_start()))
+                .collect();
+            writeln!(dot, "    b{} [label=\"{listing}\"];", block.start).unwrap();
+        }
+
+        for block in self.blocks() {
+            for edge in block.successors.iter() {
+                let attributes = match edge.kind {
+                    EdgeKind::Next => "",
+                    EdgeKind::Taken => " [label=\"taken\"]",
+                    EdgeKind::NotTaken => " [label=\"not taken\"]",
+                    EdgeKind::Observed => " [label=\"observed\", style=dashed]",
+                };
+                writeln!(dot, "    b{} -> b{}{attributes};", block.start, edge.target).unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler::assemble, super::Program, *};
+
+    // Counts down from the input, jumping to a computed handler for each value
+    const DISPATCH: &str = "
+            in [n]
+        loop:
+            jf [n], #done
+            add [n], #-1, [n]
+            eq [n], #1, [t]
+            mul [t], #5, [t]
+            add [t], #other, [target]
+            jt #1, [target]
+        done:
+            hlt
+        other:
+            out #0
+            jt #1, #loop
+        one:
+            out #1
+            jt #1, #loop
+        n: data 0
+        t: data 0
+        target: data 0
+    ";
+
+    fn dispatch_graph(observe: bool) -> ControlFlowGraph {
+        let mut program = Program::new(&assemble(DISPATCH).unwrap());
+        let memory = program.memory().clone();
+        program.start_profiling();
+        program.set_input(&[3]);
+        assert_eq!(vec![0, 1, 0], program.run());
+        let profile = program.stop_profiling().unwrap();
+        ControlFlowGraph::build(&memory, observe.then_some(&profile))
+    }
+
+    fn edges(graph: &ControlFlowGraph, start: Address) -> Vec<(Address, EdgeKind)> {
+        graph
+            .block(start)
+            .unwrap()
+            .successors
+            .iter()
+            .map(|edge| (edge.target, edge.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_static_graph() {
+        let graph = dispatch_graph(false);
+        assert_eq!(
+            vec![0, 5, 24],
+            graph.blocks().map(|block| block.start).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(24, EdgeKind::Taken), (5, EdgeKind::NotTaken)],
+            edges(&graph, 0)
+        );
+        // The computed jump's targets are unknown, and nothing is decoded beyond it
+        assert!(edges(&graph, 5).is_empty());
+        assert_eq!(24, graph.block(5).unwrap().end());
+        assert!(graph.block(25).is_none());
+    }
+
+    #[test]
+    fn test_observed_targets() {
+        let graph = dispatch_graph(true);
+        assert_eq!(
+            vec![0, 2, 5, 24, 25, 30],
+            graph.blocks().map(|block| block.start).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![(2, EdgeKind::Next)], edges(&graph, 0));
+        assert_eq!(
+            vec![(24, EdgeKind::Taken), (5, EdgeKind::NotTaken)],
+            edges(&graph, 2)
+        );
+        assert_eq!(
+            vec![(25, EdgeKind::Observed), (30, EdgeKind::Observed)],
+            edges(&graph, 5)
+        );
+        assert_eq!(vec![(2, EdgeKind::Taken)], edges(&graph, 30));
+        assert!(edges(&graph, 24).is_empty());
+        assert_eq!(5, graph.block_containing(21).unwrap().start);
+        assert!(graph.block_containing(22).is_none());
+    }
+
+    #[test]
+    fn test_entries() {
+        let program = Program::new(&assemble(DISPATCH).unwrap());
+        let graph = ControlFlowGraph::build_with_entries(program.memory(), None, &[25, 30]);
+        assert_eq!(
+            vec![0, 2, 5, 24, 25, 30],
+            graph.blocks().map(|block| block.start).collect::<Vec<_>>()
+        );
+        assert!(edges(&graph, 5).is_empty());
+    }
+
+    #[test]
+    fn test_dot() {
+        let dot = dispatch_graph(true).to_dot();
+        assert!(dot.starts_with("digraph intcode {\n"));
+        assert!(dot.contains("    b0 [label=\"0: in   [35]\\l\"];\n"));
+        assert!(dot.contains("    b0 -> b2;\n"));
+        assert!(dot.contains("    b2 -> b24 [label=\"taken\"];\n"));
+        assert!(dot.contains("    b5 -> b30 [label=\"observed\", style=dashed];\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_day_13() {
+        let mut program = Program::new(include_str!("../../src/bin/input/13"));
+        let memory = program.memory().clone();
+        program.start_profiling();
+        assert!(program.try_run().is_ok());
+        let graph = ControlFlowGraph::build(&memory, program.profile());
+        assert!(graph.blocks().count() > 10);
+        // Every observed jump target starts a block
+        for targets in program.profile().unwrap().jump_targets().values() {
+            assert!(targets.iter().all(|&target| graph.block(target).is_some()));
+        }
+    }
+}
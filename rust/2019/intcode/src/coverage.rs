@@ -0,0 +1,394 @@
+//! Tracking how each memory cell is used during a run
+//!
+//! While coverage is enabled, each executed instruction marks its opcode cell as executed and its
+//! parameter cells as operands, along with the cells that it reads from or writes to through
+//! position or relative mode parameters. The resulting [Coverage] separates the program's code
+//! from its data, and can be rendered as a grid or as an annotated listing.
+
+use {
+    super::{
+        disassembler::{self, Line},
+        instruction::Opcode,
+        memory::{Memory, PAGE_SIZE},
+        Address, Program,
+    },
+    std::{
+        collections::{BTreeMap, BTreeSet},
+        fmt::Write,
+        ops::{BitOr, Range},
+    },
+};
+
+/// Separates ranges of memory that aren't next to each other when rendering
+const GAP: &str = "...";
+
+/// The ways in which a memory cell was used, combined with `|`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Usage(u8);
+
+impl Usage {
+    pub const UNTOUCHED: Usage = Usage(0);
+    /// The cell was executed as an instruction's opcode
+    pub const EXECUTED: Usage = Usage(1);
+    /// The cell was used as a parameter of an executed instruction
+    pub const OPERAND: Usage = Usage(2);
+    /// The cell was read through a position or relative mode parameter
+    pub const READ: Usage = Usage(4);
+    /// The cell was written to
+    pub const WRITTEN: Usage = Usage(8);
+
+    pub fn contains(self, other: Usage) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_code(self) -> bool {
+        self.0 & (Usage::EXECUTED.0 | Usage::OPERAND.0) != 0
+    }
+
+    pub fn is_untouched(self) -> bool {
+        self == Usage::UNTOUCHED
+    }
+
+    /// A single character summarizing the usage, as shown by [Coverage::render_grid]
+    pub fn symbol(self) -> char {
+        if self.is_code() && self.contains(Usage::WRITTEN) {
+            '!'
+        } else if self.contains(Usage::EXECUTED) {
+            '#'
+        } else if self.contains(Usage::OPERAND) {
+            '+'
+        } else if self.contains(Usage::READ | Usage::WRITTEN) {
+            'b'
+        } else if self.contains(Usage::WRITTEN) {
+            'w'
+        } else if self.contains(Usage::READ) {
+            'r'
+        } else {
+            '.'
+        }
+    }
+
+    fn color(self) -> &'static str {
+        match self.symbol() {
+            '!' => "\x1b[31m",
+            '#' | '+' => "\x1b[32m",
+            'b' => "\x1b[35m",
+            'w' => "\x1b[33m",
+            'r' => "\x1b[36m",
+            _ => "\x1b[90m",
+        }
+    }
+
+    fn description(self) -> String {
+        let names = [
+            (Usage::EXECUTED, "executed"),
+            (Usage::OPERAND, "operand"),
+            (Usage::READ, "read"),
+            (Usage::WRITTEN, "written"),
+        ];
+        let used: Vec<&str> = names
+            .iter()
+            .filter(|(usage, _)| self.contains(*usage))
+            .map(|(_, name)| *name)
+            .collect();
+        if used.is_empty() {
+            "untouched".to_string()
+        } else {
+            used.join(", ")
+        }
+    }
+}
+
+impl BitOr for Usage {
+    type Output = Usage;
+
+    fn bitor(self, other: Usage) -> Usage {
+        Usage(self.0 | other.0)
+    }
+}
+
+/// The symbols used by [Coverage::render_grid]
+pub const GRID_LEGEND: &str =
+    "# executed, + operand, ! modified code, r read, w written, b read and written, . untouched";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    cells: BTreeMap<Address, Usage>,
+}
+
+impl Coverage {
+    fn mark(&mut self, address: Address, usage: Usage) {
+        let cell = self.cells.entry(address).or_default();
+        *cell = *cell | usage;
+    }
+
+    pub fn usage(&self, address: Address) -> Usage {
+        self.cells.get(&address).copied().unwrap_or_default()
+    }
+
+    /// The cells that were used, in address order
+    pub fn cells(&self) -> impl Iterator<Item = (Address, Usage)> + '_ {
+        self.cells.iter().map(|(&address, &usage)| (address, usage))
+    }
+
+    /// The ranges of memory to render, up to `end`
+    ///
+    /// Memory is sparse, so only the pages that are allocated or hold used cells are included,
+    /// with neighbouring pages merged into a single range.
+    fn rendered_ranges(&self, memory: &Memory, end: Address) -> Vec<Range<Address>> {
+        let page_size = PAGE_SIZE as Address;
+        let pages: BTreeSet<Address> = memory
+            .pages()
+            .into_iter()
+            .map(|(index, _)| index)
+            .chain(self.cells.keys().map(|address| address / page_size))
+            .collect();
+
+        let mut ranges: Vec<Range<Address>> = Vec::new();
+        for index in pages {
+            let start = index * page_size;
+            if start >= end {
+                break;
+            }
+            let page_end = start.saturating_add(page_size).min(end);
+            match ranges.last_mut() {
+                Some(range) if range.end == start => range.end = page_end,
+                _ => ranges.push(start..page_end),
+            }
+        }
+        ranges
+    }
+
+    /// Renders a grid with a symbol for each cell of memory, with `columns` cells per row
+    ///
+    /// The grid covers memory up to its length, along with any cells beyond it that were read.
+    /// Only pages that are allocated or hold used cells are shown, with gaps between them marked
+    /// by `...`. If `color` is true then the symbols are colored with ANSI escape codes.
+    pub fn render_grid(&self, memory: &Memory, columns: usize, color: bool) -> String {
+        let columns = columns.max(1) as Address;
+        let end = self
+            .cells
+            .keys()
+            .next_back()
+            .map_or(0, |&last| last.saturating_add(1))
+            .max(memory.len());
+
+        let mut grid = String::new();
+        let mut previous_end = 0;
+        for range in self.rendered_ranges(memory, end) {
+            if range.start > previous_end {
+                writeln!(grid, "{GAP}").unwrap();
+            }
+            previous_end = range.end;
+
+            let mut row_start = range.start;
+            while row_start < range.end {
+                let row_end = row_start.saturating_add(columns).min(range.end);
+                write!(grid, "{row_start:>6}: ").unwrap();
+                for address in row_start..row_end {
+                    let usage = self.usage(address);
+                    if color {
+                        grid.push_str(usage.color());
+                    }
+                    grid.push(usage.symbol());
+                }
+                if color {
+                    grid.push_str("\x1b[0m");
+                }
+                grid.push('\n');
+                row_start = row_end;
+            }
+        }
+        grid
+    }
+
+    /// Renders a listing of memory, annotating each line with how its cells were used
+    ///
+    /// Instructions are only decoded at addresses that were executed, with all other cells being
+    /// listed as data. As with [Coverage::render_grid], gaps between pages are marked by `...`.
+    pub fn render_listing(&self, memory: &Memory) -> String {
+        let mut listing = String::new();
+        let mut address = 0;
+        for range in self.rendered_ranges(memory, memory.len()) {
+            if range.start > address {
+                writeln!(listing, "{GAP}").unwrap();
+                address = range.start;
+            }
+            while address < range.end {
+                address += self.render_line(&mut listing, memory, address);
+            }
+        }
+        listing
+    }
+
+    /// Adds the line at the address to a listing, returning its size
+    fn render_line(&self, listing: &mut String, memory: &Memory, address: Address) -> Address {
+        let line = if self.usage(address).contains(Usage::EXECUTED) {
+            disassembler::decode_line(memory, address)
+        } else {
+            Line::Data {
+                address,
+                value: memory.get(address),
+            }
+        };
+        let usage = (address..address + line.size())
+            .map(|address| self.usage(address))
+            .fold(Usage::UNTOUCHED, |a, b| a | b);
+        writeln!(
+            listing,
+            "{:<40} ; {}",
+            line.to_string(),
+            usage.description()
+        )
+        .unwrap();
+        line.size()
+    }
+}
+
+impl Program {
+    /// Starts tracking how memory is used, discarding any existing coverage
+    pub fn start_coverage(&mut self) {
+        self.coverage = Some(Box::default());
+    }
+
+    /// Stops tracking memory usage, returning the coverage that was collected
+    pub fn stop_coverage(&mut self) -> Option<Coverage> {
+        self.coverage.take().map(|coverage| *coverage)
+    }
+
+    pub fn coverage(&self) -> Option<&Coverage> {
+        self.coverage.as_deref()
+    }
+
+    /// The cells used by the next instruction, to be marked once it has executed
+    pub(super) fn cells_to_cover(&self) -> Vec<(Address, Usage)> {
+        let ip = self.ip;
+        let op = self.read(ip);
+        let mut cells = vec![(ip, Usage::EXECUTED)];
+        if let Some(opcode) = Opcode::from_value(op) {
+            for id in 1..=opcode.parameter_count() {
+                cells.push((ip + id as Address, Usage::OPERAND));
+                // Immediate mode parameters don't refer to a cell
+                if let Ok(address) = self.parameter_address(id, op) {
+                    let usage = if opcode.writes_parameter(id) {
+                        Usage::WRITTEN
+                    } else {
+                        Usage::READ
+                    };
+                    cells.push((address, usage));
+                }
+            }
+        }
+        cells
+    }
+
+    pub(super) fn mark_coverage(&mut self, cells: Vec<(Address, Usage)>) {
+        if let Some(coverage) = &mut self.coverage {
+            for (address, usage) in cells {
+                coverage.mark(address, usage);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler::assemble, *};
+
+    // Outputs a value from a lookup table
+    const LOOKUP: &str = "
+            in [index]
+            arb [index]
+            out rb+table
+            hlt
+        table: data 10, 20, 30
+        index: data 0
+    ";
+
+    fn covered_lookup() -> (Program, Coverage) {
+        let mut program = Program::new(&assemble(LOOKUP).unwrap());
+        program.start_coverage();
+        program.set_input(&[1]);
+        assert_eq!(vec![20], program.run());
+        let coverage = program.stop_coverage().unwrap();
+        (program, coverage)
+    }
+
+    #[test]
+    fn test_coverage() {
+        let (program, coverage) = covered_lookup();
+        assert!(program.coverage().is_none());
+
+        assert_eq!(Usage::EXECUTED, coverage.usage(0));
+        assert_eq!(Usage::OPERAND, coverage.usage(1));
+        assert_eq!(Usage::EXECUTED, coverage.usage(6));
+        assert!(coverage.usage(7).is_untouched());
+        assert_eq!(Usage::READ, coverage.usage(8));
+        assert_eq!(Usage::READ | Usage::WRITTEN, coverage.usage(10));
+        assert_eq!(9, coverage.cells().count());
+    }
+
+    #[test]
+    fn test_input_exhausted() {
+        // The input instruction isn't marked until it executes
+        let mut program = Program::new("3,3,99,0");
+        program.start_coverage();
+        assert!(program.try_run().is_err());
+        assert!(program.coverage().unwrap().usage(0).is_untouched());
+    }
+
+    #[test]
+    fn test_rendering() {
+        let (program, coverage) = covered_lookup();
+        assert_eq!(
+            "     0: #+#+#+\n     6: #.r.b\n",
+            coverage.render_grid(program.memory(), 6, false)
+        );
+        assert!(coverage
+            .render_grid(program.memory(), 6, true)
+            .starts_with("     0: \x1b[32m#"));
+
+        let listing = coverage.render_listing(program.memory());
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(8, lines.len());
+        assert_eq!(
+            "     2: arb  [10]                        ; executed, operand",
+            lines[1]
+        );
+        assert_eq!(
+            "     6: hlt                              ; executed",
+            lines[3]
+        );
+        assert_eq!(
+            "     7: data 10                          ; untouched",
+            lines[4]
+        );
+        assert_eq!(
+            "    10: data 1                           ; read, written",
+            lines[7]
+        );
+    }
+
+    #[test]
+    fn test_sparse_rendering() {
+        // Only the pages around the program and the high address are rendered
+        let mut program = Program::new("1101,1,2,100000000000,99");
+        program.start_coverage();
+        program.run();
+        let coverage = program.stop_coverage().unwrap();
+        let grid = coverage.render_grid(program.memory(), 64, false);
+        let lines: Vec<&str> = grid.lines().collect();
+        assert_eq!(PAGE_SIZE / 64 + 2, lines.len());
+        assert_eq!("     0: #+++#", &lines[0][..13]);
+        assert_eq!(GAP, lines[PAGE_SIZE / 64]);
+        assert_eq!("100000000000: w", lines[PAGE_SIZE / 64 + 1]);
+
+        let listing = coverage.render_listing(program.memory());
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(GAP, lines[PAGE_SIZE - 3]);
+        assert_eq!(
+            "100000000000: data 3                     ; written",
+            *lines.last().unwrap()
+        );
+    }
+}
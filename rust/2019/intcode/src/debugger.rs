@@ -0,0 +1,235 @@
+//! Breakpoints, watchpoints and single-stepping for a [Program]
+
+use {
+    super::{instruction::Opcode, Address, IntcodeError, Program, Step, Value},
+    std::fmt,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Breakpoint {
+    /// Stops before the instruction at the address is executed
+    Address(Address),
+    /// Stops before any instruction with the opcode is executed
+    Opcode(Opcode),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Address(address) => write!(f, "address {address}"),
+            Breakpoint::Opcode(opcode) => write!(f, "opcode {}", opcode.mnemonic()),
+        }
+    }
+}
+
+/// The reason that the debugger stopped executing the program
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// A single step was executed
+    Stepped,
+    /// The next instruction matches a breakpoint
+    Breakpoint(Breakpoint),
+    /// The last instruction changed the value at a watched address
+    Watchpoint {
+        address: Address,
+        old: Value,
+        new: Value,
+    },
+    /// The program is waiting for input
+    NeedsInput,
+    /// The program has halted
+    Halted,
+}
+
+/// Wraps a [Program], stepping through it while checking breakpoints and watchpoints
+pub struct Debugger {
+    program: Program,
+    breakpoints: Vec<Breakpoint>,
+    watchpoints: Vec<Address>,
+    output: Vec<Value>,
+}
+
+impl Debugger {
+    pub fn new(program: Program) -> Self {
+        Self {
+            program,
+            breakpoints: Vec::new(),
+            watchpoints: Vec::new(),
+            output: Vec::new(),
+        }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn program_mut(&mut self) -> &mut Program {
+        &mut self.program
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+    }
+
+    /// Removes a breakpoint, returning false if it wasn't set
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        let count = self.breakpoints.len();
+        self.breakpoints.retain(|&b| b != breakpoint);
+        self.breakpoints.len() != count
+    }
+
+    pub fn watchpoints(&self) -> &[Address] {
+        &self.watchpoints
+    }
+
+    pub fn add_watchpoint(&mut self, address: Address) {
+        if !self.watchpoints.contains(&address) {
+            self.watchpoints.push(address);
+        }
+    }
+
+    /// Removes a watchpoint, returning false if it wasn't set
+    pub fn remove_watchpoint(&mut self, address: Address) -> bool {
+        let count = self.watchpoints.len();
+        self.watchpoints.retain(|&a| a != address);
+        self.watchpoints.len() != count
+    }
+
+    /// Returns the output produced by the program since the last call to `take_output`
+    pub fn take_output(&mut self) -> Vec<Value> {
+        std::mem::take(&mut self.output)
+    }
+
+    fn read(&self, address: Address) -> Value {
+        self.program.peek(address)
+    }
+
+    /// Returns the first breakpoint matching the next instruction
+    pub fn breakpoint_at_ip(&self) -> Option<Breakpoint> {
+        let ip = self.program.ip();
+        let opcode = Opcode::from_value(self.read(ip));
+        self.breakpoints.iter().copied().find(|&b| match b {
+            Breakpoint::Address(address) => address == ip,
+            Breakpoint::Opcode(op) => Some(op) == opcode,
+        })
+    }
+
+    /// Executes a single instruction
+    pub fn step(&mut self) -> Result<Stop, IntcodeError> {
+        let watched: Vec<Value> = self.watchpoints.iter().map(|&a| self.read(a)).collect();
+
+        match self.program.try_step() {
+            Ok(Step::Continue) => {}
+            Ok(Step::Output(value)) => self.output.push(value),
+            Ok(Step::Halted) => return Ok(Stop::Halted),
+            Err(IntcodeError::InputExhausted { .. }) => return Ok(Stop::NeedsInput),
+            Err(error) => return Err(error),
+        }
+
+        for (&address, &old) in self.watchpoints.iter().zip(watched.iter()) {
+            let new = self.read(address);
+            if new != old {
+                return Ok(Stop::Watchpoint { address, old, new });
+            }
+        }
+
+        Ok(Stop::Stepped)
+    }
+
+    /// Executes instructions until a breakpoint or watchpoint is hit, or the program stops
+    ///
+    /// Breakpoints are checked after the first instruction is executed, so that calling `resume`
+    /// again after stopping at a breakpoint continues the program.
+    pub fn resume(&mut self) -> Result<Stop, IntcodeError> {
+        loop {
+            match self.step()? {
+                Stop::Stepped => {}
+                stop => return Ok(stop),
+            }
+            if let Some(breakpoint) = self.breakpoint_at_ip() {
+                return Ok(Stop::Breakpoint(breakpoint));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler::assemble, *};
+
+    fn debugger(source: &str) -> Debugger {
+        Debugger::new(Program::new(&assemble(source).unwrap()))
+    }
+
+    const COUNTDOWN: &str = "
+        start:
+            out [counter]
+            add [counter], #-1, [counter]
+            jt [counter], #start
+            hlt
+        counter: data 3
+    ";
+
+    #[test]
+    fn test_step() {
+        let mut debugger = debugger(COUNTDOWN);
+        assert_eq!(Ok(Stop::Stepped), debugger.step());
+        assert_eq!(vec![3], debugger.take_output());
+        assert_eq!(2, debugger.program().ip());
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut debugger = debugger(COUNTDOWN);
+        debugger.add_breakpoint(Breakpoint::Address(6));
+        debugger.add_breakpoint(Breakpoint::Opcode(Opcode::Halt));
+
+        assert_eq!(
+            Ok(Stop::Breakpoint(Breakpoint::Address(6))),
+            debugger.resume()
+        );
+        assert_eq!(
+            Ok(Stop::Breakpoint(Breakpoint::Address(6))),
+            debugger.resume()
+        );
+        assert!(debugger.remove_breakpoint(Breakpoint::Address(6)));
+        assert!(!debugger.remove_breakpoint(Breakpoint::Address(6)));
+        assert_eq!(
+            Ok(Stop::Breakpoint(Breakpoint::Opcode(Opcode::Halt))),
+            debugger.resume()
+        );
+        assert_eq!(Ok(Stop::Halted), debugger.resume());
+        assert_eq!(vec![3, 2, 1], debugger.take_output());
+    }
+
+    #[test]
+    fn test_watchpoints() {
+        let mut debugger = debugger(COUNTDOWN);
+        debugger.add_watchpoint(10);
+        assert_eq!(
+            Ok(Stop::Watchpoint {
+                address: 10,
+                old: 3,
+                new: 2
+            }),
+            debugger.resume()
+        );
+        assert_eq!(6, debugger.program().ip());
+        assert_eq!(vec![3], debugger.take_output());
+    }
+
+    #[test]
+    fn test_needs_input() {
+        let mut debugger = debugger("in [5]\nhlt");
+        assert_eq!(Ok(Stop::NeedsInput), debugger.resume());
+        debugger.program_mut().add_input(&[7]);
+        assert_eq!(Ok(Stop::Halted), debugger.resume());
+        assert_eq!(7, debugger.program().memory().get(5));
+    }
+}
@@ -0,0 +1,306 @@
+//! Peripherals that are driven by a program's output
+//!
+//! Many programs control a peripheral by outputting fixed-size frames, such as a screen's
+//! `x, y, tile` triples or a robot's colour and turn pairs, and read input that depends on the
+//! peripheral's state. A [Device] handles each complete frame of output and provides input when
+//! the program reads it, and a [Bus] runs a program with a device attached. Devices can be
+//! tested without a program by replaying a recorded output stream with [replay].
+//!
+//! ```
+//! use intcode::{
+//!     device::{Bus, Control, Device, Stop},
+//!     Program, Value,
+//! };
+//!
+//! // Sums pairs of output values, asking for input with the running total
+//! #[derive(Default)]
+//! struct Adder(Value);
+//!
+//! impl Device for Adder {
+//!     fn frame_size(&self) -> usize {
+//!         2
+//!     }
+//!
+//!     fn output(&mut self, frame: &[Value]) -> Control {
+//!         self.0 += frame[0] + frame[1];
+//!         Control::Continue
+//!     }
+//!
+//!     fn input(&mut self) -> Option<Value> {
+//!         Some(self.0)
+//!     }
+//! }
+//!
+//! let mut bus = Bus::new(Program::new("104,1,104,2,3,13,4,13,104,10,99,0,0,0"), Adder::default());
+//! assert_eq!(Ok(Stop::Halted), bus.run());
+//! assert_eq!(16, bus.device().0);
+//! ```
+
+use {
+    super::{Event, IntcodeError, Program, Value},
+    std::{error, fmt, mem},
+};
+
+/// Whether a program should keep running after its device has handled a frame
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Control {
+    Continue,
+    Stop,
+}
+
+pub trait Device {
+    /// The number of output values in each frame
+    ///
+    /// A frame size of zero is reported as [DeviceError::ZeroFrameSize].
+    fn frame_size(&self) -> usize;
+
+    /// Handles a complete frame of output
+    fn output(&mut self, frame: &[Value]) -> Control;
+
+    /// Provides the program's next input value, or None if the device has no input for it
+    fn input(&mut self) -> Option<Value> {
+        None
+    }
+}
+
+/// The reason that [Bus::run] returned
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The program has halted
+    Halted,
+    /// The device stopped the program after handling a frame
+    Stopped,
+    /// The program is waiting for input that the device didn't provide
+    NeedsInput,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeviceError {
+    /// The program failed
+    Program(IntcodeError),
+    /// The output ended part of the way through a frame
+    IncompleteFrame(Vec<Value>),
+    /// The device has a frame size of zero
+    ZeroFrameSize,
+}
+
+impl fmt::Display for DeviceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceError::Program(error) => write!(f, "{error}"),
+            DeviceError::IncompleteFrame(values) => {
+                write!(f, "Output ended with an incomplete frame: {values:?}")
+            }
+            DeviceError::ZeroFrameSize => write!(f, "The device has a frame size of zero"),
+        }
+    }
+}
+
+impl error::Error for DeviceError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            DeviceError::Program(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<IntcodeError> for DeviceError {
+    fn from(error: IntcodeError) -> Self {
+        DeviceError::Program(error)
+    }
+}
+
+/// A program with a device attached to its input and output
+pub struct Bus<D> {
+    program: Program,
+    device: D,
+    frame: Vec<Value>,
+}
+
+impl<D: Device> Bus<D> {
+    pub fn new(program: Program, device: D) -> Self {
+        Self {
+            program,
+            device,
+            frame: Vec::new(),
+        }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn program_mut(&mut self) -> &mut Program {
+        &mut self.program
+    }
+
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    pub fn device_mut(&mut self) -> &mut D {
+        &mut self.device
+    }
+
+    pub fn into_parts(self) -> (Program, D) {
+        (self.program, self.device)
+    }
+
+    /// Runs the program until it halts, the device stops it, or it needs input
+    ///
+    /// The device is asked for input whenever the program has none pending. Output that doesn't
+    /// complete a frame is kept until the program is resumed, unless the program halts.
+    pub fn run(&mut self) -> Result<Stop, DeviceError> {
+        loop {
+            match self.program.run_until_event()? {
+                Event::Output(value) => {
+                    let frame_size = self.device.frame_size();
+                    if frame_size == 0 {
+                        return Err(DeviceError::ZeroFrameSize);
+                    }
+                    self.frame.push(value);
+                    if self.frame.len() == frame_size {
+                        let control = self.device.output(&self.frame);
+                        self.frame.clear();
+                        if control == Control::Stop {
+                            return Ok(Stop::Stopped);
+                        }
+                    }
+                }
+                Event::NeedsInput => match self.device.input() {
+                    Some(value) => self.program.add_input(&[value]),
+                    None => return Ok(Stop::NeedsInput),
+                },
+                Event::Halted if self.frame.is_empty() => return Ok(Stop::Halted),
+                Event::Halted => {
+                    return Err(DeviceError::IncompleteFrame(mem::take(&mut self.frame)))
+                }
+            }
+        }
+    }
+}
+
+/// Passes recorded output to a device a frame at a time, until the output ends or the device
+/// stops
+pub fn replay(device: &mut impl Device, output: &[Value]) -> Result<Control, DeviceError> {
+    let frame_size = device.frame_size();
+    if frame_size == 0 {
+        return Err(DeviceError::ZeroFrameSize);
+    }
+    for frame in output.chunks(frame_size) {
+        if frame.len() < frame_size {
+            return Err(DeviceError::IncompleteFrame(frame.to_vec()));
+        }
+        if device.output(frame) == Control::Stop {
+            return Ok(Control::Stop);
+        }
+    }
+    Ok(Control::Continue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler::assemble, *};
+
+    /// Records each frame, stopping at a frame starting with 0
+    #[derive(Default)]
+    struct Recorder {
+        frames: Vec<Vec<Value>>,
+        inputs: Vec<Value>,
+    }
+
+    impl Device for Recorder {
+        fn frame_size(&self) -> usize {
+            3
+        }
+
+        fn output(&mut self, frame: &[Value]) -> Control {
+            self.frames.push(frame.to_vec());
+            match frame[0] {
+                0 => Control::Stop,
+                _ => Control::Continue,
+            }
+        }
+
+        fn input(&mut self) -> Option<Value> {
+            self.inputs.pop()
+        }
+    }
+
+    // Outputs a frame of its input followed by 8 and 9 for each input value
+    const FRAMES: &str = "
+        loop:
+            in [x]
+            out [x]
+            out #8
+            out #9
+            jt #1, #loop
+        x: data 0
+    ";
+
+    #[test]
+    fn test_bus() {
+        let device = Recorder {
+            inputs: vec![0, 2, 1],
+            ..Default::default()
+        };
+        let mut bus = Bus::new(Program::new(&assemble(FRAMES).unwrap()), device);
+        assert_eq!(Ok(Stop::Stopped), bus.run());
+        assert_eq!(
+            vec![vec![1, 8, 9], vec![2, 8, 9], vec![0, 8, 9]],
+            bus.device().frames
+        );
+
+        // Without more input from the device the program waits, and can be given input directly
+        assert_eq!(Ok(Stop::NeedsInput), bus.run());
+        bus.program_mut().add_input(&[5]);
+        bus.device_mut().inputs.push(0);
+        assert_eq!(Ok(Stop::Stopped), bus.run());
+        let (_, device) = bus.into_parts();
+        assert_eq!(&[vec![5, 8, 9], vec![0, 8, 9]], &device.frames[3..]);
+    }
+
+    #[test]
+    fn test_incomplete_frame() {
+        let mut bus = Bus::new(Program::new("104,1,104,2,99"), Recorder::default());
+        assert_eq!(Err(DeviceError::IncompleteFrame(vec![1, 2])), bus.run());
+
+        let mut bus = Bus::new(Program::new("104,1,3,0,99"), Recorder::default());
+        assert_eq!(Ok(Stop::NeedsInput), bus.run());
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut device = Recorder::default();
+        assert_eq!(
+            Ok(Control::Continue),
+            replay(&mut device, &[1, 2, 3, 4, 5, 6])
+        );
+        assert_eq!(Ok(Control::Stop), replay(&mut device, &[0, 1, 2, 3, 4, 5]));
+        assert_eq!(3, device.frames.len());
+        assert_eq!(
+            Err(DeviceError::IncompleteFrame(vec![7])),
+            replay(&mut device, &[4, 5, 6, 7])
+        );
+    }
+
+    #[test]
+    fn test_zero_frame_size() {
+        struct Empty;
+
+        impl Device for Empty {
+            fn frame_size(&self) -> usize {
+                0
+            }
+
+            fn output(&mut self, _: &[Value]) -> Control {
+                Control::Continue
+            }
+        }
+
+        assert_eq!(Err(DeviceError::ZeroFrameSize), replay(&mut Empty, &[1, 2]));
+        let mut bus = Bus::new(Program::new("104,1,99"), Empty);
+        assert_eq!(Err(DeviceError::ZeroFrameSize), bus.run());
+    }
+}
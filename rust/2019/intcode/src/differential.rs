@@ -0,0 +1,342 @@
+//! Differential testing of the VM against the [reference](mod@super::reference) interpreter
+//!
+//! Programs are generated from random well-formed instructions, with operands that mostly refer
+//! to a block of data following the code. Both interpreters run a program with the same input
+//! and step budget, and any difference in their output, final memory, instruction pointer,
+//! relative base or error is reported as a [Divergence]. A divergence can then be shrunk to a
+//! minimal program and input that still diverge.
+//!
+//! ```
+//! use intcode::differential::{compare, random_program, Rng};
+//!
+//! let mut rng = Rng::new(2019);
+//! for _ in 0..100 {
+//!     let (program, input) = random_program(&mut rng, 20);
+//!     assert_eq!(None, compare(&program, &input, 1000));
+//! }
+//! ```
+
+use {
+    super::{
+        instruction::{Instruction, Opcode, ParameterMode},
+        reference::{self, Run},
+        Address, Program, Step, Value,
+    },
+    std::{collections::BTreeMap, ops::Range},
+};
+
+/// The number of data cells following a generated program's code
+const DATA_SIZE: Value = 16;
+
+/// A xorshift random number generator, so that generated programs can be reproduced from a seed
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // The state must be non-zero
+        Self(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Returns a value in the range, which mustn't be empty
+    pub fn range(&mut self, range: Range<Value>) -> Value {
+        range.start + (self.next_u64() % (range.end - range.start) as u64) as Value
+    }
+
+    /// Returns true with the given percentage chance
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    fn choose<T: Copy>(&mut self, values: &[T]) -> T {
+        values[(self.next_u64() % values.len() as u64) as usize]
+    }
+}
+
+/// Generates a program and its input
+///
+/// The program has `instructions` random instructions followed by a halt and a block of data.
+/// Writes usually go to the data, jumps usually go to the start of an instruction, and the
+/// relative base is usually adjusted by small amounts, but any of them can end up elsewhere.
+pub fn random_program(rng: &mut Rng, instructions: usize) -> (Vec<Value>, Vec<Value>) {
+    let opcodes: Vec<Opcode> = (0..instructions)
+        .map(|_| rng.choose(&Opcode::ALL[..9]))
+        .chain([Opcode::Halt])
+        .collect();
+    let starts: Vec<Value> = opcodes
+        .iter()
+        .scan(0, |address, opcode| {
+            let start = *address;
+            *address += opcode.parameter_count() as Value + 1;
+            Some(start)
+        })
+        .collect();
+    let code_size = starts.last().unwrap() + 1;
+    let size = code_size + DATA_SIZE;
+
+    let mut program = Vec::new();
+    for opcode in opcodes {
+        let mut modes = [ParameterMode::Position; 3];
+        let mut operands = Vec::new();
+        for (i, mode) in modes.iter_mut().take(opcode.parameter_count()).enumerate() {
+            let id = i + 1;
+            let jump_target = matches!(opcode, Opcode::JumpIfTrue | Opcode::JumpIfFalse) && id == 2;
+            let small_offset = opcode == Opcode::AdjustRelativeBase;
+
+            let (operand_mode, operand) = if jump_target && rng.chance(75) {
+                (ParameterMode::Immediate, rng.choose(&starts))
+            } else if small_offset && rng.chance(80) {
+                (ParameterMode::Immediate, rng.range(-DATA_SIZE..DATA_SIZE))
+            } else {
+                let writes = opcode.writes_parameter(id);
+                match rng.range(0..if writes { 2 } else { 3 }) {
+                    0 if !writes || rng.chance(85) => {
+                        (ParameterMode::Position, rng.range(code_size..size))
+                    }
+                    0 => (ParameterMode::Position, rng.range(0..size)),
+                    1 => (ParameterMode::Relative, rng.range(-4..size)),
+                    _ => (ParameterMode::Immediate, rng.range(-100..100)),
+                }
+            };
+            *mode = operand_mode;
+            operands.push(operand);
+        }
+        program.push(Instruction { opcode, modes }.encode());
+        program.extend(operands);
+    }
+    program.extend((0..DATA_SIZE).map(|_| rng.range(-100..100)));
+
+    let input = (0..rng.range(0..4)).map(|_| rng.range(-100..100)).collect();
+    (program, input)
+}
+
+/// A program whose run differed between the VM and the reference interpreter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    pub program: Vec<Value>,
+    pub input: Vec<Value>,
+    pub budget: u64,
+    /// The run of the reference interpreter
+    pub expected: Run,
+    /// The run of the VM
+    pub actual: Run,
+}
+
+/// Runs a program on the VM, returning its state in the same form as the reference interpreter
+pub fn run_vm(program: &[Value], input: &[Value], budget: u64) -> Run {
+    let mut vm = Program::from(program);
+    vm.set_input(input);
+    vm.set_step_budget(Some(budget));
+
+    let mut output = Vec::new();
+    let result = loop {
+        match vm.try_step() {
+            Ok(Step::Continue) => {}
+            Ok(Step::Output(value)) => output.push(value),
+            Ok(Step::Halted) => break Ok(()),
+            Err(error) => break Err(error),
+        }
+    };
+
+    let memory: BTreeMap<Address, Value> = vm
+        .memory()
+        .pages()
+        .into_iter()
+        .flat_map(|(index, page)| {
+            let start = index * page.len() as Address;
+            (start..).zip(page.iter().copied())
+        })
+        .filter(|&(_, value)| value != 0)
+        .collect();
+    Run {
+        output,
+        memory,
+        len: vm.memory().len(),
+        ip: vm.ip(),
+        relative_base: vm.relative_base(),
+        result,
+    }
+}
+
+/// Runs a program on both interpreters, returning the divergence if their runs differ
+pub fn compare(program: &[Value], input: &[Value], budget: u64) -> Option<Divergence> {
+    let expected = reference::run(program, input, budget);
+    let actual = run_vm(program, input, budget);
+    (expected != actual).then(|| Divergence {
+        program: program.to_vec(),
+        input: input.to_vec(),
+        budget,
+        expected,
+        actual,
+    })
+}
+
+/// Shrinks a program and its input while the predicate holds for them
+///
+/// Runs of values are removed, starting with large runs, and then single values are moved
+/// towards zero, until no change keeps the predicate true. Removing values shifts the addresses
+/// of those that follow, so the result may fail in a different way to the original.
+pub fn shrink(
+    program: &[Value],
+    input: &[Value],
+    mut predicate: impl FnMut(&[Value], &[Value]) -> bool,
+) -> (Vec<Value>, Vec<Value>) {
+    let mut values = [program.to_vec(), input.to_vec()];
+    let mut shrunk = true;
+    while shrunk {
+        shrunk = false;
+        for which in 0..2 {
+            let mut size = values[which].len();
+            while size > 0 {
+                let mut start = 0;
+                while start + size <= values[which].len() {
+                    let mut candidate = values.clone();
+                    candidate[which].drain(start..start + size);
+                    if predicate(&candidate[0], &candidate[1]) {
+                        values = candidate;
+                        shrunk = true;
+                    } else {
+                        start += size;
+                    }
+                }
+                size /= 2;
+            }
+
+            for i in 0..values[which].len() {
+                let value = values[which][i];
+                for smaller in [0, value / 2, value - value.signum()] {
+                    if smaller == value {
+                        continue;
+                    }
+                    let mut candidate = values.clone();
+                    candidate[which][i] = smaller;
+                    if predicate(&candidate[0], &candidate[1]) {
+                        values = candidate;
+                        shrunk = true;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    let [program, input] = values;
+    (program, input)
+}
+
+impl Divergence {
+    /// Shrinks the divergence to a minimal program and input that still diverge
+    pub fn shrink(&self) -> Divergence {
+        let (program, input) = shrink(&self.program, &self.input, |program, input| {
+            compare(program, input, self.budget).is_some()
+        });
+        compare(&program, &input, self.budget).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{parse_values, IntcodeError},
+        *,
+    };
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(0);
+        let values: Vec<Value> = (0..1000).map(|_| rng.range(-3..3)).collect();
+        assert!(values.iter().all(|value| (-3..3).contains(value)));
+        assert!((-3..3).all(|x| values.contains(&x)));
+
+        let mut other = Rng::new(0);
+        let mut rng = Rng::new(0);
+        assert_eq!(rng.next_u64(), other.next_u64());
+        assert_ne!(rng.next_u64(), Rng::new(1).next_u64());
+    }
+
+    #[test]
+    fn test_random_program() {
+        let mut rng = Rng::new(5);
+        for _ in 0..100 {
+            let (program, _) = random_program(&mut rng, 10);
+            // Every instruction is well-formed, and the code ends with a halt
+            let mut address = 0;
+            for _ in 0..10 {
+                let instruction = Instruction::decode(program[address]).unwrap();
+                assert_ne!(Opcode::Halt, instruction.opcode);
+                address += instruction.size();
+            }
+            assert_eq!(99, program[address]);
+            assert_eq!(address + 1 + DATA_SIZE as usize, program.len());
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut rng = Rng::new(9);
+        let mut halted = 0;
+        for _ in 0..500 {
+            let (program, input) = random_program(&mut rng, 15);
+            assert_eq!(None, compare(&program, &input, 1000), "{program:?}");
+            if reference::run(&program, &input, 1000).result.is_ok() {
+                halted += 1;
+            }
+        }
+        // Enough programs run to completion to exercise more than their first few instructions
+        assert!(halted > 50, "Only {halted} programs halted");
+
+        let boost = parse_values(include_str!("../../src/bin/input/9")).unwrap();
+        assert_eq!(None, compare(&boost, &[1], 10_000));
+    }
+
+    #[test]
+    fn test_shrink() {
+        // Keep any program that outputs 42 at some point
+        let outputs_42 =
+            |program: &[Value], input: &[Value]| run_vm(program, input, 100).output.contains(&42);
+        // Output 42 between instructions that don't affect it
+        let program = [1101, 1, 1, 20, 1002, 20, 3, 20, 104, 42, 3, 21, 99];
+        assert!(outputs_42(&program, &[5]));
+
+        let (program, input) = shrink(&program, &[5], outputs_42);
+        assert_eq!((vec![104, 42], vec![]), (program, input));
+    }
+
+    #[test]
+    fn test_shrink_overflow() {
+        // Keep any program that overflows in the reference interpreter
+        let overflows = |program: &[Value], input: &[Value]| {
+            matches!(
+                reference::run(program, input, 100).result,
+                Err(IntcodeError::Overflow { .. })
+            )
+        };
+        // Multiply 2^32 by itself between instructions that don't affect it
+        let program = [
+            1101, 1, 1, 20, 1002, 20, 3, 20, 1102, 4294967296, 4294967296, 21, 104, 42, 99, 0,
+        ];
+        assert!(overflows(&program, &[5]));
+        assert_eq!(None, compare(&program, &[5], 100));
+
+        // The VM reports the same overflow for the minimal program
+        let (program, input) = shrink(&program, &[5], overflows);
+        assert_eq!(vec![1102, 2147483648, 4294967296], program);
+        assert!(input.is_empty());
+        assert_eq!(None, compare(&program, &input, 100));
+        assert_eq!(
+            Err(IntcodeError::Overflow {
+                ip: 0,
+                opcode: 1102,
+                relative_base: 0
+            }),
+            run_vm(&program, &input, 100).result
+        );
+    }
+}
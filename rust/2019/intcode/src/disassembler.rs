@@ -0,0 +1,186 @@
+//! Decoding memory into a listing of instructions and data
+
+use {
+    super::{
+        instruction::{Instruction, ParameterMode},
+        memory::Memory,
+        Address, Value,
+    },
+    std::{fmt, ops::Range},
+};
+
+/// A decoded instruction parameter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Operand {
+    pub mode: ParameterMode,
+    pub value: Value,
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            ParameterMode::Position => write!(f, "[{}]", self.value),
+            ParameterMode::Immediate => write!(f, "#{}", self.value),
+            ParameterMode::Relative if self.value < 0 => write!(f, "rb{}", self.value),
+            ParameterMode::Relative => write!(f, "rb+{}", self.value),
+        }
+    }
+}
+
+/// A line in a disassembly listing
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Line {
+    Instruction {
+        address: Address,
+        instruction: Instruction,
+        operands: Vec<Operand>,
+    },
+    Data {
+        address: Address,
+        value: Value,
+    },
+}
+
+impl Line {
+    pub fn address(&self) -> Address {
+        match self {
+            Line::Instruction { address, .. } | Line::Data { address, .. } => *address,
+        }
+    }
+
+    /// The number of memory cells covered by the line
+    pub fn size(&self) -> Address {
+        match self {
+            Line::Instruction { instruction, .. } => instruction.size() as Address,
+            Line::Data { .. } => 1,
+        }
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Instruction {
+                address,
+                instruction,
+                operands,
+            } => {
+                let mnemonic = instruction.opcode.mnemonic();
+                if operands.is_empty() {
+                    return write!(f, "{address:>6}: {mnemonic}");
+                }
+                write!(f, "{address:>6}: {mnemonic:<4}")?;
+                for (i, operand) in operands.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{separator}{operand}")?;
+                }
+                Ok(())
+            }
+            Line::Data { address, value } => write!(f, "{address:>6}: data {value}"),
+        }
+    }
+}
+
+/// Decodes the line starting at the given address
+///
+/// Values that can't be decoded as an instruction, or that have unused mode digits, or
+/// instructions with parameters that would extend past the end of memory, are decoded as data.
+/// This ensures that the listing can be reassembled into the original values.
+pub fn decode_line(memory: &Memory, address: Address) -> Line {
+    let value = memory.get(address);
+
+    match Instruction::decode(value) {
+        Some(instruction)
+            if instruction.encode() == value
+                && address.saturating_add(instruction.size() as Address) <= memory.len() =>
+        {
+            Line::Instruction {
+                address,
+                instruction,
+                operands: instruction
+                    .modes
+                    .iter()
+                    .take(instruction.opcode.parameter_count())
+                    .enumerate()
+                    .map(|(i, &mode)| Operand {
+                        mode,
+                        value: memory.get(address + i as Address + 1),
+                    })
+                    .collect(),
+            }
+        }
+        _ => Line::Data { address, value },
+    }
+}
+
+/// Disassembles memory by decoding lines in sequence from the start of the range
+///
+/// The range is clamped to the size of memory, and the final line may extend beyond its end.
+pub fn disassemble(memory: &Memory, range: Range<Address>) -> Vec<Line> {
+    let end = range.end.min(memory.len());
+    let mut address = range.start;
+    let mut result = Vec::new();
+
+    while address < end {
+        let line = decode_line(memory, address);
+        address += line.size();
+        result.push(line);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(memory: &[Value], range: Range<Address>) -> Vec<String> {
+        disassemble(&Memory::from(memory), range)
+            .iter()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let memory = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        assert_eq!(
+            vec![
+                "     0: arb  #1",
+                "     2: out  rb-1",
+                "     4: add  [100], #1, [100]",
+                "     8: eq   [100], #16, [101]",
+                "    12: jf   [101], #0",
+                "    15: hlt",
+            ],
+            listing(&memory, 0..Address::MAX)
+        );
+    }
+
+    #[test]
+    fn test_data() {
+        let memory = [1002, 4, 3, 4, 33, 11101, 1, 2, 1099];
+        assert_eq!(
+            vec![
+                "     0: mul  [4], #3, [4]",
+                "     4: data 33",
+                "     5: data 11101",
+                "     6: data 1",
+                "     7: data 2",
+                "     8: data 1099",
+            ],
+            listing(&memory, 0..Address::MAX)
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let memory = [1002, 4, 3, 4, 33, 22201, 1, 2, 3];
+        assert_eq!(
+            vec!["     4: data 33", "     5: add  rb+1, rb+2, rb+3"],
+            listing(&memory, 4..6)
+        );
+    }
+}
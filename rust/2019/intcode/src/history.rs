@@ -0,0 +1,275 @@
+//! Reverse execution
+//!
+//! While recording, each executed instruction adds an entry to an undo log holding the state that
+//! it changed: the instruction pointer, the relative base, any input that it consumed, and the
+//! previous value of the memory cell that it wrote to. Stepping back removes entries from the end
+//! of the log and restores that state. The size of the log is capped, with the oldest entries
+//! being discarded once the cap is reached.
+//!
+//! Only the program's execution state is rewound, profiles and coverage keep the instructions
+//! that were undone.
+
+use {
+    super::{limits::LoopDetector, Address, Program, Step, Value},
+    std::{collections::VecDeque, mem},
+};
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct UndoEntry {
+    ip: Address,
+    relative_base: Value,
+    inputs_read: u64,
+    /// The address that was written to, its previous value, and the previous length of memory
+    write: Option<(Address, Value, Address)>,
+    /// The input value that was consumed
+    input: Option<Value>,
+    /// The index of the output that was produced, counting from the start of recording
+    output: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct History {
+    entries: VecDeque<UndoEntry>,
+    max_entries: usize,
+    outputs: u64,
+}
+
+impl Program {
+    /// Starts recording an undo log, discarding any existing log
+    ///
+    /// The log uses at most `max_bytes` of memory, once it's full the oldest steps can no longer
+    /// be undone.
+    pub fn start_recording(&mut self, max_bytes: usize) {
+        self.history = Some(Box::new(History {
+            entries: VecDeque::new(),
+            max_entries: max_bytes / mem::size_of::<UndoEntry>(),
+            outputs: 0,
+        }));
+    }
+
+    pub fn stop_recording(&mut self) {
+        self.history = None;
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.history.is_some()
+    }
+
+    /// The number of steps that can be undone
+    pub fn recorded_steps(&self) -> usize {
+        self.history
+            .as_ref()
+            .map_or(0, |history| history.entries.len())
+    }
+
+    /// The state that the next instruction might change, to be recorded once it has executed
+    pub(super) fn undo_entry(&self) -> UndoEntry {
+        UndoEntry {
+            ip: self.ip,
+            relative_base: self.relative_base,
+            inputs_read: self.inputs_read,
+            write: self
+                .write_address()
+                .map(|address| (address, self.read(address), self.state.len())),
+            input: None,
+            output: None,
+        }
+    }
+
+    pub(super) fn record_undo_entry(&mut self, mut entry: UndoEntry, step: Step) {
+        if self.inputs_read != entry.inputs_read {
+            // The input was written to the instruction's write address
+            entry.input = entry.write.map(|(address, ..)| self.read(address));
+        }
+        let Some(history) = &mut self.history else {
+            return;
+        };
+        if let Step::Output(_) = step {
+            entry.output = Some(history.outputs);
+            history.outputs += 1;
+        }
+
+        if history.max_entries == 0 {
+            return;
+        }
+        if history.entries.len() == history.max_entries {
+            history.entries.pop_front();
+        }
+        history.entries.push_back(entry);
+    }
+
+    /// Undoes the last `count` steps, which must have been recorded
+    fn undo(&mut self, count: usize) {
+        for _ in 0..count {
+            let history = self.history.as_mut().unwrap();
+            let entry = history.entries.pop_back().unwrap();
+            if entry.output.is_some() {
+                history.outputs -= 1;
+            }
+
+            if let Some((address, value, len)) = entry.write {
+                self.write(address, value);
+                self.state.set_len(len);
+            }
+            self.ip = entry.ip;
+            self.relative_base = entry.relative_base;
+            if let Some(value) = entry.input {
+                // The input might have been replaced since it was consumed
+                if self.current_input > 0 && self.inputs[self.current_input - 1] == value {
+                    self.current_input -= 1;
+                } else {
+                    self.inputs.insert(self.current_input, value);
+                }
+                self.inputs_read -= 1;
+            }
+            self.steps = self.steps.saturating_sub(1);
+        }
+
+        // Earlier states will be repeated, so they mustn't be mistaken for a loop
+        if count > 0 && self.loop_detector.is_some() {
+            self.loop_detector = Some(LoopDetector::new(&self.state));
+        }
+    }
+
+    /// Undoes the last recorded step, returning false if there's no step to undo
+    pub fn step_back(&mut self) -> bool {
+        if self.recorded_steps() == 0 {
+            return false;
+        }
+        self.undo(1);
+        true
+    }
+
+    /// Steps back until the instruction at the address is about to be executed
+    ///
+    /// At least one step is undone. If the instruction wasn't executed within the recorded
+    /// steps then the program is left unchanged and false is returned.
+    pub fn run_back_to(&mut self, address: Address) -> bool {
+        self.undo_until(|entry| entry.ip == address)
+    }
+
+    /// Steps back until the output with the given index is about to be produced
+    ///
+    /// Outputs are counted from the start of recording. If the output isn't within the recorded
+    /// steps then the program is left unchanged and false is returned.
+    pub fn rewind_to_output(&mut self, index: u64) -> bool {
+        self.undo_until(|entry| entry.output == Some(index))
+    }
+
+    /// Undoes steps up to and including the most recent one that matches the predicate
+    fn undo_until(&mut self, predicate: impl Fn(&UndoEntry) -> bool) -> bool {
+        let Some(history) = &self.history else {
+            return false;
+        };
+        match history.entries.iter().rev().position(predicate) {
+            Some(position) => {
+                self.undo(position + 1);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler::assemble, super::input, super::Event, *};
+
+    // Doubles the input 3 times, outputting each result
+    const DOUBLER: &str = "
+            in [x]
+        loop:
+            add [x], [x], [x]
+            out [x]
+            add [count], #-1, [count]
+            jt [count], #loop
+            hlt
+        x: data 0
+        count: data 3
+    ";
+
+    fn doubler() -> Program {
+        let mut program = Program::new(&assemble(DOUBLER).unwrap());
+        program.start_recording(1 << 20);
+        program.set_input(&[1]);
+        program
+    }
+
+    #[test]
+    fn test_step_back() {
+        let mut program = doubler();
+        let initial = program.clone();
+        assert_eq!(vec![2, 4, 8], program.run());
+        assert_eq!(program.steps() as usize, program.recorded_steps());
+
+        while program.step_back() {}
+        assert_eq!(initial.memory(), program.memory());
+        assert_eq!(initial.ip(), program.ip());
+        assert_eq!(0, program.steps());
+        assert_eq!(vec![2, 4, 8], program.run());
+    }
+
+    #[test]
+    fn test_run_back_to() {
+        let mut program = doubler();
+        assert_eq!(Ok(Event::Output(2)), program.run_until_event());
+        assert_eq!(Ok(Event::Output(4)), program.run_until_event());
+
+        // An address that wasn't executed leaves the program unchanged
+        let steps = program.recorded_steps();
+        assert!(!program.run_back_to(100));
+        assert_eq!(steps, program.recorded_steps());
+
+        assert!(program.run_back_to(2));
+        assert_eq!(2, program.ip());
+        assert_eq!(2, program.memory().get(16));
+        assert!(program.run_back_to(0));
+        assert_eq!(&[1], program.pending_inputs());
+        assert_eq!(vec![2, 4, 8], program.run());
+    }
+
+    #[test]
+    fn test_rewind_to_output() {
+        let mut program = doubler();
+        assert_eq!(vec![2, 4, 8], program.run());
+        assert!(!program.rewind_to_output(3));
+
+        assert!(program.rewind_to_output(1));
+        assert_eq!(Ok(Event::Output(4)), program.run_until_event());
+        assert!(program.rewind_to_output(0));
+        assert_eq!(vec![2, 4, 8], program.run());
+    }
+
+    #[test]
+    fn test_replaced_input() {
+        // Input that's been replaced is restored when stepping back over its input instruction
+        let mut program = doubler();
+        assert_eq!(Ok(Event::Output(2)), program.run_until_event());
+        program.set_input(&[5]);
+        assert!(program.run_back_to(0));
+        assert_eq!(&[1, 5], program.pending_inputs());
+    }
+
+    #[test]
+    fn test_source_input() {
+        // Input taken from a source is made pending when stepping back over its input instruction
+        let mut program = doubler();
+        program.set_input(&[]);
+        program.set_input_source(input::from_iter([3]));
+        assert_eq!(Ok(Event::Output(6)), program.run_until_event());
+        assert!(program.run_back_to(0));
+        assert_eq!(&[3], program.pending_inputs());
+        assert_eq!(vec![6, 12, 24], program.run());
+    }
+
+    #[test]
+    fn test_cap() {
+        let mut program = doubler();
+        program.start_recording(3 * mem::size_of::<UndoEntry>());
+        assert_eq!(vec![2, 4, 8], program.run());
+        assert_eq!(3, program.recorded_steps());
+        assert!(!program.rewind_to_output(1));
+        assert!(program.rewind_to_output(2));
+        assert_eq!(Ok(Event::Output(8)), program.run_until_event());
+    }
+}
@@ -0,0 +1,225 @@
+//! Sources of input that are read from as a program needs it
+//!
+//! An [InputSource] is asked for a value whenever an input instruction executes and the
+//! program's pending input is empty, so input can be computed from the latest state of whatever
+//! the program is controlling rather than being provided ahead of time.
+//!
+//! ```
+//! use intcode::Program;
+//!
+//! // Outputs its input multiplied by 3, forever
+//! let mut program = Program::new("3,11,1002,11,3,11,4,11,1105,1,0,0");
+//! let mut next = 0;
+//! program.set_input_source(move || {
+//!     next += 1;
+//!     Some(next)
+//! });
+//! assert_eq!(vec![3, 6, 9], program.take(3).collect::<Vec<_>>());
+//! ```
+//!
+//! Sources are provided for closures, iterators, channels and queues. Input that's been added to
+//! the program with [Program::add_input] is read before the source is asked for more.
+
+use {
+    super::{Program, Value},
+    std::{collections::VecDeque, sync::mpsc::Receiver},
+};
+
+/// Provides a program's input one value at a time, as each input instruction executes
+///
+/// A program's source must be [Send], so that the program can still be run on its own thread,
+/// see [threaded](super::threaded). State can be shared with the code driving the program
+/// through types like [Arc](std::sync::Arc) and [Mutex](std::sync::Mutex).
+pub trait InputSource {
+    /// Returns the next input value, or None if there's no input available
+    ///
+    /// When None is returned the program stops with
+    /// [IntcodeError::InputExhausted](super::IntcodeError::InputExhausted), and the source will
+    /// be asked again if the program is resumed.
+    fn next_input(&mut self) -> Option<Value>;
+}
+
+impl<F: FnMut() -> Option<Value>> InputSource for F {
+    fn next_input(&mut self) -> Option<Value> {
+        self()
+    }
+}
+
+/// Blocks until a value is received, there's no more input once the sender has been dropped
+impl InputSource for Receiver<Value> {
+    fn next_input(&mut self) -> Option<Value> {
+        self.recv().ok()
+    }
+}
+
+impl InputSource for VecDeque<Value> {
+    fn next_input(&mut self) -> Option<Value> {
+        self.pop_front()
+    }
+}
+
+/// An input source that reads from an iterator, see [from_iter]
+#[derive(Clone, Debug)]
+pub struct FromIter<I>(I);
+
+impl<I: Iterator<Item = Value>> InputSource for FromIter<I> {
+    fn next_input(&mut self) -> Option<Value> {
+        self.0.next()
+    }
+}
+
+/// Creates an input source that reads values from an iterator
+pub fn from_iter<I: IntoIterator<Item = Value>>(iter: I) -> FromIter<I::IntoIter> {
+    FromIter(iter.into_iter())
+}
+
+impl Program {
+    /// Reads input from the source whenever the program's pending input runs out
+    ///
+    /// The source replaces any existing source. It isn't shared with clones of the program, and
+    /// isn't included in snapshots.
+    pub fn set_input_source(&mut self, source: impl InputSource + Send + 'static) {
+        self.input_source = Some(Box::new(source));
+    }
+
+    /// Drops the input source, so that the program waits for input once its pending input runs
+    /// out
+    pub fn remove_input_source(&mut self) {
+        self.input_source = None;
+    }
+
+    /// Returns true if the program has an input source, see [Program::set_input_source]
+    pub fn has_input_source(&self) -> bool {
+        self.input_source.is_some()
+    }
+
+    /// Consumes the next input value, taking it from the source if there's no pending input
+    ///
+    /// The source is asked for a single value each time, which is only done once the input
+    /// instruction can't fail, so values taken from the source are never buffered or lost.
+    pub(super) fn read_input(&mut self) -> Option<Value> {
+        let value = match self.inputs.get(self.current_input) {
+            Some(&value) => {
+                self.current_input += 1;
+                value
+            }
+            None => self.input_source.as_mut()?.next_input()?,
+        };
+        self.inputs_read += 1;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            super::{Event, IntcodeError},
+            *,
+        },
+        std::{
+            sync::{
+                atomic::{AtomicI64, Ordering},
+                mpsc::channel,
+                Arc,
+            },
+            thread,
+        },
+    };
+
+    // Outputs the sum of pairs of input values until it reads a 0
+    const ADDER: &str = "3,17,1006,17,16,3,18,1,17,18,19,4,19,1105,1,0,99,0,0,0";
+
+    #[test]
+    fn test_closure() {
+        // The input depends on the program's latest output
+        let mut program = Program::new("3,13,4,13,1001,13,1,13,4,13,1105,1,0,0");
+        let mut outputs = Vec::new();
+        let last = Arc::new(AtomicI64::new(5));
+        program.set_input_source({
+            let last = last.clone();
+            move || Some(last.load(Ordering::Relaxed) * 2)
+        });
+        for _ in 0..3 {
+            let value = program.next().unwrap();
+            outputs.push(value);
+            last.store(program.next().unwrap(), Ordering::Relaxed);
+        }
+        assert_eq!(vec![10, 22, 46], outputs);
+    }
+
+    #[test]
+    fn test_one_value_per_input() {
+        // The source is only asked for a value when an input instruction executes
+        let mut program = Program::new(ADDER);
+        let requests = Arc::new(AtomicI64::new(0));
+        program.set_input_source({
+            let requests = requests.clone();
+            move || Some(requests.fetch_add(1, Ordering::Relaxed) + 1)
+        });
+        assert_eq!(Ok(Event::Output(3)), program.run_until_event());
+        assert_eq!(2, requests.load(Ordering::Relaxed));
+        assert!(program.pending_inputs().is_empty());
+
+        // An input instruction that can't write its input doesn't take a value from the source
+        let mut program = Program::new("203,-1,99");
+        program.set_input_source(from_iter([7]));
+        assert_eq!(
+            Err(IntcodeError::NegativeAddress {
+                ip: 0,
+                opcode: 203,
+                relative_base: 0,
+                address: -1
+            }),
+            program.try_step()
+        );
+        program.write(1, 1);
+        assert_eq!(Ok(vec![]), program.try_run());
+        assert_eq!(7, program.read(1));
+    }
+
+    #[test]
+    fn test_pending_input_first() {
+        let mut program = Program::new("3,7,4,7,1105,1,0,0");
+        program.set_input(&[1, 2]);
+        program.set_input_source(from_iter([3, 4]));
+        assert!(program.has_input_source());
+        assert_eq!(
+            vec![1, 2, 3, 4],
+            program.by_ref().take(4).collect::<Vec<_>>()
+        );
+
+        // Once the source is exhausted the program waits for more input
+        assert_eq!(Ok(Event::NeedsInput), program.run_until_event());
+        program.add_input(&[5]);
+        assert_eq!(Ok(Event::Output(5)), program.run_until_event());
+
+        program.remove_input_source();
+        assert!(!program.has_input_source());
+        assert_eq!(Ok(Event::NeedsInput), program.run_until_event());
+    }
+
+    #[test]
+    fn test_queue() {
+        let mut program = Program::new(ADDER);
+        program.set_input_source(VecDeque::from([1, 2, 3, 4, 0]));
+        assert_eq!(Ok(vec![3, 7]), program.try_run());
+    }
+
+    #[test]
+    fn test_channel() {
+        let (sender, receiver) = channel();
+        let mut program = Program::new(ADDER);
+        program.set_input_source(receiver);
+        let producer = thread::spawn(move || {
+            for value in [10, 20, 30, 40] {
+                sender.send(value).unwrap();
+            }
+        });
+        assert_eq!(vec![30, 70], program.by_ref().take(2).collect::<Vec<_>>());
+        producer.join().unwrap();
+
+        // The sender has been dropped, so there's no more input
+        assert_eq!(Ok(Event::NeedsInput), program.run_until_event());
+    }
+}
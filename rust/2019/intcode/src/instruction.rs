@@ -0,0 +1,228 @@
+//! Opcodes, parameter modes, and the encoding of instructions
+
+use super::Value;
+
+/// The operation performed by an instruction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 10] = [
+        Opcode::Add,
+        Opcode::Multiply,
+        Opcode::Input,
+        Opcode::Output,
+        Opcode::JumpIfTrue,
+        Opcode::JumpIfFalse,
+        Opcode::LessThan,
+        Opcode::Equals,
+        Opcode::AdjustRelativeBase,
+        Opcode::Halt,
+    ];
+
+    /// Returns the opcode for the two lowest digits of an instruction value
+    pub fn from_value(value: Value) -> Option<Self> {
+        use Opcode::*;
+
+        if value < 0 {
+            return None;
+        }
+
+        match value % 100 {
+            1 => Some(Add),
+            2 => Some(Multiply),
+            3 => Some(Input),
+            4 => Some(Output),
+            5 => Some(JumpIfTrue),
+            6 => Some(JumpIfFalse),
+            7 => Some(LessThan),
+            8 => Some(Equals),
+            9 => Some(AdjustRelativeBase),
+            99 => Some(Halt),
+            _ => None,
+        }
+    }
+
+    /// The numeric code of the operation, without any parameter modes
+    pub fn code(self) -> Value {
+        use Opcode::*;
+
+        match self {
+            Add => 1,
+            Multiply => 2,
+            Input => 3,
+            Output => 4,
+            JumpIfTrue => 5,
+            JumpIfFalse => 6,
+            LessThan => 7,
+            Equals => 8,
+            AdjustRelativeBase => 9,
+            Halt => 99,
+        }
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        use Opcode::*;
+
+        match self {
+            Add => "add",
+            Multiply => "mul",
+            Input => "in",
+            Output => "out",
+            JumpIfTrue => "jt",
+            JumpIfFalse => "jf",
+            LessThan => "lt",
+            Equals => "eq",
+            AdjustRelativeBase => "arb",
+            Halt => "hlt",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|op| op.mnemonic() == mnemonic)
+    }
+
+    pub fn parameter_count(self) -> usize {
+        use Opcode::*;
+
+        match self {
+            Add | Multiply | LessThan | Equals => 3,
+            JumpIfTrue | JumpIfFalse => 2,
+            Input | Output | AdjustRelativeBase => 1,
+            Halt => 0,
+        }
+    }
+
+    /// Returns true if the parameter with the given 1-based id is written to by the instruction
+    pub fn writes_parameter(self, id: usize) -> bool {
+        use Opcode::*;
+
+        match self {
+            Add | Multiply | LessThan | Equals => id == 3,
+            Input => id == 1,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParameterMode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl ParameterMode {
+    pub fn from_digit(digit: Value) -> Option<Self> {
+        match digit {
+            0 => Some(ParameterMode::Position),
+            1 => Some(ParameterMode::Immediate),
+            2 => Some(ParameterMode::Relative),
+            _ => None,
+        }
+    }
+
+    pub fn digit(self) -> Value {
+        match self {
+            ParameterMode::Position => 0,
+            ParameterMode::Immediate => 1,
+            ParameterMode::Relative => 2,
+        }
+    }
+}
+
+/// An opcode along with the modes of its parameters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [ParameterMode; 3],
+}
+
+impl Instruction {
+    /// Decodes an instruction value, e.g. `1002` decodes to `mul` with modes `[0, 1, 0]`
+    ///
+    /// Returns None if the opcode is unknown, if one of the instruction's parameters has an
+    /// unknown mode, or if a written parameter is in immediate mode. As with the VM, mode digits
+    /// beyond the instruction's parameters are ignored.
+    pub fn decode(value: Value) -> Option<Self> {
+        let opcode = Opcode::from_value(value)?;
+        let mut modes = [ParameterMode::Position; 3];
+        let mut remaining = value / 100;
+        for (i, mode) in modes.iter_mut().take(opcode.parameter_count()).enumerate() {
+            *mode = ParameterMode::from_digit(remaining % 10)?;
+            if opcode.writes_parameter(i + 1) && *mode == ParameterMode::Immediate {
+                return None;
+            }
+            remaining /= 10;
+        }
+        Some(Self { opcode, modes })
+    }
+
+    pub fn encode(&self) -> Value {
+        self.modes
+            .iter()
+            .rev()
+            .fold(0, |result, mode| result * 10 + mode.digit())
+            * 100
+            + self.opcode.code()
+    }
+
+    /// The number of values taken up by the instruction, including its parameters
+    pub fn size(&self) -> usize {
+        self.opcode.parameter_count() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        use ParameterMode::*;
+
+        assert_eq!(
+            Some(Instruction {
+                opcode: Opcode::Multiply,
+                modes: [Position, Immediate, Position]
+            }),
+            Instruction::decode(1002)
+        );
+        assert_eq!(
+            Some(Instruction {
+                opcode: Opcode::Output,
+                modes: [Relative, Position, Position]
+            }),
+            Instruction::decode(204)
+        );
+        assert_eq!(None, Instruction::decode(42));
+        assert_eq!(None, Instruction::decode(301));
+        assert_eq!(None, Instruction::decode(11101));
+        assert_eq!(
+            Some(Instruction {
+                opcode: Opcode::Halt,
+                modes: [Position, Position, Position]
+            }),
+            Instruction::decode(1099)
+        );
+        assert_eq!(None, Instruction::decode(-1));
+    }
+
+    #[test]
+    fn test_encode() {
+        for value in [1, 99, 1002, 21107, 204, 1105, 109] {
+            assert_eq!(value, Instruction::decode(value).unwrap().encode());
+        }
+    }
+}
@@ -0,0 +1,926 @@
+//! An Intcode virtual machine, as used in Advent of Code 2019
+//!
+//! A [Program] is loaded from the comma-separated form used by puzzle inputs, and can then be
+//! run to completion, iterated over for its output values, or driven one event at a time.
+//!
+//! ```
+//! use intcode::{Event, Program};
+//!
+//! // Outputs its input multiplied by 3
+//! let mut program = Program::new("3,9,1002,9,3,9,4,9,99,0");
+//! program.set_input(&[14]);
+//! assert_eq!(vec![42], program.run());
+//!
+//! let mut program = Program::new("3,9,1002,9,3,9,4,9,99,0");
+//! assert_eq!(Ok(Event::NeedsInput), program.run_until_event());
+//! program.add_input(&[2]);
+//! assert_eq!(Ok(Event::Output(6)), program.run_until_event());
+//! assert_eq!(Ok(Event::Halted), program.run_until_event());
+//! ```
+//!
+//! Along with the VM there are modules for assembling, disassembling, debugging and patching
+//! programs, for reading input from pluggable sources, for attaching devices that are driven by
+//! a program's output, for profiling, coverage, control-flow graphs, self-modifying code detection
+//! and reverse execution, for translating programs into Rust, for exchanging text with ASCII-mode
+//! programs, for running programs on threads or in a packet network, and for differential testing
+//! of the VM against a simple reference interpreter.
+
+pub mod ascii;
+pub mod assembler;
+pub mod cli;
+pub mod control_flow;
+pub mod coverage;
+pub mod debugger;
+pub mod device;
+pub mod differential;
+pub mod disassembler;
+pub mod history;
+pub mod input;
+pub mod instruction;
+pub mod limits;
+pub mod memory;
+pub mod network;
+pub mod patch;
+pub mod profile;
+pub mod reference;
+pub mod self_modifying;
+pub mod snapshot;
+pub mod threaded;
+pub mod trace;
+pub mod translate;
+
+use {
+    coverage::Coverage,
+    history::History,
+    input::InputSource,
+    instruction::{Opcode, ParameterMode},
+    limits::LoopDetector,
+    memory::Memory,
+    profile::Profile,
+    self_modifying::CodeWriteDetector,
+    std::{error, fmt},
+    trace::Tracer,
+};
+
+/// A position in a program's memory
+pub type Address = u64;
+/// A value stored in memory, or passed to or from a program as input or output
+pub type Value = i64;
+
+/// Divisors for extracting the mode digit of an instruction's parameters
+const PARAMETER_MODE_DIVISORS: [Value; 4] = [1, 100, 1000, 10000];
+
+/// Errors that can occur while loading or running an Intcode program
+///
+/// Execution errors carry the VM's instruction pointer, the opcode being executed, and the
+/// relative base at the point of failure. The VM is left unchanged by the failing instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntcodeError {
+    /// A value in a program's source, or in a list of values, couldn't be parsed
+    InvalidValue { index: usize, value: String },
+    /// The opcode at `ip` doesn't correspond to a known instruction
+    InvalidOpcode {
+        ip: Address,
+        opcode: Value,
+        relative_base: Value,
+    },
+    /// One of the instruction's parameters has an unknown mode
+    InvalidParameterMode {
+        ip: Address,
+        opcode: Value,
+        relative_base: Value,
+        parameter: usize,
+    },
+    /// The instruction's output parameter is in immediate mode
+    ImmediateModeWrite {
+        ip: Address,
+        opcode: Value,
+        relative_base: Value,
+        parameter: usize,
+    },
+    /// An instruction tried to access a negative address, or to jump to one
+    NegativeAddress {
+        ip: Address,
+        opcode: Value,
+        relative_base: Value,
+        address: Value,
+    },
+    /// An addition, multiplication or relative base calculation overflowed
+    Overflow {
+        ip: Address,
+        opcode: Value,
+        relative_base: Value,
+    },
+    /// An input instruction was reached with no remaining input
+    InputExhausted {
+        ip: Address,
+        opcode: Value,
+        relative_base: Value,
+    },
+    /// The program's step budget was used up before the program halted
+    StepBudgetExceeded {
+        ip: Address,
+        opcode: Value,
+        relative_base: Value,
+        budget: u64,
+    },
+    /// The program returned to an earlier state without reading input, so it will never halt
+    ///
+    /// The error is reported after the jump to the start of the loop has been executed.
+    InfiniteLoop {
+        ip: Address,
+        opcode: Value,
+        relative_base: Value,
+    },
+    /// The instruction would write to code while strict self-modifying code detection is enabled
+    CodeWrite {
+        ip: Address,
+        opcode: Value,
+        relative_base: Value,
+        address: Address,
+    },
+}
+
+impl fmt::Display for IntcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use IntcodeError::*;
+
+        match self {
+            InvalidValue { index, value } => {
+                write!(f, "Unable to parse value at index {index}: '{value}'")
+            }
+            InvalidOpcode {
+                ip,
+                opcode,
+                relative_base,
+            } => write!(
+                f,
+                "Invalid opcode {opcode} at position {ip} (relative base: {relative_base})"
+            ),
+            InvalidParameterMode {
+                ip,
+                opcode,
+                relative_base,
+                parameter,
+            } => write!(
+                f,
+                "Invalid mode for parameter {parameter} of opcode {opcode} at position {ip} \
+                 (relative base: {relative_base})"
+            ),
+            ImmediateModeWrite {
+                ip,
+                opcode,
+                relative_base,
+                parameter,
+            } => write!(
+                f,
+                "Immediate mode used for output parameter {parameter} of opcode {opcode} \
+                 at position {ip} (relative base: {relative_base})"
+            ),
+            NegativeAddress {
+                ip,
+                opcode,
+                relative_base,
+                address,
+            } => write!(
+                f,
+                "Negative address {address} used by opcode {opcode} at position {ip} \
+                 (relative base: {relative_base})"
+            ),
+            Overflow {
+                ip,
+                opcode,
+                relative_base,
+            } => write!(
+                f,
+                "Arithmetic overflow in opcode {opcode} at position {ip} \
+                 (relative base: {relative_base})"
+            ),
+            InputExhausted {
+                ip,
+                opcode,
+                relative_base,
+            } => write!(
+                f,
+                "No input available for opcode {opcode} at position {ip} \
+                 (relative base: {relative_base})"
+            ),
+            StepBudgetExceeded {
+                ip,
+                opcode,
+                relative_base,
+                budget,
+            } => write!(
+                f,
+                "Step budget of {budget} exceeded before opcode {opcode} at position {ip} \
+                 (relative base: {relative_base})"
+            ),
+            InfiniteLoop {
+                ip,
+                opcode,
+                relative_base,
+            } => write!(
+                f,
+                "Infinite loop detected at position {ip} with opcode {opcode} \
+                 (relative base: {relative_base})"
+            ),
+            CodeWrite {
+                ip,
+                opcode,
+                relative_base,
+                address,
+            } => write!(
+                f,
+                "Opcode {opcode} at position {ip} would write to code at position {address} \
+                 (relative base: {relative_base})"
+            ),
+        }
+    }
+}
+
+impl error::Error for IntcodeError {}
+
+/// The result of executing a single instruction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// The instruction was executed without producing output
+    Continue,
+    /// The instruction produced an output value
+    Output(Value),
+    /// The program has halted, further steps will have no effect
+    Halted,
+}
+
+/// The reason that [Program::run_until_event] returned
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// The program produced an output value
+    Output(Value),
+    /// The program is waiting at an input instruction, it can be resumed after adding input
+    NeedsInput,
+    /// The program has halted
+    Halted,
+}
+
+/// An Intcode program along with its execution state
+#[derive(Default)]
+pub struct Program {
+    state: Memory,
+    ip: Address,
+    relative_base: Value,
+    inputs: Vec<Value>,
+    current_input: usize,
+    /// The number of input values consumed, including those taken from the input source
+    inputs_read: u64,
+    input_source: Option<Box<dyn InputSource + Send>>,
+    tracer: Option<Tracer>,
+    step_budget: Option<u64>,
+    steps: u64,
+    loop_detector: Option<LoopDetector>,
+    profile: Option<Box<Profile>>,
+    coverage: Option<Box<Coverage>>,
+    code_write_detector: Option<Box<CodeWriteDetector>>,
+    history: Option<Box<History>>,
+}
+
+impl Clone for Program {
+    /// Clones the program's state, an input source or trace sink isn't shared with the clone
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            ip: self.ip,
+            relative_base: self.relative_base,
+            inputs: self.inputs.clone(),
+            current_input: self.current_input,
+            inputs_read: self.inputs_read,
+            input_source: None,
+            tracer: None,
+            step_budget: self.step_budget,
+            steps: self.steps,
+            loop_detector: self.loop_detector.clone(),
+            profile: self.profile.clone(),
+            coverage: self.coverage.clone(),
+            code_write_detector: self.code_write_detector.clone(),
+            history: self.history.clone(),
+        }
+    }
+}
+
+/// Parses comma-separated values, in the format accepted by [Program::try_new]
+pub fn parse_values(input: &str) -> Result<Vec<Value>, IntcodeError> {
+    let without_comments: String = input
+        .lines()
+        .map(|line| line.split('#').next().unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+    without_comments
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|x| !x.is_empty())
+        .enumerate()
+        .map(|(index, x)| {
+            x.parse::<Value>().map_err(|_| IntcodeError::InvalidValue {
+                index,
+                value: x.to_string(),
+            })
+        })
+        .collect()
+}
+
+impl Program {
+    /// Loads a program from comma-separated values, panicking if the source is invalid
+    pub fn new(input: &str) -> Self {
+        Self::try_new(input).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Loads a program from comma-separated values
+    ///
+    /// Values can also be separated by whitespace, e.g. by starting a new line without a trailing
+    /// comma, and comments start with `#` and continue to the end of the line. Empty values are
+    /// skipped, so a trailing comma is allowed, and an empty source loads an empty program.
+    pub fn try_new(input: &str) -> Result<Self, IntcodeError> {
+        Ok(Self::from(parse_values(input)?.as_slice()))
+    }
+
+    /// Replaces the program's pending input
+    pub fn set_input(&mut self, input: &[Value]) {
+        self.inputs = input.to_vec();
+        self.current_input = 0;
+    }
+
+    /// Appends to the program's pending input, preserving any input that hasn't been read yet
+    pub fn add_input(&mut self, input: &[Value]) {
+        self.inputs.extend_from_slice(input);
+    }
+
+    /// Runs the program until it halts, returning all of its output
+    ///
+    /// Panics if an error occurs, see [Program::try_run].
+    pub fn run(&mut self) -> Vec<Value> {
+        self.collect()
+    }
+
+    /// Runs the program until it halts, returning all of its output
+    pub fn try_run(&mut self) -> Result<Vec<Value>, IntcodeError> {
+        let mut output = Vec::new();
+        loop {
+            match self.try_step()? {
+                Step::Continue => {}
+                Step::Output(value) => output.push(value),
+                Step::Halted => return Ok(output),
+            }
+        }
+    }
+
+    /// Runs the program until it produces output, needs input, or halts
+    ///
+    /// When [Event::NeedsInput] is returned the program is paused at the input instruction,
+    /// calling `run_until_event` again after [Program::add_input] will resume execution.
+    pub fn run_until_event(&mut self) -> Result<Event, IntcodeError> {
+        loop {
+            match self.try_step() {
+                Ok(Step::Continue) => {}
+                Ok(Step::Output(value)) => return Ok(Event::Output(value)),
+                Ok(Step::Halted) => return Ok(Event::Halted),
+                Err(IntcodeError::InputExhausted { .. }) => return Ok(Event::NeedsInput),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// The program's memory, as loaded or as modified by the program
+    pub fn memory(&self) -> &Memory {
+        &self.state
+    }
+
+    /// The address of the next instruction to be executed
+    pub fn ip(&self) -> Address {
+        self.ip
+    }
+
+    /// The base address used by parameters in relative mode
+    pub fn relative_base(&self) -> Value {
+        self.relative_base
+    }
+
+    /// Input that has been provided but not yet read by the program
+    pub fn pending_inputs(&self) -> &[Value] {
+        &self.inputs[self.current_input..]
+    }
+
+    /// Decodes the next instruction to be executed
+    pub fn next_instruction(&self) -> disassembler::Line {
+        disassembler::decode_line(&self.state, self.ip)
+    }
+
+    fn read(&self, position: Address) -> Value {
+        self.state.get(position)
+    }
+
+    /// Sets the value at an address in the program's memory
+    pub fn write(&mut self, position: Address, value: Value) {
+        if let Some(detector) = &mut self.loop_detector {
+            detector.update_memory_hash(position, self.state.get(position), value);
+        }
+        self.state.set(position, value);
+    }
+
+    fn error_context(&self) -> (Address, Value, Value) {
+        (self.ip, self.read(self.ip), self.relative_base)
+    }
+
+    /// Unwraps the result of checked arithmetic, failing if it overflowed
+    fn overflow_checked(&self, value: Option<Value>) -> Result<Value, IntcodeError> {
+        value.ok_or_else(|| {
+            let (ip, opcode, relative_base) = self.error_context();
+            IntcodeError::Overflow {
+                ip,
+                opcode,
+                relative_base,
+            }
+        })
+    }
+
+    /// Adds an offset to the relative base, failing if it overflows
+    fn relative_address(&self, offset: Value) -> Result<Address, IntcodeError> {
+        self.address(self.overflow_checked(self.relative_base.checked_add(offset))?)
+    }
+
+    /// Converts a value into an address, failing if the value is negative
+    fn address(&self, value: Value) -> Result<Address, IntcodeError> {
+        Address::try_from(value).map_err(|_| {
+            let (ip, opcode, relative_base) = self.error_context();
+            IntcodeError::NegativeAddress {
+                ip,
+                opcode,
+                relative_base,
+                address: value,
+            }
+        })
+    }
+
+    fn invalid_opcode(&self) -> IntcodeError {
+        let (ip, opcode, relative_base) = self.error_context();
+        IntcodeError::InvalidOpcode {
+            ip,
+            opcode,
+            relative_base,
+        }
+    }
+
+    fn parameter_mode(&self, id: usize, op: Value) -> Result<ParameterMode, IntcodeError> {
+        let digit = op / PARAMETER_MODE_DIVISORS[id] % 10;
+        match ParameterMode::from_digit(digit) {
+            Some(mode) => Ok(mode),
+            None => {
+                let (ip, opcode, relative_base) = self.error_context();
+                Err(IntcodeError::InvalidParameterMode {
+                    ip,
+                    opcode,
+                    relative_base,
+                    parameter: id,
+                })
+            }
+        }
+    }
+
+    fn read_parameter(&self, id: usize, op: Value) -> Result<Value, IntcodeError> {
+        let value = self.read(self.ip + id as Address);
+        Ok(match self.parameter_mode(id, op)? {
+            ParameterMode::Position => self.read(self.address(value)?),
+            ParameterMode::Relative => self.read(self.relative_address(value)?),
+            ParameterMode::Immediate => value,
+        })
+    }
+
+    /// Resolves the address that a written parameter refers to
+    fn parameter_address(&self, id: usize, op: Value) -> Result<Address, IntcodeError> {
+        let position = self.read(self.ip + id as Address);
+        match self.parameter_mode(id, op)? {
+            ParameterMode::Position => self.address(position),
+            ParameterMode::Relative => self.relative_address(position),
+            ParameterMode::Immediate => {
+                let (ip, opcode, relative_base) = self.error_context();
+                Err(IntcodeError::ImmediateModeWrite {
+                    ip,
+                    opcode,
+                    relative_base,
+                    parameter: id,
+                })
+            }
+        }
+    }
+
+    fn write_parameter(&mut self, id: usize, op: Value, value: Value) -> Result<(), IntcodeError> {
+        let address = self.parameter_address(id, op)?;
+        self.write(address, value);
+        Ok(())
+    }
+
+    /// Executes a single instruction
+    ///
+    /// If an error occurs then the instruction pointer isn't advanced, so after the problem
+    /// has been addressed (e.g. by providing more input) the step can be retried.
+    pub fn try_step(&mut self) -> Result<Step, IntcodeError> {
+        if let Some(budget) = self.step_budget {
+            if self.steps >= budget {
+                let (ip, opcode, relative_base) = self.error_context();
+                return Err(IntcodeError::StepBudgetExceeded {
+                    ip,
+                    opcode,
+                    relative_base,
+                    budget,
+                });
+            }
+        }
+
+        let ip = self.ip;
+        let profiled_instruction = match self.profile {
+            Some(_) => self.profiled_instruction(),
+            None => None,
+        };
+        let covered_cells = self.coverage.as_ref().map(|_| self.cells_to_cover());
+        let code_write = match self.code_write_detector {
+            Some(_) => Some(self.check_code_write()?),
+            None => None,
+        };
+        let undo_entry = self.history.as_ref().map(|_| self.undo_entry());
+        let step = if self.tracer.is_some() {
+            self.traced_step()?
+        } else {
+            self.execute()?
+        };
+        if let Some(cells) = covered_cells {
+            self.mark_coverage(cells);
+        }
+        if let Some((opcode, write)) = code_write {
+            self.record_code_write(ip, opcode, write);
+        }
+        if let (Some(entry), true) = (undo_entry, step != Step::Halted) {
+            self.record_undo_entry(entry, step);
+        }
+
+        if let (Some(profile), Some((opcode, taken))) = (&mut self.profile, profiled_instruction) {
+            profile.record(ip, opcode, taken, self.ip);
+        }
+        if step != Step::Halted {
+            self.steps += 1;
+            if self.ip <= ip && self.loop_detector.is_some() {
+                self.check_for_loop()?;
+            }
+        }
+
+        Ok(step)
+    }
+
+    fn execute(&mut self) -> Result<Step, IntcodeError> {
+        let op = self.read(self.ip);
+        let Some(opcode) = Opcode::from_value(op) else {
+            return Err(self.invalid_opcode());
+        };
+
+        match opcode {
+            Opcode::Add => {
+                // Sum
+                let a = self.read_parameter(1, op)?;
+                let b = self.read_parameter(2, op)?;
+                let sum = self.overflow_checked(a.checked_add(b))?;
+                self.write_parameter(3, op, sum)?;
+                self.ip += 4;
+            }
+            Opcode::Multiply => {
+                // Multiply
+                let a = self.read_parameter(1, op)?;
+                let b = self.read_parameter(2, op)?;
+                let product = self.overflow_checked(a.checked_mul(b))?;
+                self.write_parameter(3, op, product)?;
+                self.ip += 4;
+            }
+            Opcode::Input => {
+                // Store input, resolving the address first so that input isn't consumed by an
+                // instruction that then fails
+                let address = self.parameter_address(1, op)?;
+                let Some(input) = self.read_input() else {
+                    let (ip, opcode, relative_base) = self.error_context();
+                    return Err(IntcodeError::InputExhausted {
+                        ip,
+                        opcode,
+                        relative_base,
+                    });
+                };
+                self.write(address, input);
+                self.ip += 2;
+            }
+            Opcode::Output => {
+                // Output
+                let value = self.read_parameter(1, op)?;
+                self.ip += 2;
+                return Ok(Step::Output(value));
+            }
+            Opcode::JumpIfTrue => {
+                // jump-if-true
+                let a = self.read_parameter(1, op)?;
+                let b = self.read_parameter(2, op)?;
+                if a != 0 {
+                    self.ip = self.address(b)?;
+                } else {
+                    self.ip += 3;
+                }
+            }
+            Opcode::JumpIfFalse => {
+                // jump-if-false
+                let a = self.read_parameter(1, op)?;
+                let b = self.read_parameter(2, op)?;
+                if a == 0 {
+                    self.ip = self.address(b)?;
+                } else {
+                    self.ip += 3;
+                }
+            }
+            Opcode::LessThan => {
+                // Less than
+                let a = self.read_parameter(1, op)?;
+                let b = self.read_parameter(2, op)?;
+                self.write_parameter(3, op, if a < b { 1 } else { 0 })?;
+                self.ip += 4;
+            }
+            Opcode::Equals => {
+                // Equals
+                let a = self.read_parameter(1, op)?;
+                let b = self.read_parameter(2, op)?;
+                self.write_parameter(3, op, if a == b { 1 } else { 0 })?;
+                self.ip += 4;
+            }
+            Opcode::AdjustRelativeBase => {
+                // Relative base offset
+                let a = self.read_parameter(1, op)?;
+                self.relative_base = self.overflow_checked(self.relative_base.checked_add(a))?;
+                self.ip += 2;
+            }
+            Opcode::Halt => {
+                // Halt
+                return Ok(Step::Halted);
+            }
+        }
+
+        Ok(Step::Continue)
+    }
+}
+
+impl From<&[Value]> for Program {
+    fn from(values: &[Value]) -> Self {
+        Self {
+            state: Memory::from(values),
+            ..Default::default()
+        }
+    }
+}
+
+impl Iterator for Program {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.try_step() {
+                Ok(Step::Continue) => {}
+                Ok(Step::Output(value)) => return Some(value),
+                Ok(Step::Halted) => return None,
+                Err(error) => panic!("{error}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_0() {
+        let mut program = Program::new("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+        assert_eq!(
+            vec![109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99],
+            program.run()
+        );
+    }
+
+    #[test]
+    fn test_1() {
+        let mut program = Program::new("1102,34915192,34915192,7,4,7,99,0");
+        let result = program.run()[0];
+        assert!(result >= 1_000_000_000_000_000);
+    }
+
+    #[test]
+    fn test_2() {
+        let mut program = Program::new("104,1125899906842624,99");
+        assert_eq!(vec![1125899906842624], program.run());
+    }
+
+    #[test]
+    fn test_unused_mode_digits() {
+        // Mode digits beyond an instruction's parameters are ignored
+        let mut program = Program::new("90004,0,1099");
+        assert_eq!(Ok(vec![90004]), program.try_run());
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        assert_eq!(
+            Some(IntcodeError::InvalidValue {
+                index: 2,
+                value: "x".to_string()
+            }),
+            Program::try_new("1,0,x,0,99").err()
+        );
+        assert_eq!(
+            Some(IntcodeError::InvalidValue {
+                index: 3,
+                value: "1.5".to_string()
+            }),
+            Program::try_new("1,,2, 3,1.5").err()
+        );
+    }
+
+    #[test]
+    fn test_whitespace_and_comments() {
+        let source = "
+            # Adds 9 and 10
+            1,5,6,0    # add [5], [6], [0]
+            99
+            9 10
+        ";
+        let mut program = Program::new(source);
+        assert_eq!(&[1, 5, 6, 0, 99, 9, 10], &program.peek_range(0..7)[..]);
+        program.run();
+        assert_eq!(19, program.peek(0));
+    }
+
+    #[test]
+    fn test_empty_values() {
+        let mut program = Program::new("1,0,0,0,99,\n");
+        assert_eq!(&[1, 0, 0, 0, 99], &program.peek_range(0..5)[..]);
+        program.run();
+        assert_eq!(2, program.peek(0));
+
+        assert_eq!(Ok(vec![1, -2]), parse_values(" 1,-2,\n"));
+
+        for source in ["", "# Nothing to see here\n  # or here\n"] {
+            let program = Program::try_new(source).unwrap();
+            assert_eq!(0, program.memory().len());
+        }
+    }
+
+    #[test]
+    fn test_invalid_opcode() {
+        let mut program = Program::new("1101,1,2,0,42,99");
+        assert_eq!(
+            Err(IntcodeError::InvalidOpcode {
+                ip: 4,
+                opcode: 42,
+                relative_base: 0
+            }),
+            program.try_run()
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameter_mode() {
+        let mut program = Program::new("109,7,301,1,2,0,99");
+        assert_eq!(
+            Err(IntcodeError::InvalidParameterMode {
+                ip: 2,
+                opcode: 301,
+                relative_base: 7,
+                parameter: 1
+            }),
+            program.try_run()
+        );
+    }
+
+    #[test]
+    fn test_immediate_mode_write() {
+        let mut program = Program::new("11101,1,2,0,99");
+        assert_eq!(
+            Err(IntcodeError::ImmediateModeWrite {
+                ip: 0,
+                opcode: 11101,
+                relative_base: 0,
+                parameter: 3
+            }),
+            program.try_run()
+        );
+    }
+
+    #[test]
+    fn test_input_exhausted_is_resumable() {
+        let mut program = Program::new("3,11,3,12,1,11,12,13,4,13,99");
+        program.set_input(&[20]);
+        assert_eq!(Ok(Step::Continue), program.try_step());
+        assert_eq!(
+            Err(IntcodeError::InputExhausted {
+                ip: 2,
+                opcode: 3,
+                relative_base: 0
+            }),
+            program.try_step()
+        );
+
+        program.set_input(&[22]);
+        assert_eq!(Ok(vec![42]), program.try_run());
+        assert_eq!(Ok(Step::Halted), program.try_step());
+    }
+
+    #[test]
+    fn test_high_addresses() {
+        // Write to an address above 2^32 via the relative base, then read it back
+        let mut program = Program::new("109,5000000000,21101,7,35,1,204,1,99");
+        assert_eq!(Ok(vec![42]), program.try_run());
+        assert_eq!(42, program.memory().get(5_000_000_001));
+        assert_eq!(2, program.memory().page_count());
+    }
+
+    #[test]
+    fn test_negative_address() {
+        let mut program = Program::new("109,-10,204,3,99");
+        assert_eq!(
+            Err(IntcodeError::NegativeAddress {
+                ip: 2,
+                opcode: 204,
+                relative_base: -10,
+                address: -7
+            }),
+            program.try_run()
+        );
+
+        let mut program = Program::new("1105,1,-1");
+        assert_eq!(
+            Err(IntcodeError::NegativeAddress {
+                ip: 0,
+                opcode: 1105,
+                relative_base: 0,
+                address: -1
+            }),
+            program.try_run()
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = Value::MAX;
+        let cases = [
+            // Add
+            (format!("1101,{max},1,0,99"), 0, 1101, 0),
+            // Multiply
+            (format!("1102,{max},2,0,99"), 0, 1102, 0),
+            // Adjust the relative base
+            (format!("109,{max},109,1,99"), 2, 109, max),
+            // Read a relative parameter
+            (format!("109,{max},204,1,99"), 2, 204, max),
+            // Write to a relative parameter
+            (format!("109,{max},21101,1,1,1,99"), 2, 21101, max),
+        ];
+        for (source, ip, opcode, relative_base) in cases {
+            let mut program = Program::new(&source);
+            assert_eq!(
+                Err(IntcodeError::Overflow {
+                    ip,
+                    opcode,
+                    relative_base
+                }),
+                program.try_run(),
+                "{source}"
+            );
+            // The failing instruction is left unexecuted
+            assert_eq!((ip, relative_base), (program.ip(), program.relative_base()));
+        }
+    }
+
+    #[test]
+    fn test_run_until_event() {
+        let mut program = Program::new("3,11,3,12,1,11,12,13,4,13,99");
+        assert_eq!(Ok(Event::NeedsInput), program.run_until_event());
+        assert_eq!(Ok(Event::NeedsInput), program.run_until_event());
+        program.add_input(&[40]);
+        assert_eq!(Ok(Event::NeedsInput), program.run_until_event());
+        program.add_input(&[2]);
+        assert_eq!(Ok(Event::Output(42)), program.run_until_event());
+        assert_eq!(Ok(Event::Halted), program.run_until_event());
+        assert_eq!(Ok(Event::Halted), program.run_until_event());
+    }
+
+    #[test]
+    fn test_inspection() {
+        let mut program = Program::new("109,19,3,0,204,-19,99");
+        program.set_input(&[1, 2]);
+        assert_eq!(Ok(Step::Continue), program.try_step());
+        assert_eq!(2, program.ip());
+        assert_eq!(19, program.relative_base());
+        assert_eq!(&[1, 2], program.pending_inputs());
+        assert_eq!("     2: in   [0]", program.next_instruction().to_string());
+
+        assert_eq!(Ok(Step::Continue), program.try_step());
+        assert_eq!(&[2], program.pending_inputs());
+        assert_eq!(1, program.memory().get(0));
+        assert_eq!(Ok(Step::Output(1)), program.try_step());
+    }
+}
@@ -0,0 +1,228 @@
+//! Limits on how long a program can run
+//!
+//! A step budget limits the number of instructions that a program can execute, failing with
+//! [IntcodeError::StepBudgetExceeded] when it's used up.
+//!
+//! Loop detection checks the program's state each time it jumps backwards. If the memory,
+//! instruction pointer, relative base, and number of inputs read are all the same as at an
+//! earlier backward jump, then the program is certain to repeat itself forever and
+//! [IntcodeError::InfiniteLoop] is returned. Earlier states are sampled using Brent's cycle
+//! detection algorithm, so only a single copy of memory is kept. Memory is hashed
+//! incrementally as it's written to, with states only being compared in full when their
+//! hashes match.
+
+use super::{memory::Memory, Address, IntcodeError, Program, Value};
+
+fn hash_value(address: Address, value: Value) -> u64 {
+    // Unwritten memory reads as zero, so zeros don't contribute to the hash
+    if value == 0 {
+        return 0;
+    }
+    // splitmix64 finalizer
+    let mut x = address.wrapping_mul(0x9e3779b97f4a7c15) ^ value as u64;
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+#[derive(Clone)]
+struct Checkpoint {
+    hash: u64,
+    ip: Address,
+    relative_base: Value,
+    inputs_read: u64,
+    memory: Memory,
+}
+
+#[derive(Clone)]
+pub(super) struct LoopDetector {
+    /// The sum of the hashes of each value in memory
+    memory_hash: u64,
+    checkpoint: Option<Checkpoint>,
+    /// The number of backward jumps between checkpoints, doubled after each new checkpoint
+    interval: u64,
+    jumps: u64,
+}
+
+impl LoopDetector {
+    pub(super) fn new(memory: &Memory) -> Self {
+        let memory_hash = memory
+            .pages()
+            .into_iter()
+            .flat_map(|(index, values)| {
+                let start = index * values.len() as Address;
+                values
+                    .iter()
+                    .enumerate()
+                    .map(move |(offset, &value)| hash_value(start + offset as Address, value))
+            })
+            .fold(0, u64::wrapping_add);
+
+        Self {
+            memory_hash,
+            checkpoint: None,
+            interval: 1,
+            jumps: 0,
+        }
+    }
+
+    pub(super) fn update_memory_hash(&mut self, address: Address, old: Value, new: Value) {
+        self.memory_hash = self
+            .memory_hash
+            .wrapping_sub(hash_value(address, old))
+            .wrapping_add(hash_value(address, new));
+    }
+}
+
+impl Program {
+    /// Limits the number of instructions that the program can execute, or removes the limit
+    ///
+    /// Instructions executed before the budget was set count towards it.
+    pub fn set_step_budget(&mut self, budget: Option<u64>) {
+        self.step_budget = budget;
+    }
+
+    pub fn step_budget(&self) -> Option<u64> {
+        self.step_budget
+    }
+
+    /// The number of instructions that the program has executed
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Enables or disables infinite loop detection
+    pub fn set_loop_detection(&mut self, enabled: bool) {
+        self.loop_detector = enabled.then(|| LoopDetector::new(&self.state));
+    }
+
+    pub fn is_detecting_loops(&self) -> bool {
+        self.loop_detector.is_some()
+    }
+
+    /// Called after a backward jump, compares the program's state against the last checkpoint
+    pub(super) fn check_for_loop(&mut self) -> Result<(), IntcodeError> {
+        let Some(detector) = &mut self.loop_detector else {
+            return Ok(());
+        };
+
+        let hash = detector.memory_hash
+            ^ hash_value(1, self.ip as Value)
+            ^ hash_value(2, self.relative_base)
+            ^ hash_value(3, self.inputs_read as Value);
+
+        if let Some(checkpoint) = &detector.checkpoint {
+            if checkpoint.hash == hash
+                && checkpoint.ip == self.ip
+                && checkpoint.relative_base == self.relative_base
+                && checkpoint.inputs_read == self.inputs_read
+                && checkpoint.memory == self.state
+            {
+                let (ip, opcode, relative_base) = self.error_context();
+                return Err(IntcodeError::InfiniteLoop {
+                    ip,
+                    opcode,
+                    relative_base,
+                });
+            }
+        }
+
+        detector.jumps += 1;
+        if detector.jumps == detector.interval {
+            detector.checkpoint = Some(Checkpoint {
+                hash,
+                ip: self.ip,
+                relative_base: self.relative_base,
+                inputs_read: self.inputs_read,
+                memory: self.state.clone(),
+            });
+            detector.interval *= 2;
+            detector.jumps = 0;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler::assemble, *};
+
+    #[test]
+    fn test_step_budget() {
+        let mut program = Program::new("1101,1,1,5,99,0");
+        program.set_step_budget(Some(1));
+        assert!(matches!(
+            program.try_run(),
+            Err(IntcodeError::StepBudgetExceeded {
+                ip: 4,
+                budget: 1,
+                ..
+            })
+        ));
+        assert_eq!(1, program.steps());
+
+        program.set_step_budget(Some(2));
+        assert_eq!(Ok(vec![]), program.try_run());
+    }
+
+    #[test]
+    fn test_infinite_loops() {
+        // Jumps to itself
+        let mut program = Program::new("1105,1,0");
+        program.set_loop_detection(true);
+        assert!(matches!(
+            program.try_run(),
+            Err(IntcodeError::InfiniteLoop { ip: 0, .. })
+        ));
+
+        // Counts up to 10 and then toggles a flag forever
+        let source = "
+            count:
+                add [counter], #1, [counter]
+                lt [counter], #10, [t]
+                jt [t], #count
+            toggle:
+                eq [flag], #0, [flag]
+                out [flag]
+                jt #1, #toggle
+            counter: data 0
+            flag: data 0
+            t: data 0
+        ";
+        let mut program = Program::new(&assemble(source).unwrap());
+        program.set_loop_detection(true);
+        let mut outputs = 0;
+        let error = loop {
+            match program.run_until_event() {
+                Ok(_) => outputs += 1,
+                Err(error) => break error,
+            }
+        };
+        assert!(matches!(error, IntcodeError::InfiniteLoop { ip: 11, .. }));
+        assert!(outputs >= 2);
+        assert_eq!(10, program.memory().get(20));
+    }
+
+    #[test]
+    fn test_terminating_loops() {
+        // A loop that reads input isn't reported, as the input might end it
+        let source = "
+            start:
+                in [x]
+                jf [x], #start
+                hlt
+            x: data 0
+        ";
+        let mut program = Program::new(&assemble(source).unwrap());
+        program.set_loop_detection(true);
+        program.set_input(&[0, 0, 0, 1]);
+        assert_eq!(Ok(vec![]), program.try_run());
+
+        // Day 9's BOOST program in sensor boost mode runs for a long time without repeating
+        let mut program = Program::new(include_str!("../../src/bin/input/9"));
+        program.set_loop_detection(true);
+        program.set_input(&[2]);
+        assert_eq!(1, program.try_run().unwrap().len());
+    }
+}
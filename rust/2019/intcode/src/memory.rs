@@ -0,0 +1,202 @@
+//! The memory of a [Program](super::Program)
+
+use {
+    super::{Address, Value},
+    std::{collections::HashMap, ops::Range},
+};
+
+const PAGE_BITS: u32 = 10;
+pub const PAGE_SIZE: usize = 1 << PAGE_BITS;
+
+/// Pages below this index are kept in a Vec for fast lookup, covering the first 1M addresses
+const DIRECT_PAGE_COUNT: Address = 1024;
+
+type Page = [Value; PAGE_SIZE];
+
+fn new_page() -> Box<Page> {
+    Box::new([0; PAGE_SIZE])
+}
+
+fn split_address(address: Address) -> (Address, usize) {
+    (address >> PAGE_BITS, (address as usize) & (PAGE_SIZE - 1))
+}
+
+/// Sparse Intcode memory
+///
+/// Memory is allocated in pages when first written to, with unallocated memory reading as zero.
+#[derive(Clone, Debug, Default)]
+pub struct Memory {
+    direct_pages: Vec<Option<Box<Page>>>,
+    sparse_pages: HashMap<Address, Box<Page>>,
+    len: Address,
+}
+
+impl Memory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn page(&self, index: Address) -> Option<&Page> {
+        if index < DIRECT_PAGE_COUNT {
+            self.direct_pages.get(index as usize)?.as_deref()
+        } else {
+            self.sparse_pages.get(&index).map(|page| &**page)
+        }
+    }
+
+    fn page_mut(&mut self, index: Address) -> &mut Page {
+        if index < DIRECT_PAGE_COUNT {
+            let index = index as usize;
+            if index >= self.direct_pages.len() {
+                self.direct_pages.resize_with(index + 1, || None);
+            }
+            self.direct_pages[index].get_or_insert_with(new_page)
+        } else {
+            self.sparse_pages.entry(index).or_insert_with(new_page)
+        }
+    }
+
+    pub fn get(&self, address: Address) -> Value {
+        let (page, offset) = split_address(address);
+        self.page(page).map_or(0, |page| page[offset])
+    }
+
+    pub fn set(&mut self, address: Address, value: Value) {
+        let (page, offset) = split_address(address);
+        self.page_mut(page)[offset] = value;
+        self.len = self.len.max(address.saturating_add(1));
+    }
+
+    /// One past the highest address that has been written to
+    pub fn len(&self) -> Address {
+        self.len
+    }
+
+    /// Restores the length of memory after a write has been undone
+    pub(super) fn set_len(&mut self, len: Address) {
+        self.len = len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of pages that have been allocated
+    pub fn page_count(&self) -> usize {
+        self.direct_pages
+            .iter()
+            .filter(|page| page.is_some())
+            .count()
+            + self.sparse_pages.len()
+    }
+
+    pub fn read_range(&self, range: Range<Address>) -> Vec<Value> {
+        range.map(|address| self.get(address)).collect()
+    }
+
+    /// The allocated pages and their indices, in address order
+    pub fn pages(&self) -> Vec<(Address, &[Value])> {
+        let mut pages: Vec<(Address, &[Value])> = self
+            .direct_pages
+            .iter()
+            .enumerate()
+            .filter_map(|(index, page)| Some((index as Address, page.as_deref()?.as_slice())))
+            .chain(
+                self.sparse_pages
+                    .iter()
+                    .map(|(&index, page)| (index, page.as_slice())),
+            )
+            .collect();
+        pages.sort_by_key(|(index, _)| *index);
+        pages
+    }
+
+    /// Rebuilds memory from pages as returned by [Memory::pages], along with its length
+    ///
+    /// Returns None if a page has the wrong size.
+    pub(super) fn from_pages(pages: Vec<(Address, Vec<Value>)>, len: Address) -> Option<Self> {
+        let mut memory = Self::new();
+        for (index, values) in pages {
+            *memory.page_mut(index) = values.try_into().ok()?;
+        }
+        memory.len = len;
+        Some(memory)
+    }
+}
+
+impl PartialEq for Memory {
+    /// Memory is equal if it has the same length and values, regardless of allocated pages
+    fn eq(&self, other: &Self) -> bool {
+        let pages_match = |a: &Self, b: &Self| {
+            a.pages()
+                .into_iter()
+                .all(|(index, values)| match b.page(index) {
+                    Some(page) => page.as_slice() == values,
+                    None => values.iter().all(|&value| value == 0),
+                })
+        };
+        self.len == other.len && pages_match(self, other) && pages_match(other, self)
+    }
+}
+
+impl Eq for Memory {}
+
+impl From<&[Value]> for Memory {
+    fn from(values: &[Value]) -> Self {
+        let mut memory = Self::new();
+        for (address, &value) in values.iter().enumerate() {
+            memory.set(address as Address, value);
+        }
+        memory
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_and_set() {
+        let mut memory = Memory::from(&[1, 2, 3][..]);
+        assert_eq!(3, memory.len());
+        assert_eq!(vec![1, 2, 3, 0], memory.read_range(0..4));
+
+        memory.set(PAGE_SIZE as Address, 42);
+        assert_eq!(42, memory.get(PAGE_SIZE as Address));
+        assert_eq!(0, memory.get(PAGE_SIZE as Address - 1));
+        assert_eq!(PAGE_SIZE as Address + 1, memory.len());
+        assert_eq!(2, memory.page_count());
+    }
+
+    #[test]
+    fn test_sparse_pages() {
+        let mut memory = Memory::new();
+        assert_eq!(0, memory.get(1 << 40));
+        assert_eq!(0, memory.page_count());
+
+        memory.set(1 << 40, 7);
+        memory.set(Address::MAX, 8);
+        assert_eq!(7, memory.get(1 << 40));
+        assert_eq!(8, memory.get(Address::MAX));
+        assert_eq!(0, memory.get((1 << 40) + PAGE_SIZE as Address));
+        assert_eq!(2, memory.page_count());
+        assert_eq!(Address::MAX, memory.len());
+    }
+
+    #[test]
+    fn test_equality() {
+        let mut a = Memory::from(&[1, 2, 3][..]);
+        let mut b = a.clone();
+        assert_eq!(a, b);
+
+        // A page of zeros is equal to an unallocated page
+        a.set(PAGE_SIZE as Address, 0);
+        b.set(PAGE_SIZE as Address + 1, 0);
+        a.set(PAGE_SIZE as Address + 1, 0);
+        assert_eq!(a, b);
+        assert_eq!(2, a.page_count());
+
+        b.set(2, 4);
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,406 @@
+//! A network of Intcode programs that send packets to each other
+//!
+//! Each program is given its network address as its first input. Programs send packets by
+//! outputting the destination address followed by the packet's `x` and `y` values, and receive
+//! packets as pairs of input values. When a program reads input and no packets are waiting for
+//! it, it receives `-1`.
+//!
+//! The network is run in rounds, where each program runs until it's waiting for input with no
+//! packets left to read. A [Monitor] can be attached at an address outside of the network,
+//! receiving the packets sent to it and being given the chance to wake the network when it's idle.
+
+use {
+    super::{Event, IntcodeError, Program, Value},
+    std::{error, fmt},
+};
+
+/// The address used by the NAT in the 2019 puzzles
+pub const NAT_ADDRESS: Value = 255;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Packet {
+    pub destination: Value,
+    pub x: Value,
+    pub y: Value,
+}
+
+/// Receives the packets sent to an address outside of the network
+pub trait Monitor {
+    fn receive(&mut self, packet: Packet);
+
+    /// Called after a round in which the network was idle, returns a packet to wake it up
+    fn idle(&mut self) -> Option<Packet>;
+}
+
+/// A monitor that sends the last packet it received to address 0 when the network is idle
+#[derive(Clone, Debug, Default)]
+pub struct Nat {
+    last: Option<Packet>,
+}
+
+impl Nat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Monitor for Nat {
+    fn receive(&mut self, packet: Packet) {
+        self.last = Some(packet);
+    }
+
+    fn idle(&mut self) -> Option<Packet> {
+        self.last.map(|packet| Packet {
+            destination: 0,
+            ..packet
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NetworkError {
+    /// A program in the network failed
+    Machine { address: usize, error: IntcodeError },
+    /// A packet was sent to an address that isn't in the network
+    UnknownDestination(Packet),
+    /// Packets sent during a round were addressed outside of the network
+    ///
+    /// The round was completed, with the rest of its packets delivered.
+    Undeliverable { packets: Vec<Packet>, round: Round },
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkError::Machine { address, error } => {
+                write!(f, "Error in the program at address {address}: {error}")
+            }
+            NetworkError::UnknownDestination(packet) => write!(
+                f,
+                "Packet ({}, {}) sent to unknown address {}",
+                packet.x, packet.y, packet.destination
+            ),
+            NetworkError::Undeliverable { packets, .. } => {
+                write!(f, "Packets sent to unknown addresses:")?;
+                for packet in packets {
+                    write!(f, " {} ({}, {})", packet.destination, packet.x, packet.y)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl error::Error for NetworkError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            NetworkError::Machine { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The packets sent during a round of [Network::step]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Round {
+    /// The packets sent by the network's programs, in the order they were sent
+    pub packets: Vec<Packet>,
+    /// True if no program received or sent a packet during the round
+    pub idle: bool,
+    /// The packet sent by the monitor to wake the network
+    pub monitor_packet: Option<Packet>,
+}
+
+struct Machine {
+    program: Program,
+    output: Vec<Value>,
+    halted: bool,
+}
+
+pub struct Network {
+    machines: Vec<Machine>,
+    monitor: Option<(Value, Box<dyn Monitor>)>,
+}
+
+impl Network {
+    /// Creates a network of `size` copies of the program, with addresses from 0 to `size - 1`
+    pub fn new(program: &Program, size: usize) -> Self {
+        let machines = (0..size)
+            .map(|address| {
+                let mut program = program.clone();
+                program.add_input(&[address as Value]);
+                Machine {
+                    program,
+                    output: Vec::new(),
+                    halted: false,
+                }
+            })
+            .collect();
+
+        Self {
+            machines,
+            monitor: None,
+        }
+    }
+
+    /// Attaches a monitor that receives the packets sent to the address
+    pub fn set_monitor(&mut self, address: Value, monitor: impl Monitor + 'static) {
+        self.monitor = Some((address, Box::new(monitor)));
+    }
+
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+
+    pub fn machine(&self, address: usize) -> &Program {
+        &self.machines[address].program
+    }
+
+    /// True when every program in the network has halted
+    pub fn is_halted(&self) -> bool {
+        self.machines.iter().all(|machine| machine.halted)
+    }
+
+    /// Delivers a packet to a program in the network, or to the monitor
+    pub fn send(&mut self, packet: Packet) -> Result<(), NetworkError> {
+        match &mut self.monitor {
+            Some((address, monitor)) if *address == packet.destination => monitor.receive(packet),
+            _ => {
+                let Some(machine) = usize::try_from(packet.destination)
+                    .ok()
+                    .and_then(|address| self.machines.get_mut(address))
+                else {
+                    return Err(NetworkError::UnknownDestination(packet));
+                };
+                machine.program.add_input(&[packet.x, packet.y]);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs each program in turn until it's waiting for input, delivering the packets it sends
+    ///
+    /// Packets are delivered at the end of the sending program's turn. If the network is idle
+    /// at the end of the round then the monitor is given the chance to wake it up. Packets sent
+    /// to unknown addresses don't stop the round, they're reported along with the round once
+    /// it's complete as [NetworkError::Undeliverable].
+    pub fn step(&mut self) -> Result<Round, NetworkError> {
+        let mut round = Round::default();
+        let mut received = false;
+        let mut undeliverable = Vec::new();
+
+        for address in 0..self.machines.len() {
+            let machine = &mut self.machines[address];
+            if machine.halted {
+                continue;
+            }
+
+            if machine.program.pending_inputs().is_empty() {
+                machine.program.add_input(&[-1]);
+            } else {
+                received = true;
+            }
+
+            let mut sent = Vec::new();
+            loop {
+                let event = machine
+                    .program
+                    .run_until_event()
+                    .map_err(|error| NetworkError::Machine { address, error })?;
+                match event {
+                    Event::Output(value) => {
+                        machine.output.push(value);
+                        if let [destination, x, y] = machine.output[..] {
+                            sent.push(Packet { destination, x, y });
+                            machine.output.clear();
+                        }
+                    }
+                    Event::NeedsInput => break,
+                    Event::Halted => {
+                        machine.halted = true;
+                        break;
+                    }
+                }
+            }
+
+            for packet in sent {
+                if let Err(NetworkError::UnknownDestination(packet)) = self.send(packet) {
+                    undeliverable.push(packet);
+                }
+                round.packets.push(packet);
+            }
+        }
+
+        round.idle = !received && round.packets.is_empty();
+        if round.idle {
+            if let Some((_, monitor)) = &mut self.monitor {
+                round.monitor_packet = monitor.idle();
+            }
+            if let Some(packet) = round.monitor_packet {
+                self.send(packet)?;
+            }
+        }
+
+        if !undeliverable.is_empty() {
+            return Err(NetworkError::Undeliverable {
+                packets: undeliverable,
+                round,
+            });
+        }
+        Ok(round)
+    }
+
+    /// Runs rounds until `done` returns true for one of them, returning that round
+    ///
+    /// Returns None if the network halts, or if it's idle and the monitor doesn't wake it up.
+    pub fn run_until(
+        &mut self,
+        mut done: impl FnMut(&Round) -> bool,
+    ) -> Result<Option<Round>, NetworkError> {
+        while !self.is_halted() {
+            let round = self.step()?;
+            if done(&round) {
+                return Ok(Some(round));
+            }
+            if round.idle && round.monitor_packet.is_none() {
+                return Ok(None);
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler::assemble, *};
+
+    // Forwards each packet to the next address with y incremented, the last machine in a network
+    // of 4 sends its packets to the NAT
+    const RELAY: &str = "
+            in [address]
+        receive:
+            in [x]
+            eq [x], #-1, [t]
+            jt [t], #receive
+            in [y]
+            add [address], #1, [destination]
+            eq [destination], #4, [t]
+            jf [t], #send
+            add #255, #0, [destination]
+        send:
+            out [destination]
+            out [x]
+            add [y], #1, [y]
+            out [y]
+            jt #1, #receive
+        address: data 0
+        x: data 0
+        y: data 0
+        destination: data 0
+        t: data 0
+    ";
+
+    fn relay_network() -> Network {
+        Network::new(&Program::new(&assemble(RELAY).unwrap()), 4)
+    }
+
+    fn packet(destination: Value, x: Value, y: Value) -> Packet {
+        Packet { destination, x, y }
+    }
+
+    #[test]
+    fn test_routing() {
+        let mut network = relay_network();
+        network.set_monitor(NAT_ADDRESS, Nat::new());
+        network.send(packet(0, 7, 0)).unwrap();
+
+        let round = network.step().unwrap();
+        assert_eq!(
+            vec![
+                packet(1, 7, 1),
+                packet(2, 7, 2),
+                packet(3, 7, 3),
+                packet(255, 7, 4)
+            ],
+            round.packets
+        );
+        assert!(!round.idle);
+        assert!(network.machine(0).pending_inputs().is_empty());
+    }
+
+    #[test]
+    fn test_idle_monitor() {
+        let mut network = relay_network();
+        network.set_monitor(NAT_ADDRESS, Nat::new());
+        network.send(packet(0, 7, 0)).unwrap();
+
+        let round = network.run_until(|round| round.idle).unwrap().unwrap();
+        assert!(round.packets.is_empty());
+        assert_eq!(Some(packet(0, 7, 4)), round.monitor_packet);
+
+        let round = network.step().unwrap();
+        assert_eq!(Some(&packet(255, 7, 8)), round.packets.last());
+    }
+
+    #[test]
+    fn test_idle_without_monitor() {
+        let mut network = relay_network();
+        assert_eq!(Ok(None), network.run_until(|_| false));
+        assert!(!network.is_halted());
+
+        network.send(packet(2, 1, 1)).unwrap();
+        assert_eq!(
+            Err(NetworkError::Undeliverable {
+                packets: vec![packet(255, 1, 3)],
+                round: Round {
+                    packets: vec![packet(3, 1, 2), packet(255, 1, 3)],
+                    idle: false,
+                    monitor_packet: None
+                }
+            }),
+            network.step()
+        );
+        assert_eq!(
+            Err(NetworkError::UnknownDestination(packet(-1, 0, 0))),
+            network.send(packet(-1, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_undeliverable() {
+        // A packet to an unknown address doesn't stop the packets sent after it
+        let source = "
+                in [address]
+                jt [address], #done
+                out #9
+                out #1
+                out #2
+                out #1
+                out #3
+                out #4
+            done:
+                hlt
+            address: data 0
+        ";
+        let mut network = Network::new(&Program::new(&assemble(source).unwrap()), 2);
+        let Err(NetworkError::Undeliverable { packets, round }) = network.step() else {
+            panic!("Expected an undeliverable packet");
+        };
+        assert_eq!(vec![packet(9, 1, 2)], packets);
+        assert_eq!(vec![packet(9, 1, 2), packet(1, 3, 4)], round.packets);
+        assert_eq!(&[3, 4], network.machine(1).pending_inputs());
+        assert!(network.is_halted());
+    }
+
+    #[test]
+    fn test_halted() {
+        let mut network = Network::new(&Program::new("3,7,4,7,4,7,99,0"), 2);
+        let round = network.run_until(|_| false).unwrap();
+        assert_eq!(None, round);
+        assert!(network.is_halted());
+    }
+}
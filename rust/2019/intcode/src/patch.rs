@@ -0,0 +1,214 @@
+//! Inspecting and patching a program's memory
+//!
+//! Along with reading and writing ranges of memory, programs can be modified with patches, which
+//! are loaded from text with an `address=value` pair on each line:
+//!
+//! ```
+//! use intcode::{patch::Patch, Program};
+//!
+//! let patch: Patch = "
+//!     1=12  # noun
+//!     2 = 2 # verb
+//! "
+//! .parse()
+//! .unwrap();
+//!
+//! let mut program = Program::new("1,0,0,0,99");
+//! program.apply_patch(&patch);
+//! assert_eq!(vec![1, 12, 2, 0, 99], program.peek_range(0..5));
+//! ```
+
+use {
+    super::{Address, Program, Value},
+    std::{error, fmt, fmt::Write, ops::Range, str::FromStr},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PatchErrorKind {
+    MissingSeparator(String),
+    InvalidAddress(String),
+    InvalidValue(String),
+}
+
+/// An error encountered while parsing a patch, along with the 1-based line where it occurred
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatchError {
+    pub line: usize,
+    pub kind: PatchErrorKind,
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use PatchErrorKind::*;
+
+        write!(f, "Line {}: ", self.line)?;
+        match &self.kind {
+            MissingSeparator(line) => write!(f, "Expected 'address=value', found '{line}'"),
+            InvalidAddress(address) => write!(f, "Invalid address '{address}'"),
+            InvalidValue(value) => write!(f, "Invalid value '{value}'"),
+        }
+    }
+}
+
+impl error::Error for PatchError {}
+
+/// A set of values to be written to memory
+///
+/// Blank lines and comments starting with `#` are ignored. Values are written in order, so a
+/// later line for the same address takes precedence.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Patch {
+    values: Vec<(Address, Value)>,
+}
+
+impl Patch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, address: Address, value: Value) {
+        self.values.push((address, value));
+    }
+
+    /// The addresses and values in the patch, in the order that they're written
+    pub fn values(&self) -> &[(Address, Value)] {
+        &self.values
+    }
+}
+
+impl FromStr for Patch {
+    type Err = PatchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut patch = Patch::new();
+        for (index, line) in s.lines().enumerate() {
+            let error = |kind| PatchError {
+                line: index + 1,
+                kind,
+            };
+
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((address, value)) = line.split_once('=') else {
+                return Err(error(PatchErrorKind::MissingSeparator(line.to_string())));
+            };
+            let (address, value) = (address.trim(), value.trim());
+            patch.set(
+                address
+                    .parse()
+                    .map_err(|_| error(PatchErrorKind::InvalidAddress(address.to_string())))?,
+                value
+                    .parse()
+                    .map_err(|_| error(PatchErrorKind::InvalidValue(value.to_string())))?,
+            );
+        }
+        Ok(patch)
+    }
+}
+
+impl Program {
+    /// Returns the value at an address in the program's memory
+    pub fn peek(&self, address: Address) -> Value {
+        self.read(address)
+    }
+
+    pub fn peek_range(&self, range: Range<Address>) -> Vec<Value> {
+        self.state.read_range(range)
+    }
+
+    /// Writes values to consecutive addresses, starting at `start`
+    pub fn poke_range(&mut self, start: Address, values: &[Value]) {
+        for (address, &value) in (start..).zip(values) {
+            self.write(address, value);
+        }
+    }
+
+    pub fn apply_patch(&mut self, patch: &Patch) {
+        for &(address, value) in patch.values() {
+            self.write(address, value);
+        }
+    }
+
+    /// Formats a range of memory with `columns` values per row, each row starting with its address
+    pub fn dump(&self, range: Range<Address>, columns: usize) -> String {
+        let columns = columns.max(1);
+        let values = self.peek_range(range.clone());
+        let width = values
+            .iter()
+            .map(|value| value.to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        let mut dump = String::new();
+        for (row, values) in (range.start..).step_by(columns).zip(values.chunks(columns)) {
+            write!(dump, "{row:>6}:").unwrap();
+            for value in values {
+                write!(dump, " {value:>width$}").unwrap();
+            }
+            dump.push('\n');
+        }
+        dump
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peek_and_poke() {
+        let mut program = Program::new("1,9,10,3,2,3,11,0,99,30,40,50");
+        assert_eq!(9, program.peek(1));
+        assert_eq!(0, program.peek(100));
+
+        program.poke_range(10, &[4, 5, 6]);
+        assert_eq!(vec![30, 4, 5, 6, 0], program.peek_range(9..14));
+        assert_eq!(13, program.memory().len());
+    }
+
+    #[test]
+    fn test_dump() {
+        let program = Program::new("1,9,10,3,2,3,11,0,99,30,40,50");
+        assert_eq!(
+            "     0:  1  9 10  3  2\n     5:  3 11  0 99 30\n    10: 40 50\n",
+            program.dump(0..12, 5)
+        );
+        assert_eq!("     8: 99 30\n", program.dump(8..10, 4));
+        assert_eq!("", program.dump(5..5, 4));
+    }
+
+    #[test]
+    fn test_patch() {
+        let patch: Patch = "1=12\n\n  2 = 2  # verb\n2=3\n".parse().unwrap();
+        assert_eq!(&[(1, 12), (2, 2), (2, 3)], patch.values());
+
+        // The day 2 program alarm state
+        let mut program = Program::new(include_str!("../../src/bin/input/2"));
+        program.apply_patch(&"1=12\n2=2".parse().unwrap());
+        program.run();
+        assert_eq!(3895705, program.peek(0));
+    }
+
+    #[test]
+    fn test_patch_errors() {
+        let error = |line, kind| Err(PatchError { line, kind });
+        assert_eq!(
+            error(2, PatchErrorKind::MissingSeparator("3 4".to_string())),
+            "1=2\n3 4".parse::<Patch>()
+        );
+        assert_eq!(
+            error(1, PatchErrorKind::InvalidAddress("-1".to_string())),
+            "-1=2".parse::<Patch>()
+        );
+        assert_eq!(
+            error(1, PatchErrorKind::InvalidValue("x".to_string())),
+            "1=x".parse::<Patch>()
+        );
+        assert_eq!(
+            "Line 1: Invalid value 'x'",
+            "1=x".parse::<Patch>().unwrap_err().to_string()
+        );
+    }
+}
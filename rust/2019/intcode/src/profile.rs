@@ -0,0 +1,372 @@
+//! Execution profiling
+//!
+//! While profiling is enabled, a [Profile] counts the number of times each instruction is
+//! executed, the number of times each opcode is used, and whether each conditional jump was
+//! taken. Loops are identified by jumps back to an earlier address, with the loop covering the
+//! addresses from the jump's target up to the jump instruction.
+
+use {
+    super::{instruction::Opcode, Address, Program},
+    std::{
+        collections::{BTreeMap, BTreeSet, HashMap},
+        fmt::Write,
+    },
+};
+
+/// The number of times a conditional jump was taken and not taken
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BranchStats {
+    pub taken: u64,
+    pub not_taken: u64,
+}
+
+/// A loop found by [Profile::hot_loops]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HotLoop {
+    /// The target of the backward jump
+    pub start: Address,
+    /// The address of the backward jump instruction
+    pub end: Address,
+    /// The number of times that the backward jump was taken
+    pub iterations: u64,
+    /// The number of instructions executed at addresses within the loop
+    pub instructions: u64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    executions: BTreeMap<Address, u64>,
+    opcodes: HashMap<Opcode, u64>,
+    branches: BTreeMap<Address, BranchStats>,
+    jump_targets: BTreeMap<Address, BTreeSet<Address>>,
+    /// Backward jumps, keyed by their target and the jump's address
+    back_edges: HashMap<(Address, Address), u64>,
+    /// The address of the last recorded instruction if it was a halt, as a halted program
+    /// doesn't advance and stepping it again repeats the halt
+    halted_at: Option<Address>,
+}
+
+impl Profile {
+    /// Records an executed instruction, with `taken` holding the outcome of a conditional jump
+    pub(super) fn record(
+        &mut self,
+        address: Address,
+        opcode: Opcode,
+        taken: Option<bool>,
+        next_ip: Address,
+    ) {
+        if opcode == Opcode::Halt && self.halted_at == Some(address) {
+            return;
+        }
+        self.halted_at = (opcode == Opcode::Halt).then_some(address);
+
+        *self.executions.entry(address).or_default() += 1;
+        *self.opcodes.entry(opcode).or_default() += 1;
+
+        if let Some(taken) = taken {
+            let branch = self.branches.entry(address).or_default();
+            if !taken {
+                branch.not_taken += 1;
+            } else {
+                branch.taken += 1;
+                self.jump_targets
+                    .entry(address)
+                    .or_default()
+                    .insert(next_ip);
+                if next_ip <= address {
+                    *self.back_edges.entry((next_ip, address)).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    /// The total number of instructions executed
+    pub fn instructions(&self) -> u64 {
+        self.executions.values().sum()
+    }
+
+    /// The number of times the instruction at the address was executed
+    pub fn executions(&self, address: Address) -> u64 {
+        self.executions.get(&address).copied().unwrap_or(0)
+    }
+
+    /// The addresses of executed instructions along with their execution counts, in address order
+    pub fn execution_counts(&self) -> impl Iterator<Item = (Address, u64)> + '_ {
+        self.executions
+            .iter()
+            .map(|(&address, &count)| (address, count))
+    }
+
+    /// The number of times that instructions with the opcode were executed
+    pub fn opcode_count(&self, opcode: Opcode) -> u64 {
+        self.opcodes.get(&opcode).copied().unwrap_or(0)
+    }
+
+    /// Statistics for each conditional jump that was executed, in address order
+    pub fn branches(&self) -> &BTreeMap<Address, BranchStats> {
+        &self.branches
+    }
+
+    /// The addresses that each conditional jump was seen to jump to, in address order
+    pub fn jump_targets(&self) -> &BTreeMap<Address, BTreeSet<Address>> {
+        &self.jump_targets
+    }
+
+    /// The program's loops, ordered by the number of instructions executed within them
+    pub fn hot_loops(&self) -> Vec<HotLoop> {
+        let mut loops: Vec<HotLoop> = self
+            .back_edges
+            .iter()
+            .map(|(&(start, end), &iterations)| HotLoop {
+                start,
+                end,
+                iterations,
+                instructions: self.executions.range(start..=end).map(|(_, n)| n).sum(),
+            })
+            .collect();
+        loops.sort_by(|a, b| {
+            b.instructions
+                .cmp(&a.instructions)
+                .then(a.start.cmp(&b.start))
+                .then(a.end.cmp(&b.end))
+        });
+        loops
+    }
+
+    /// Summarizes the profile, listing up to `max_loops` of the hottest loops
+    pub fn report(&self, max_loops: usize) -> String {
+        let total = self.instructions();
+        let percent = |count: u64| 100.0 * count as f64 / total.max(1) as f64;
+        let mut report = String::new();
+
+        writeln!(report, "Instructions executed: {total}").unwrap();
+
+        writeln!(report, "\nOpcodes:").unwrap();
+        for opcode in Opcode::ALL {
+            let count = self.opcode_count(opcode);
+            if count > 0 {
+                writeln!(
+                    report,
+                    "  {:<4} {count:>12} {:>6.2}%",
+                    opcode.mnemonic(),
+                    percent(count)
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(report, "\nHot loops:").unwrap();
+        for hot_loop in self.hot_loops().iter().take(max_loops) {
+            writeln!(
+                report,
+                "  {:>6}..={:<6} {:>10} iterations {:>12} instructions {:>6.2}%",
+                hot_loop.start,
+                hot_loop.end,
+                hot_loop.iterations,
+                hot_loop.instructions,
+                percent(hot_loop.instructions)
+            )
+            .unwrap();
+        }
+
+        writeln!(report, "\nBranches:").unwrap();
+        for (address, branch) in self.branches.iter() {
+            writeln!(
+                report,
+                "  {address:>6}: {:>10} taken {:>10} not taken",
+                branch.taken, branch.not_taken
+            )
+            .unwrap();
+        }
+
+        report
+    }
+}
+
+impl Program {
+    /// Starts collecting a profile of executed instructions, discarding any existing profile
+    pub fn start_profiling(&mut self) {
+        self.profile = Some(Box::default());
+    }
+
+    /// Stops profiling, returning the collected profile
+    pub fn stop_profiling(&mut self) -> Option<Profile> {
+        self.profile.take().map(|profile| *profile)
+    }
+
+    pub fn profile(&self) -> Option<&Profile> {
+        self.profile.as_deref()
+    }
+
+    /// Decodes the next instruction's opcode for the profile, along with whether it's a
+    /// conditional jump that will be taken
+    pub(super) fn profiled_instruction(&self) -> Option<(Opcode, Option<bool>)> {
+        let op = self.read(self.ip);
+        let opcode = Opcode::from_value(op)?;
+        let taken = match opcode {
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.read_parameter(1, op).ok()?;
+                Some((condition != 0) == (opcode == Opcode::JumpIfTrue))
+            }
+            _ => None,
+        };
+        Some((opcode, taken))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{assembler::assemble, Event},
+        *,
+    };
+
+    // Sums the numbers from 1 to 3, 2 times over
+    const NESTED_LOOPS: &str = "
+        outer:
+            add #3, #0, [i]
+        inner:
+            add [sum], [i], [sum]
+            add [i], #-1, [i]
+            jt [i], #inner
+            add [j], #-1, [j]
+            jt [j], #outer
+            out [sum]
+            hlt
+        i: data 0
+        j: data 2
+        sum: data 0
+    ";
+
+    #[test]
+    fn test_profile() {
+        let mut program = Program::new(&assemble(NESTED_LOOPS).unwrap());
+        program.start_profiling();
+        assert_eq!(vec![12], program.run());
+        let profile = program.stop_profiling().unwrap();
+        assert!(program.profile().is_none());
+
+        assert_eq!(2, profile.executions(0));
+        assert_eq!(6, profile.executions(4));
+        assert_eq!(0, profile.executions(5));
+        assert_eq!(2 + 6 * 3 + 2 * 2 + 2, profile.instructions());
+        assert_eq!(2 + 6 * 2 + 2, profile.opcode_count(Opcode::Add));
+        assert_eq!(1, profile.opcode_count(Opcode::Halt));
+
+        assert_eq!(
+            vec![
+                (
+                    12,
+                    BranchStats {
+                        taken: 4,
+                        not_taken: 2
+                    }
+                ),
+                (
+                    19,
+                    BranchStats {
+                        taken: 1,
+                        not_taken: 1
+                    }
+                )
+            ],
+            profile
+                .branches()
+                .iter()
+                .map(|(&address, &stats)| (address, stats))
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![(12, BTreeSet::from([4])), (19, BTreeSet::from([0]))],
+            profile
+                .jump_targets()
+                .iter()
+                .map(|(&address, targets)| (address, targets.clone()))
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![
+                HotLoop {
+                    start: 0,
+                    end: 19,
+                    iterations: 1,
+                    instructions: 24
+                },
+                HotLoop {
+                    start: 4,
+                    end: 12,
+                    iterations: 4,
+                    instructions: 18
+                }
+            ],
+            profile.hot_loops()
+        );
+
+        let report = profile.report(1);
+        assert!(report.starts_with("Instructions executed: 26\n"));
+        assert!(report.contains("\n       0..=19              1 iterations"));
+        assert!(!report.contains("\n       4..=12"));
+    }
+
+    #[test]
+    fn test_repeated_halt() {
+        // Polling a halted program doesn't count the halt again
+        let mut program = Program::new("104,1,99");
+        program.start_profiling();
+        program.start_recording(1 << 10);
+        assert_eq!(Ok(Event::Output(1)), program.run_until_event());
+        assert_eq!(Ok(Event::Halted), program.run_until_event());
+        assert_eq!(Ok(Event::Halted), program.run_until_event());
+        let profile = program.profile().unwrap();
+        assert_eq!(2, profile.instructions());
+        assert_eq!(1, profile.executions(2));
+        assert_eq!(1, profile.opcode_count(Opcode::Halt));
+
+        // Running the program again after stepping back counts the next halt
+        assert!(program.run_back_to(0));
+        assert_eq!(Ok(vec![1]), program.try_run());
+        assert_eq!(2, program.profile().unwrap().opcode_count(Opcode::Halt));
+    }
+
+    #[test]
+    fn test_jump_to_next_instruction() {
+        // A taken jump can land where execution would have continued anyway
+        let mut program = Program::new("1105,1,3,1106,1,6,99");
+        program.start_profiling();
+        assert_eq!(Ok(vec![]), program.try_run());
+        let profile = program.stop_profiling().unwrap();
+        assert_eq!(
+            vec![
+                (
+                    0,
+                    BranchStats {
+                        taken: 1,
+                        not_taken: 0
+                    }
+                ),
+                (
+                    3,
+                    BranchStats {
+                        taken: 0,
+                        not_taken: 1
+                    }
+                )
+            ],
+            profile
+                .branches()
+                .iter()
+                .map(|(&address, &stats)| (address, stats))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, BTreeSet::from([3]))],
+            profile
+                .jump_targets()
+                .iter()
+                .map(|(&address, targets)| (address, targets.clone()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
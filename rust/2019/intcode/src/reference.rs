@@ -0,0 +1,317 @@
+//! A deliberately simple reference interpreter
+//!
+//! The interpreter favours obviously correct code over speed, so that the VM can be checked
+//! against it with [differential](super::differential) testing. It doesn't share any decoding
+//! or memory code with the VM: memory is a map of non-zero cells, and each instruction is decoded
+//! from its digits as it's executed. Errors are reported in the same way as by the VM.
+
+use {
+    super::{Address, IntcodeError, Value},
+    std::collections::BTreeMap,
+};
+
+/// The state of a program at the end of a run
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Run {
+    pub output: Vec<Value>,
+    /// The non-zero cells of memory
+    pub memory: BTreeMap<Address, Value>,
+    /// One past the highest address that was loaded or written to
+    pub len: Address,
+    pub ip: Address,
+    pub relative_base: Value,
+    /// Ok if the program halted, otherwise the error that stopped it
+    pub result: Result<(), IntcodeError>,
+}
+
+struct Interpreter<'a> {
+    memory: BTreeMap<Address, Value>,
+    len: Address,
+    ip: Address,
+    relative_base: Value,
+    input: &'a [Value],
+    output: Vec<Value>,
+}
+
+/// Runs a program until it halts, fails, or has executed `budget` instructions
+pub fn run(program: &[Value], input: &[Value], budget: u64) -> Run {
+    let mut interpreter = Interpreter {
+        memory: BTreeMap::new(),
+        len: program.len() as Address,
+        ip: 0,
+        relative_base: 0,
+        input,
+        output: Vec::new(),
+    };
+    for (address, &value) in program.iter().enumerate() {
+        interpreter.set(address as Address, value);
+    }
+
+    let mut steps = 0;
+    let result = loop {
+        if steps == budget {
+            let opcode = interpreter.get(interpreter.ip);
+            break Err(IntcodeError::StepBudgetExceeded {
+                ip: interpreter.ip,
+                opcode,
+                relative_base: interpreter.relative_base,
+                budget,
+            });
+        }
+        match interpreter.step() {
+            Ok(true) => break Ok(()),
+            Ok(false) => steps += 1,
+            Err(error) => break Err(error),
+        }
+    };
+
+    Run {
+        output: interpreter.output,
+        memory: interpreter.memory,
+        len: interpreter.len,
+        ip: interpreter.ip,
+        relative_base: interpreter.relative_base,
+        result,
+    }
+}
+
+impl Interpreter<'_> {
+    fn get(&self, address: Address) -> Value {
+        self.memory.get(&address).copied().unwrap_or(0)
+    }
+
+    fn set(&mut self, address: Address, value: Value) {
+        if value == 0 {
+            self.memory.remove(&address);
+        } else {
+            self.memory.insert(address, value);
+        }
+        self.len = self.len.max(address.saturating_add(1));
+    }
+
+    fn opcode(&self) -> Value {
+        self.get(self.ip)
+    }
+
+    fn negative_address(&self, address: Value) -> IntcodeError {
+        IntcodeError::NegativeAddress {
+            ip: self.ip,
+            opcode: self.opcode(),
+            relative_base: self.relative_base,
+            address,
+        }
+    }
+
+    fn overflow(&self) -> IntcodeError {
+        IntcodeError::Overflow {
+            ip: self.ip,
+            opcode: self.opcode(),
+            relative_base: self.relative_base,
+        }
+    }
+
+    fn address(&self, value: Value) -> Result<Address, IntcodeError> {
+        if value < 0 {
+            return Err(self.negative_address(value));
+        }
+        Ok(value as Address)
+    }
+
+    /// The mode digit of the nth parameter
+    fn mode(&self, n: usize) -> Result<Value, IntcodeError> {
+        let mode = self.opcode() / 10_i64.pow(n as u32 + 1) % 10;
+        if !(0..=2).contains(&mode) {
+            return Err(IntcodeError::InvalidParameterMode {
+                ip: self.ip,
+                opcode: self.opcode(),
+                relative_base: self.relative_base,
+                parameter: n,
+            });
+        }
+        Ok(mode)
+    }
+
+    fn relative(&self, offset: Value) -> Result<Address, IntcodeError> {
+        let address = self
+            .relative_base
+            .checked_add(offset)
+            .ok_or_else(|| self.overflow())?;
+        self.address(address)
+    }
+
+    /// Reads the value of the nth parameter
+    fn read(&self, n: usize) -> Result<Value, IntcodeError> {
+        let parameter = self.get(self.ip + n as Address);
+        Ok(match self.mode(n)? {
+            0 => self.get(self.address(parameter)?),
+            1 => parameter,
+            _ => self.get(self.relative(parameter)?),
+        })
+    }
+
+    /// The address referred to by the nth parameter, which is written to
+    fn target(&self, n: usize) -> Result<Address, IntcodeError> {
+        let parameter = self.get(self.ip + n as Address);
+        Ok(match self.mode(n)? {
+            0 => self.address(parameter)?,
+            1 => {
+                return Err(IntcodeError::ImmediateModeWrite {
+                    ip: self.ip,
+                    opcode: self.opcode(),
+                    relative_base: self.relative_base,
+                    parameter: n,
+                })
+            }
+            _ => self.relative(parameter)?,
+        })
+    }
+
+    /// Writes to the address referred to by the nth parameter
+    fn write(&mut self, n: usize, value: Value) -> Result<(), IntcodeError> {
+        let address = self.target(n)?;
+        self.set(address, value);
+        Ok(())
+    }
+
+    /// Executes an instruction, returning true if the program halted
+    fn step(&mut self) -> Result<bool, IntcodeError> {
+        let opcode = self.opcode();
+        if opcode < 0 {
+            return Err(self.invalid_opcode());
+        }
+
+        match opcode % 100 {
+            1 => {
+                let value = self.read(1)?.checked_add(self.read(2)?);
+                self.write(3, value.ok_or_else(|| self.overflow())?)?;
+                self.ip += 4;
+            }
+            2 => {
+                let value = self.read(1)?.checked_mul(self.read(2)?);
+                self.write(3, value.ok_or_else(|| self.overflow())?)?;
+                self.ip += 4;
+            }
+            3 => {
+                let address = self.target(1)?;
+                let Some((&value, rest)) = self.input.split_first() else {
+                    return Err(IntcodeError::InputExhausted {
+                        ip: self.ip,
+                        opcode,
+                        relative_base: self.relative_base,
+                    });
+                };
+                self.set(address, value);
+                self.input = rest;
+                self.ip += 2;
+            }
+            4 => {
+                let value = self.read(1)?;
+                self.output.push(value);
+                self.ip += 2;
+            }
+            code @ (5 | 6) => {
+                let condition = self.read(1)?;
+                let target = self.read(2)?;
+                if (condition != 0) == (code == 5) {
+                    self.ip = self.address(target)?;
+                } else {
+                    self.ip += 3;
+                }
+            }
+            code @ (7 | 8) => {
+                let (a, b) = (self.read(1)?, self.read(2)?);
+                let result = if code == 7 { a < b } else { a == b };
+                self.write(3, result as Value)?;
+                self.ip += 4;
+            }
+            9 => {
+                let offset = self.read(1)?;
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(offset)
+                    .ok_or_else(|| self.overflow())?;
+                self.ip += 2;
+            }
+            99 => return Ok(true),
+            _ => return Err(self.invalid_opcode()),
+        }
+        Ok(false)
+    }
+
+    fn invalid_opcode(&self) -> IntcodeError {
+        IntcodeError::InvalidOpcode {
+            ip: self.ip,
+            opcode: self.opcode(),
+            relative_base: self.relative_base,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Vec<Value> {
+        super::super::parse_values(source).unwrap()
+    }
+
+    #[test]
+    fn test_run() {
+        let run = run(&parse("1,9,10,3,2,3,11,0,99,30,40,50"), &[], 100);
+        assert_eq!(Ok(()), run.result);
+        assert_eq!(Some(&3500), run.memory.get(&0));
+        assert_eq!(12, run.len);
+        assert_eq!(8, run.ip);
+
+        let quine = parse("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+        let run = super::run(&quine, &[], 1000);
+        assert_eq!(quine, run.output);
+        assert_eq!(102, run.len);
+        assert_eq!(16, run.relative_base);
+    }
+
+    #[test]
+    fn test_day_9() {
+        let program = parse(include_str!("../../src/bin/input/9"));
+        assert_eq!(vec![2494485073], run(&program, &[1], 10_000).output);
+    }
+
+    #[test]
+    fn test_errors() {
+        let run = run(&parse("3,5,3,5,99"), &[7], 100);
+        assert_eq!(
+            Err(IntcodeError::InputExhausted {
+                ip: 2,
+                opcode: 3,
+                relative_base: 0
+            }),
+            run.result
+        );
+        assert_eq!(Some(&7), run.memory.get(&5));
+
+        let run = super::run(&parse("1105,1,0"), &[], 10);
+        assert_eq!(
+            Err(IntcodeError::StepBudgetExceeded {
+                ip: 0,
+                opcode: 1105,
+                relative_base: 0,
+                budget: 10
+            }),
+            run.result
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let run = run(&parse("1102,9223372036854775807,2,0,99"), &[], 10);
+        assert_eq!(
+            Err(IntcodeError::Overflow {
+                ip: 0,
+                opcode: 1102,
+                relative_base: 0
+            }),
+            run.result
+        );
+        assert_eq!(0, run.ip);
+    }
+}
@@ -0,0 +1,243 @@
+//! Detecting self-modifying code
+//!
+//! While detection is enabled, the cells of each executed instruction are remembered, and any
+//! instruction that writes to one of them, or to one of its own cells, is recorded as a
+//! [CodeWrite]. In strict mode the write is reported as [IntcodeError::CodeWrite] instead, before
+//! the instruction is executed.
+//!
+//! Only writes made by the program itself are checked, changes made with [Program::write] aren't.
+
+use {
+    super::{instruction::Opcode, Address, IntcodeError, Program, Value},
+    std::collections::HashSet,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodeWriteTarget {
+    /// The write landed on a cell of an instruction that has already been executed
+    ExecutedCode,
+    /// The write landed on a cell of the instruction doing the writing
+    CurrentInstruction,
+}
+
+/// A write by the program to one of its instructions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CodeWrite {
+    /// The address of the instruction that made the write
+    pub ip: Address,
+    pub address: Address,
+    pub old: Value,
+    pub new: Value,
+    pub target: CodeWriteTarget,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct CodeWriteDetector {
+    strict: bool,
+    executed: HashSet<Address>,
+    writes: Vec<CodeWrite>,
+}
+
+/// A write to code that's about to be made by the next instruction
+pub(super) struct PendingCodeWrite {
+    address: Address,
+    old: Value,
+    target: CodeWriteTarget,
+}
+
+impl Program {
+    /// Starts detecting writes to code, reporting them as errors if `strict` is true
+    ///
+    /// Instructions executed before detection was enabled aren't considered to be code.
+    pub fn detect_code_writes(&mut self, strict: bool) {
+        self.code_write_detector = Some(Box::new(CodeWriteDetector {
+            strict,
+            ..Default::default()
+        }));
+    }
+
+    pub fn stop_detecting_code_writes(&mut self) {
+        self.code_write_detector = None;
+    }
+
+    pub fn is_detecting_code_writes(&self) -> bool {
+        self.code_write_detector.is_some()
+    }
+
+    /// Returns the writes to code that have been detected since the last call
+    pub fn take_code_writes(&mut self) -> Vec<CodeWrite> {
+        match &mut self.code_write_detector {
+            Some(detector) => std::mem::take(&mut detector.writes),
+            None => Vec::new(),
+        }
+    }
+
+    /// The address that the next instruction writes to, if it writes to memory
+    ///
+    /// Errors are left to be reported when the instruction is executed.
+    pub(super) fn write_address(&self) -> Option<Address> {
+        let op = self.read(self.ip);
+        let opcode = Opcode::from_value(op)?;
+        let id = (1..=opcode.parameter_count()).find(|&id| opcode.writes_parameter(id))?;
+        self.parameter_address(id, op).ok()
+    }
+
+    /// Checks whether the next instruction writes to code, failing in strict mode if it does
+    ///
+    /// Returns the instruction's opcode along with the write, to be passed to
+    /// [Program::record_code_write] once the instruction has executed.
+    pub(super) fn check_code_write(
+        &self,
+    ) -> Result<(Option<Opcode>, Option<PendingCodeWrite>), IntcodeError> {
+        let opcode = Opcode::from_value(self.read(self.ip));
+        let (Some(detector), Some(opcode)) = (&self.code_write_detector, opcode) else {
+            return Ok((opcode, None));
+        };
+        let Some(address) = self.write_address() else {
+            return Ok((Some(opcode), None));
+        };
+
+        let size = opcode.parameter_count() as Address + 1;
+        let target = if (self.ip..self.ip + size).contains(&address) {
+            CodeWriteTarget::CurrentInstruction
+        } else if detector.executed.contains(&address) {
+            CodeWriteTarget::ExecutedCode
+        } else {
+            return Ok((Some(opcode), None));
+        };
+
+        if detector.strict {
+            let (ip, opcode, relative_base) = self.error_context();
+            return Err(IntcodeError::CodeWrite {
+                ip,
+                opcode,
+                relative_base,
+                address,
+            });
+        }
+
+        let write = PendingCodeWrite {
+            address,
+            old: self.read(address),
+            target,
+        };
+        Ok((Some(opcode), Some(write)))
+    }
+
+    /// Marks an executed instruction's cells as code, and records its write to code if it made one
+    pub(super) fn record_code_write(
+        &mut self,
+        ip: Address,
+        opcode: Option<Opcode>,
+        write: Option<PendingCodeWrite>,
+    ) {
+        let new = write.as_ref().map(|write| self.read(write.address));
+        let Some(detector) = &mut self.code_write_detector else {
+            return;
+        };
+
+        if let Some(opcode) = opcode {
+            detector
+                .executed
+                .extend(ip..=ip + opcode.parameter_count() as Address);
+        }
+        if let (Some(write), Some(new)) = (write, new) {
+            detector.writes.push(CodeWrite {
+                ip,
+                address: write.address,
+                old: write.old,
+                new,
+                target: write.target,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler::assemble, *};
+
+    // Patches the operand of an earlier output instruction, then jumps back to it
+    const PATCHER: &str = "
+        show:
+            out #1
+            jt [done], #end
+            add #7, #0, [show+1]
+            add #1, #0, [done]
+            jt #1, #show
+        end:
+            hlt
+        done: data 0
+    ";
+
+    #[test]
+    fn test_code_writes() {
+        let mut program = Program::new(&assemble(PATCHER).unwrap());
+        program.detect_code_writes(false);
+        assert_eq!(Ok(vec![1, 7]), program.try_run());
+        assert_eq!(
+            vec![CodeWrite {
+                ip: 5,
+                address: 1,
+                old: 1,
+                new: 7,
+                target: CodeWriteTarget::ExecutedCode
+            }],
+            program.take_code_writes()
+        );
+        assert!(program.take_code_writes().is_empty());
+
+        let mut program = Program::new("1,1,1,1,99");
+        program.detect_code_writes(false);
+        assert_eq!(Ok(vec![]), program.try_run());
+        assert_eq!(
+            CodeWriteTarget::CurrentInstruction,
+            program.take_code_writes()[0].target
+        );
+    }
+
+    #[test]
+    fn test_strict_mode() {
+        let mut program = Program::new(&assemble(PATCHER).unwrap());
+        program.detect_code_writes(true);
+        assert_eq!(
+            Err(IntcodeError::CodeWrite {
+                ip: 5,
+                opcode: 1101,
+                relative_base: 0,
+                address: 1
+            }),
+            program.try_run()
+        );
+        // The write hasn't been made, and the program can continue without detection
+        assert_eq!(1, program.memory().get(1));
+        program.stop_detecting_code_writes();
+        assert_eq!(Ok(vec![7]), program.try_run());
+    }
+
+    #[test]
+    fn test_day_2() {
+        // The day 2 program stores its intermediate results in the operands of executed
+        // instructions, and detection doesn't change the result
+        let mut program = Program::new(include_str!("../../src/bin/input/2"));
+        program.write(1, 12);
+        program.write(2, 2);
+        program.detect_code_writes(false);
+        assert_eq!(Ok(vec![]), program.try_run());
+        assert_eq!(3895705, program.memory().get(0));
+        let writes = program.take_code_writes();
+        assert_eq!(
+            CodeWrite {
+                ip: 4,
+                address: 3,
+                old: 3,
+                new: 14,
+                target: CodeWriteTarget::ExecutedCode
+            },
+            writes[1]
+        );
+        assert!(writes
+            .iter()
+            .any(|write| write.target == CodeWriteTarget::CurrentInstruction));
+    }
+}
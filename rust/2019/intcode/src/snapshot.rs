@@ -0,0 +1,260 @@
+//! Saving and restoring the state of a [Program]
+//!
+//! Snapshots contain the program's memory, instruction pointer, relative base, and pending
+//! input. All values are stored as little-endian 64-bit integers, in the following layout:
+//!
+//!   - magic bytes (`ICSNAP\r\n`) and the format version
+//!   - `ip`, `relative_base`, and the memory length
+//!   - the number of pending inputs, followed by the inputs
+//!   - the number of allocated memory pages, followed by each page's index and values
+//!   - an FNV-1a checksum of everything that precedes it
+
+use {
+    super::{memory::Memory, memory::PAGE_SIZE, Address, Program, Value},
+    std::{error, fmt, fs, io, path::Path},
+};
+
+const MAGIC: &[u8; 8] = b"ICSNAP\r\n";
+pub const SNAPSHOT_VERSION: u64 = 1;
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(io::Error),
+    /// The data doesn't start with the snapshot magic bytes
+    NotASnapshot,
+    /// The snapshot was written with a different version of the format
+    UnsupportedVersion(u64),
+    /// The snapshot's checksum doesn't match its contents, or the contents are malformed
+    Corrupt,
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Io(error) => write!(f, "Snapshot IO error: {error}"),
+            SnapshotError::NotASnapshot => write!(f, "The data isn't an Intcode snapshot"),
+            SnapshotError::UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported snapshot version {version} (expected {SNAPSHOT_VERSION})"
+            ),
+            SnapshotError::Corrupt => write!(f, "The snapshot is corrupt"),
+        }
+    }
+}
+
+impl error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            SnapshotError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> Self {
+        SnapshotError::Io(error)
+    }
+}
+
+fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    fn read_u64(&mut self) -> Result<u64, SnapshotError> {
+        if self.bytes.len() < 8 {
+            return Err(SnapshotError::Corrupt);
+        }
+        let (value, rest) = self.bytes.split_at(8);
+        self.bytes = rest;
+        Ok(u64::from_le_bytes(value.try_into().unwrap()))
+    }
+
+    fn read_value(&mut self) -> Result<Value, SnapshotError> {
+        Ok(self.read_u64()? as Value)
+    }
+
+    fn read_values(&mut self, count: u64) -> Result<Vec<Value>, SnapshotError> {
+        if count > (self.bytes.len() / 8) as u64 {
+            return Err(SnapshotError::Corrupt);
+        }
+        (0..count).map(|_| self.read_value()).collect()
+    }
+}
+
+impl Program {
+    /// Serializes the program's state
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        let pending_inputs = self.pending_inputs();
+        let pages = self.state.pages();
+
+        let mut words = vec![
+            SNAPSHOT_VERSION,
+            self.ip,
+            self.relative_base as u64,
+            self.state.len(),
+            pending_inputs.len() as u64,
+        ];
+        words.extend(pending_inputs.iter().map(|&input| input as u64));
+        words.push(pages.len() as u64);
+        for (index, values) in pages {
+            words.push(index);
+            words.extend(values.iter().map(|&value| value as u64));
+        }
+
+        let mut bytes = MAGIC.to_vec();
+        for word in words {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes.extend_from_slice(&checksum(&bytes).to_le_bytes());
+        bytes
+    }
+
+    /// Restores a program from a snapshot produced by [Program::to_snapshot]
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let Some(contents) = bytes.strip_prefix(MAGIC) else {
+            return Err(SnapshotError::NotASnapshot);
+        };
+
+        let mut reader = Reader { bytes: contents };
+        let version = reader.read_u64()?;
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        if bytes.len() < MAGIC.len() + 16 {
+            return Err(SnapshotError::Corrupt);
+        }
+        let (data, expected_checksum) = bytes.split_at(bytes.len() - 8);
+        if checksum(data).to_le_bytes() != expected_checksum {
+            return Err(SnapshotError::Corrupt);
+        }
+        let mut reader = Reader {
+            bytes: &data[MAGIC.len() + 8..],
+        };
+
+        let ip = reader.read_u64()?;
+        let relative_base = reader.read_value()?;
+        let len = reader.read_u64()?;
+        let input_count = reader.read_u64()?;
+        let inputs = reader.read_values(input_count)?;
+        let page_count = reader.read_u64()?;
+        let pages = (0..page_count)
+            .map(|_| {
+                let index = reader.read_u64()?;
+                let values = reader.read_values(PAGE_SIZE as u64)?;
+                Ok((index as Address, values))
+            })
+            .collect::<Result<Vec<_>, SnapshotError>>()?;
+
+        if !reader.bytes.is_empty() {
+            return Err(SnapshotError::Corrupt);
+        }
+
+        Ok(Self {
+            state: Memory::from_pages(pages, len).ok_or(SnapshotError::Corrupt)?,
+            ip,
+            relative_base,
+            inputs,
+            ..Default::default()
+        })
+    }
+
+    pub fn save_snapshot(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        Ok(fs::write(path, self.to_snapshot())?)
+    }
+
+    pub fn load_snapshot(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        Self::from_snapshot(&fs::read(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::Event, *};
+
+    const QUINE: &str = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+
+    fn paused_program() -> Program {
+        let mut program = Program::new("3,50,3,51,1,50,51,1000000,4,1000000,3,52,99");
+        program.set_input(&[40, 2, 5]);
+        program.try_step().unwrap();
+        program
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut program = Program::new(QUINE);
+        let first_outputs: Vec<Value> = program.by_ref().take(5).collect();
+        assert_eq!(vec![109, 1, 204, -1, 1001], first_outputs);
+
+        let mut restored = Program::from_snapshot(&program.to_snapshot()).unwrap();
+        assert_eq!(program.ip(), restored.ip());
+        assert_eq!(program.relative_base(), restored.relative_base());
+        assert_eq!(program.run(), restored.run());
+    }
+
+    #[test]
+    fn test_pending_inputs_and_sparse_memory() {
+        let program = paused_program();
+        let mut restored = Program::from_snapshot(&program.to_snapshot()).unwrap();
+        assert_eq!(&[2, 5], restored.pending_inputs());
+        assert_eq!(Ok(Event::Output(42)), restored.run_until_event());
+        assert_eq!(42, restored.memory().get(1_000_000));
+        assert_eq!(Ok(Event::Halted), restored.run_until_event());
+        assert_eq!(5, restored.memory().get(52));
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut program = paused_program();
+        let mut cloned = program.clone();
+        assert_eq!(program.try_run(), cloned.try_run());
+    }
+
+    #[test]
+    fn test_file() {
+        let path = std::env::temp_dir().join(format!("intcode-snapshot-{}", std::process::id()));
+        let program = paused_program();
+        program.save_snapshot(&path).unwrap();
+        let restored = Program::load_snapshot(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(program.to_snapshot(), restored.to_snapshot());
+    }
+
+    #[test]
+    fn test_invalid_snapshots() {
+        let snapshot = paused_program().to_snapshot();
+
+        assert!(matches!(
+            Program::from_snapshot(b"1,2,3,99"),
+            Err(SnapshotError::NotASnapshot)
+        ));
+
+        let mut other_version = snapshot.clone();
+        other_version[MAGIC.len()] = 2;
+        assert!(matches!(
+            Program::from_snapshot(&other_version),
+            Err(SnapshotError::UnsupportedVersion(2))
+        ));
+
+        let mut corrupt = snapshot.clone();
+        corrupt[MAGIC.len() + 20] ^= 1;
+        assert!(matches!(
+            Program::from_snapshot(&corrupt),
+            Err(SnapshotError::Corrupt)
+        ));
+
+        assert!(matches!(
+            Program::from_snapshot(&snapshot[..snapshot.len() - 1]),
+            Err(SnapshotError::Corrupt)
+        ));
+    }
+}
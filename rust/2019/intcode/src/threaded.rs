@@ -0,0 +1,179 @@
+//! Running programs on their own threads, connected by channels
+//!
+//! A program's thread reads input from a [Receiver] whenever it runs out of input, and sends
+//! each output value to a [Sender]. Programs can be chained together by giving one program's
+//! output sender and the next program's input receiver the same channel.
+//!
+//! The thread finishes when the program halts, dropping its ends of the channels. If the input
+//! channel is disconnected while the program is waiting for input then the program stops with
+//! [IntcodeError::InputExhausted]. Output sent after the output receiver has been dropped is
+//! discarded.
+//!
+//! A program with an [input source](super::input) reads from it before the input channel, and
+//! only waits on the channel once the source has no input available.
+
+use {
+    super::{IntcodeError, Program, Step, Value},
+    std::{
+        panic,
+        sync::mpsc::{channel, Receiver, Sender},
+        thread::{self, JoinHandle},
+    },
+};
+
+/// A handle to a program running on its own thread
+pub struct ProgramHandle(JoinHandle<Result<Program, IntcodeError>>);
+
+impl ProgramHandle {
+    /// Waits for the program to finish, returning the halted program or the error that stopped it
+    ///
+    /// If the program's thread panicked then the panic is resumed on the calling thread.
+    pub fn join(self) -> Result<Program, IntcodeError> {
+        self.0
+            .join()
+            .unwrap_or_else(|payload| panic::resume_unwind(payload))
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+}
+
+/// A program running on its own thread along with the ends of its input and output channels
+pub struct ProgramThread {
+    pub input: Sender<Value>,
+    pub output: Receiver<Value>,
+    pub handle: ProgramHandle,
+}
+
+impl ProgramThread {
+    /// Waits for the program to finish, see [ProgramHandle::join]
+    pub fn join(self) -> Result<Program, IntcodeError> {
+        self.handle.join()
+    }
+}
+
+impl Program {
+    /// Runs the program on a new thread with new input and output channels
+    pub fn spawn(self) -> ProgramThread {
+        let (input, input_receiver) = channel();
+        let (output_sender, output) = channel();
+        ProgramThread {
+            input,
+            output,
+            handle: self.spawn_connected(input_receiver, output_sender),
+        }
+    }
+
+    /// Runs the program on a new thread, reading from `input` and writing to `output`
+    pub fn spawn_connected(
+        mut self,
+        input: Receiver<Value>,
+        output: Sender<Value>,
+    ) -> ProgramHandle {
+        ProgramHandle(thread::spawn(move || loop {
+            match self.try_step() {
+                Ok(Step::Continue) => {}
+                Ok(Step::Output(value)) => {
+                    // The output is discarded if nothing is listening
+                    let _ = output.send(value);
+                }
+                Ok(Step::Halted) => return Ok(self),
+                Err(error @ IntcodeError::InputExhausted { .. }) => match input.recv() {
+                    Ok(value) => self.add_input(&[value]),
+                    Err(_) => return Err(error),
+                },
+                Err(error) => return Err(error),
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{assembler::assemble, input},
+        *,
+    };
+
+    // Outputs double each input until it reads 0
+    const DOUBLER: &str = "
+        start:
+            in [x]
+            jf [x], #end
+            mul [x], #2, [x]
+            out [x]
+            jt #1, #start
+        end:
+            hlt
+        x: data 0
+    ";
+
+    fn doubler() -> Program {
+        Program::new(&assemble(DOUBLER).unwrap())
+    }
+
+    #[test]
+    fn test_spawn() {
+        let thread = doubler().spawn();
+        for input in [1, 2, 3] {
+            thread.input.send(input).unwrap();
+            assert_eq!(Ok(input * 2), thread.output.recv());
+        }
+        thread.input.send(0).unwrap();
+        let program = thread.handle.join().unwrap();
+        assert_eq!(14, program.ip());
+        assert!(thread.output.recv().is_err());
+    }
+
+    #[test]
+    fn test_pipeline() {
+        let (input, first_input) = channel();
+        let (first_output, second_input) = channel();
+        let (second_output, output) = channel();
+        let first = doubler().spawn_connected(first_input, first_output);
+        let second = doubler().spawn_connected(second_input, second_output);
+
+        for value in [5, 6, 0] {
+            input.send(value).unwrap();
+        }
+        assert_eq!(vec![20, 24], output.iter().collect::<Vec<_>>());
+        assert!(first.join().is_ok());
+        // The first program halts without passing on the 0, disconnecting the second's input
+        assert!(matches!(
+            second.join(),
+            Err(IntcodeError::InputExhausted { .. })
+        ));
+    }
+
+    #[test]
+    fn test_errors() {
+        let thread = doubler().spawn();
+        thread.input.send(4).unwrap();
+        assert_eq!(Ok(8), thread.output.recv());
+        drop(thread.input);
+        assert!(matches!(
+            thread.handle.join(),
+            Err(IntcodeError::InputExhausted { ip: 0, .. })
+        ));
+
+        let thread = Program::new("104,1,42").spawn();
+        assert_eq!(Ok(1), thread.output.recv());
+        assert!(matches!(
+            thread.join(),
+            Err(IntcodeError::InvalidOpcode { opcode: 42, .. })
+        ));
+    }
+
+    #[test]
+    fn test_input_source() {
+        // The source is read first, then the channel once the source is exhausted
+        let mut program = doubler();
+        program.set_input_source(input::from_iter([1, 2]));
+        let thread = program.spawn();
+        thread.input.send(5).unwrap();
+        thread.input.send(0).unwrap();
+        assert_eq!(vec![2, 4, 10], thread.output.iter().collect::<Vec<_>>());
+        assert!(thread.handle.join().is_ok());
+    }
+}
@@ -0,0 +1,241 @@
+//! Instruction-level execution tracing
+//!
+//! When tracing is enabled, a [TraceRecord] is written as a line of JSON for each instruction
+//! that the program executes.
+
+use {
+    super::{instruction::Opcode, Address, IntcodeError, Program, Step, Value},
+    std::{
+        fmt::Write as _,
+        fs::File,
+        io::{self, BufWriter, Write},
+        path::Path,
+        sync::{Arc, Mutex},
+    },
+};
+
+/// A record of a single executed instruction
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TraceRecord {
+    /// The address of the instruction
+    pub address: Address,
+    /// The instruction's value, including parameter modes
+    pub opcode: Value,
+    /// The resolved parameter values, or the target address for written parameters
+    pub operands: Vec<Value>,
+    /// The address and value written by the instruction
+    pub write: Option<(Address, Value)>,
+    /// The input consumed by the instruction
+    pub input: Option<Value>,
+    /// The output produced by the instruction
+    pub output: Option<Value>,
+}
+
+impl TraceRecord {
+    pub fn to_json(&self) -> String {
+        let optional = |value: Option<Value>| match value {
+            Some(value) => value.to_string(),
+            None => "null".to_string(),
+        };
+
+        let mut json = format!(
+            "{{\"address\":{},\"opcode\":{},\"operands\":[",
+            self.address, self.opcode
+        );
+        for (i, operand) in self.operands.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(json, "{separator}{operand}").unwrap();
+        }
+        json.push_str("],\"write\":");
+        match self.write {
+            Some((address, value)) => write!(json, "[{address},{value}]").unwrap(),
+            None => json.push_str("null"),
+        }
+        write!(
+            json,
+            ",\"input\":{},\"output\":{}}}",
+            optional(self.input),
+            optional(self.output)
+        )
+        .unwrap();
+        json
+    }
+}
+
+pub(super) struct Tracer {
+    writer: Box<dyn Write + Send>,
+    error: Option<io::Error>,
+}
+
+impl Tracer {
+    fn record(&mut self, record: &TraceRecord) {
+        // After a failed write, the error is kept to be reported by stop_tracing
+        if self.error.is_none() {
+            if let Err(error) = writeln!(self.writer, "{}", record.to_json()) {
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+/// An in-memory trace sink
+///
+/// Clones share the same buffer, so a clone can be given to the program while the original is
+/// used to read the trace.
+#[derive(Clone, Default)]
+pub struct TraceBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl TraceBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+    }
+}
+
+impl Write for TraceBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Program {
+    /// Starts writing a trace of executed instructions to the writer
+    pub fn trace_to(&mut self, writer: impl Write + Send + 'static) {
+        self.tracer = Some(Tracer {
+            writer: Box::new(writer),
+            error: None,
+        });
+    }
+
+    /// Starts writing a trace of executed instructions to a file
+    pub fn trace_to_file(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.trace_to(BufWriter::new(File::create(path)?));
+        Ok(())
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.tracer.is_some()
+    }
+
+    /// Stops tracing, flushing the trace sink
+    ///
+    /// If writing to the sink failed at any point then the error is returned here.
+    pub fn stop_tracing(&mut self) -> io::Result<()> {
+        match self.tracer.take() {
+            Some(Tracer {
+                error: Some(error), ..
+            }) => Err(error),
+            Some(mut tracer) => tracer.writer.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Executes a single instruction, returning a record of its effects along with the step
+    ///
+    /// Only the instruction is executed, the rest of a step is left to [Program::try_step].
+    fn step_with_record(&mut self) -> Result<(Step, TraceRecord), IntcodeError> {
+        let address = self.ip;
+        let op = self.read(address);
+        let mut record = TraceRecord {
+            address,
+            opcode: op,
+            ..Default::default()
+        };
+
+        // Operands are resolved before executing, in case the instruction overwrites them.
+        // Errors are ignored here and left to be reported by execute.
+        let mut write_address = None;
+        if let Some(opcode) = Opcode::from_value(op) {
+            for id in 1..=opcode.parameter_count() {
+                if opcode.writes_parameter(id) {
+                    if let Ok(target) = self.parameter_address(id, op) {
+                        write_address = Some(target);
+                        record.operands.push(target as Value);
+                    }
+                } else if let Ok(value) = self.read_parameter(id, op) {
+                    record.operands.push(value);
+                }
+            }
+        }
+
+        let step = self.execute()?;
+
+        record.write = write_address.map(|target| (target, self.read(target)));
+        if Opcode::from_value(op) == Some(Opcode::Input) {
+            record.input = record.write.map(|(_, value)| value);
+        }
+        if let Step::Output(value) = step {
+            record.output = Some(value);
+        }
+
+        Ok((step, record))
+    }
+
+    pub(super) fn traced_step(&mut self) -> Result<Step, IntcodeError> {
+        let (step, record) = self.step_with_record()?;
+        if let Some(tracer) = &mut self.tracer {
+            tracer.record(&record);
+        }
+        Ok(step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_buffer() {
+        let buffer = TraceBuffer::new();
+        let mut program = Program::new("3,9,1002,9,3,9,4,9,99,0");
+        program.set_input(&[14]);
+        program.trace_to(buffer.clone());
+        assert_eq!(Ok(vec![42]), program.try_run());
+        program.stop_tracing().unwrap();
+
+        assert_eq!(
+            vec![
+                r#"{"address":0,"opcode":3,"operands":[9],"write":[9,14],"input":14,"output":null}"#,
+                r#"{"address":2,"opcode":1002,"operands":[14,3,9],"write":[9,42],"input":null,"output":null}"#,
+                r#"{"address":6,"opcode":4,"operands":[42],"write":null,"input":null,"output":42}"#,
+                r#"{"address":8,"opcode":99,"operands":[],"write":null,"input":null,"output":null}"#,
+            ],
+            buffer.contents().lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_trace_to_file() {
+        let path = std::env::temp_dir().join(format!("intcode-trace-{}", std::process::id()));
+        let mut program = Program::new("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+        program.trace_to_file(&path).unwrap();
+        let output = program.run();
+        program.stop_tracing().unwrap();
+        assert!(!program.is_tracing());
+
+        let trace = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let outputs = trace
+            .lines()
+            .filter(|line| !line.ends_with("\"output\":null}"))
+            .count();
+        assert_eq!(output.len(), outputs);
+    }
+
+    #[test]
+    fn test_self_modifying_operands() {
+        // The add overwrites its own first operand, the record shows the value before execution
+        let mut program = Program::new("1,1,1,1,99");
+        let (step, record) = program.step_with_record().unwrap();
+        assert_eq!(Step::Continue, step);
+        assert_eq!(vec![1, 1, 1], record.operands);
+        assert_eq!(Some((1, 2)), record.write);
+    }
+}
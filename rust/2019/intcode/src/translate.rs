@@ -0,0 +1,736 @@
+//! Ahead-of-time translation of Intcode programs into Rust
+//!
+//! [translate] generates Rust source for a program, with a function for each basic block. Each
+//! function branches directly into the blocks that its block always leads to, either by falling
+//! through or through a jump with an immediate target, by continuing with their code. A jump back
+//! to the function's first block becomes a loop within the function. Other jumps return the next
+//! address to a dispatch loop, as Rust has no guaranteed tail calls between functions. The
+//! generated source defines a static [Translation] named `PROGRAM`, which is run with a
+//! [CompiledProgram] that has the same input and output interface as [Program].
+//!
+//! Translated code relies on the program's code staying as it was when it was translated. If a
+//! translated instruction is ever written to then the program falls back to the interpreter for
+//! the rest of its run. The interpreter also takes over for single instructions when a jump leads
+//! to an address that wasn't translated, when input is needed, and when an instruction would fail,
+//! so that errors and events are reported exactly as they would be by [Program].
+//!
+//! Instructions executed by translated code aren't counted by [Program::steps].
+//!
+//! ```
+//! use intcode::{translate, Program};
+//!
+//! let program = Program::new("3,9,102,2,9,9,4,9,99,0");
+//! let source = translate::translate(program.memory(), None);
+//! assert!(source.contains("pub static PROGRAM: Translation"));
+//! ```
+
+use {
+    super::{
+        control_flow::ControlFlowGraph,
+        disassembler::{Line, Operand},
+        instruction::{Opcode, ParameterMode},
+        memory::Memory,
+        profile::Profile,
+        Address, Event, IntcodeError, Program, Step, Value,
+    },
+    std::{
+        collections::{BTreeMap, BTreeSet},
+        fmt::Write,
+        ops::Range,
+    },
+};
+
+/// The most blocks that are translated into a single function, limiting the amount of code that
+/// is duplicated when blocks are reached from more than one place
+const MAX_CHAINED_BLOCKS: usize = 8;
+
+/// How translated code stopped running
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exit {
+    /// The instruction at the address needs to be executed by the interpreter
+    Interpret(Address),
+    /// A value was output, execution continues at the address
+    Output(Value, Address),
+}
+
+/// A translated program, as defined by the source generated by [translate]
+pub struct Translation {
+    /// The program's initial memory
+    pub memory: &'static [Value],
+    /// The ranges of addresses covered by the translated instructions
+    pub code: &'static [Range<Address>],
+    /// Runs translated code starting from the address, until it exits
+    pub run: fn(&mut Vm, Address) -> Exit,
+}
+
+/// The interface between translated code and the program's state
+pub struct Vm<'a> {
+    program: &'a mut Program,
+    code: &'a [bool],
+    code_written: bool,
+}
+
+impl Vm<'_> {
+    pub fn read(&self, address: Address) -> Value {
+        self.program.read(address)
+    }
+
+    /// Resolves a relative mode parameter, exiting to the interpreter if the address is negative
+    /// or overflows
+    pub fn relative_address(&self, offset: Value, ip: Address) -> Result<Address, Exit> {
+        let address = self.program.relative_base.checked_add(offset);
+        address
+            .and_then(|address| Address::try_from(address).ok())
+            .ok_or(Exit::Interpret(ip))
+    }
+
+    pub fn read_relative(&self, offset: Value, ip: Address) -> Result<Value, Exit> {
+        Ok(self.read(self.relative_address(offset, ip)?))
+    }
+
+    /// Writes to memory, exiting to the interpreter at `next` if the write modified code
+    pub fn write(&mut self, address: Address, value: Value, next: Address) -> Result<(), Exit> {
+        self.program.write(address, value);
+        if self.code.get(address as usize) == Some(&true) {
+            self.code_written = true;
+            return Err(Exit::Interpret(next));
+        }
+        Ok(())
+    }
+
+    /// Reads the next input value, exiting to the interpreter if there isn't one
+    pub fn input(&mut self, ip: Address) -> Result<Value, Exit> {
+        self.program.read_input().ok_or(Exit::Interpret(ip))
+    }
+
+    /// Adds to the relative base, exiting to the interpreter if it overflows
+    pub fn adjust_relative_base(&mut self, offset: Value, ip: Address) -> Result<(), Exit> {
+        self.program.relative_base = self
+            .program
+            .relative_base
+            .checked_add(offset)
+            .ok_or(Exit::Interpret(ip))?;
+        Ok(())
+    }
+
+    /// Adds two values, exiting to the interpreter if the sum overflows
+    pub fn add(&self, a: Value, b: Value, ip: Address) -> Result<Value, Exit> {
+        a.checked_add(b).ok_or(Exit::Interpret(ip))
+    }
+
+    /// Multiplies two values, exiting to the interpreter if the product overflows
+    pub fn multiply(&self, a: Value, b: Value, ip: Address) -> Result<Value, Exit> {
+        a.checked_mul(b).ok_or(Exit::Interpret(ip))
+    }
+
+    /// Resolves a computed jump target, exiting to the interpreter if it's negative
+    pub fn jump(&self, target: Value, ip: Address) -> Result<Address, Exit> {
+        Address::try_from(target).map_err(|_| Exit::Interpret(ip))
+    }
+}
+
+/// A translated program along with its execution state
+pub struct CompiledProgram {
+    program: Program,
+    translation: &'static Translation,
+    code: Vec<bool>,
+    code_written: bool,
+}
+
+impl CompiledProgram {
+    pub fn new(translation: &'static Translation) -> Self {
+        let mut code = Vec::new();
+        for range in translation.code {
+            let range = range.start as usize..range.end as usize;
+            if range.end > code.len() {
+                code.resize(range.end, false);
+            }
+            code[range].fill(true);
+        }
+
+        Self {
+            program: Program {
+                state: Memory::from(translation.memory),
+                ..Default::default()
+            },
+            translation,
+            code,
+            code_written: false,
+        }
+    }
+
+    fn is_code(&self, address: Address) -> bool {
+        self.code.get(address as usize) == Some(&true)
+    }
+
+    /// Replaces the program's pending input
+    pub fn set_input(&mut self, input: &[Value]) {
+        self.program.set_input(input);
+    }
+
+    /// Appends to the program's pending input, preserving any input that hasn't been read yet
+    pub fn add_input(&mut self, input: &[Value]) {
+        self.program.add_input(input);
+    }
+
+    /// Sets the value at an address in the program's memory
+    ///
+    /// Writing to a translated instruction switches the program to the interpreter.
+    pub fn write(&mut self, position: Address, value: Value) {
+        self.program.write(position, value);
+        if self.is_code(position) {
+            self.code_written = true;
+        }
+    }
+
+    pub fn memory(&self) -> &Memory {
+        self.program.memory()
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn into_program(self) -> Program {
+        self.program
+    }
+
+    /// Returns true if the program's code has been modified, so it's run by the interpreter
+    pub fn is_interpreting(&self) -> bool {
+        self.code_written
+    }
+
+    /// Runs translated code until it exits, then executes a single instruction if needed
+    fn try_step(&mut self) -> Result<Step, IntcodeError> {
+        if !self.code_written {
+            let ip = self.program.ip;
+            let mut vm = Vm {
+                program: &mut self.program,
+                code: &self.code,
+                code_written: false,
+            };
+            let exit = (self.translation.run)(&mut vm, ip);
+            self.code_written = vm.code_written;
+            match exit {
+                Exit::Output(value, next) => {
+                    self.program.ip = next;
+                    return Ok(Step::Output(value));
+                }
+                Exit::Interpret(ip) => self.program.ip = ip,
+            }
+        }
+
+        let written = match self.code_written {
+            true => None,
+            false => self.program.write_address(),
+        };
+        let step = self.program.try_step()?;
+        if written.is_some_and(|address| self.is_code(address)) {
+            self.code_written = true;
+        }
+        Ok(step)
+    }
+
+    /// Runs the program until it halts, returning all of its output
+    ///
+    /// Panics if an error occurs, see [CompiledProgram::try_run].
+    pub fn run(&mut self) -> Vec<Value> {
+        self.collect()
+    }
+
+    /// Runs the program until it halts, returning all of its output
+    pub fn try_run(&mut self) -> Result<Vec<Value>, IntcodeError> {
+        let mut output = Vec::new();
+        loop {
+            match self.try_step()? {
+                Step::Continue => {}
+                Step::Output(value) => output.push(value),
+                Step::Halted => return Ok(output),
+            }
+        }
+    }
+
+    /// Runs the program until it produces output, needs input, or halts
+    pub fn run_until_event(&mut self) -> Result<Event, IntcodeError> {
+        loop {
+            match self.try_step() {
+                Ok(Step::Continue) => {}
+                Ok(Step::Output(value)) => return Ok(Event::Output(value)),
+                Ok(Step::Halted) => return Ok(Event::Halted),
+                Err(IntcodeError::InputExhausted { .. }) => return Ok(Event::NeedsInput),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+impl Iterator for CompiledProgram {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.try_step() {
+                Ok(Step::Continue) => {}
+                Ok(Step::Output(value)) => return Some(value),
+                Ok(Step::Halted) => return None,
+                Err(error) => panic!("{error}"),
+            }
+        }
+    }
+}
+
+/// Return addresses of subroutine calls, i.e. immediate values that point just past a jump
+fn return_addresses(graph: &ControlFlowGraph) -> Vec<Address> {
+    let ends: BTreeSet<Address> = graph
+        .blocks()
+        .filter(|block| {
+            block
+                .successors
+                .iter()
+                .all(|edge| edge.target != block.end())
+        })
+        .map(|block| block.end())
+        .collect();
+
+    graph
+        .blocks()
+        .flat_map(|block| block.lines.iter())
+        .filter_map(|line| match line {
+            Line::Instruction { operands, .. } => Some(operands),
+            Line::Data { .. } => None,
+        })
+        .flatten()
+        .filter(|operand| operand.mode == ParameterMode::Immediate && operand.value >= 0)
+        .map(|operand| operand.value as Address)
+        .filter(|address| ends.contains(address) && graph.block(*address).is_none())
+        .collect()
+}
+
+/// An expression that reads the operand, or None if its address is invalid
+fn read_operand(operand: &Operand, ip: Address) -> Option<String> {
+    match operand.mode {
+        ParameterMode::Position if operand.value < 0 => None,
+        ParameterMode::Position => Some(format!("vm.read({})", operand.value)),
+        ParameterMode::Immediate => Some(operand.value.to_string()),
+        ParameterMode::Relative => Some(format!("vm.read_relative({}, {ip})?", operand.value)),
+    }
+}
+
+/// An expression for the address written to by the operand, or None if it's invalid
+fn write_address(operand: &Operand, ip: Address) -> Option<String> {
+    match operand.mode {
+        ParameterMode::Position if operand.value < 0 => None,
+        ParameterMode::Position => Some(operand.value.to_string()),
+        ParameterMode::Immediate => None,
+        ParameterMode::Relative => Some(format!("vm.relative_address({}, {ip})?", operand.value)),
+    }
+}
+
+/// A line of a translated block
+enum Statement {
+    Code(String),
+    /// A conditional jump to an address that's known ahead of time
+    Branch {
+        condition: String,
+        target: Address,
+    },
+}
+
+/// The generated statements of a block
+#[derive(Default)]
+struct Block {
+    statements: Vec<Statement>,
+    /// Whether the statements use the block's `vm` parameter
+    uses_vm: bool,
+}
+
+impl Block {
+    fn statement(&mut self, statement: &str, uses_vm: bool) {
+        self.statements.push(Statement::Code(statement.to_string()));
+        self.uses_vm |= uses_vm;
+    }
+
+    fn branches_to(&self, address: Address) -> bool {
+        self.statements.iter().any(
+            |statement| matches!(statement, Statement::Branch { target, .. } if *target == address),
+        )
+    }
+
+    /// Writes the statements, with jumps to `start` continuing a loop around them if `looped`
+    fn write(&self, source: &mut String, start: Address, looped: bool) {
+        let indent = if looped { "        " } else { "    " };
+        for statement in self.statements.iter() {
+            match statement {
+                Statement::Code(code) => writeln!(source, "{indent}{code}").unwrap(),
+                Statement::Branch { condition, target } => {
+                    writeln!(source, "{indent}if {condition} {{").unwrap();
+                    if looped && *target == start {
+                        writeln!(source, "{indent}    continue;").unwrap();
+                    } else {
+                        writeln!(source, "{indent}    return Ok({target});").unwrap();
+                    }
+                    writeln!(source, "{indent}}}").unwrap();
+                }
+            }
+        }
+    }
+}
+
+/// How execution leaves a translated instruction
+enum End {
+    /// Execution continues with the next instruction
+    Continue,
+    /// Execution always continues at the address
+    Jump(Address),
+    /// The block ends with the expression as its result
+    Exit(String),
+}
+
+/// Translates an instruction into statements, returning how execution leaves it
+fn translate_instruction(block: &mut Block, line: &Line) -> End {
+    let Line::Instruction {
+        address: ip,
+        instruction,
+        operands,
+    } = line
+    else {
+        unreachable!("Only instructions are translated");
+    };
+    let ip = *ip;
+    let next = ip + line.size();
+
+    let reads: Option<Vec<String>> = operands
+        .iter()
+        .enumerate()
+        .map(
+            |(i, operand)| match instruction.opcode.writes_parameter(i + 1) {
+                true => write_address(operand, ip),
+                false => read_operand(operand, ip),
+            },
+        )
+        .collect();
+    let Some(reads) = reads else {
+        // The interpreter reports the invalid address
+        return End::Exit(format!("Err(Exit::Interpret({ip}))"));
+    };
+    let binary = |block: &mut Block, expression: &str| {
+        block.statement(&format!("let a = {};", reads[0]), true);
+        block.statement(&format!("let b = {};", reads[1]), true);
+        block.statement(&format!("let address = {};", reads[2]), true);
+        block.statement(&format!("vm.write(address, {expression}, {next})?;"), true);
+        End::Continue
+    };
+    let jump = |block: &mut Block, taken_when: bool| {
+        let (target, target_uses_vm) = match operands[1].mode {
+            ParameterMode::Immediate if operands[1].value >= 0 => {
+                (format!("Ok({})", reads[1]), false)
+            }
+            _ => (format!("vm.jump({}, {ip})", reads[1]), true),
+        };
+        match operands[0].mode {
+            ParameterMode::Immediate if (operands[0].value != 0) == taken_when => {
+                match operands[1].mode {
+                    ParameterMode::Immediate if operands[1].value >= 0 => {
+                        End::Jump(operands[1].value as Address)
+                    }
+                    _ => {
+                        block.uses_vm = true;
+                        End::Exit(target)
+                    }
+                }
+            }
+            ParameterMode::Immediate => End::Continue,
+            _ => {
+                let comparison = if taken_when { "!=" } else { "==" };
+                let condition = format!("{} {comparison} 0", reads[0]);
+                match operands[1].mode {
+                    ParameterMode::Immediate if operands[1].value >= 0 => {
+                        block.statements.push(Statement::Branch {
+                            condition,
+                            target: operands[1].value as Address,
+                        });
+                        block.uses_vm = true;
+                    }
+                    _ => {
+                        block.statement(&format!("if {condition} {{"), true);
+                        block.statement(&format!("    return {target};"), target_uses_vm);
+                        block.statement("}", false);
+                    }
+                }
+                End::Continue
+            }
+        }
+    };
+
+    match instruction.opcode {
+        Opcode::Add => binary(block, &format!("vm.add(a, b, {ip})?")),
+        Opcode::Multiply => binary(block, &format!("vm.multiply(a, b, {ip})?")),
+        Opcode::LessThan => binary(block, "(a < b) as intcode::Value"),
+        Opcode::Equals => binary(block, "(a == b) as intcode::Value"),
+        Opcode::Input => {
+            block.statement(&format!("let address = {};", reads[0]), true);
+            block.statement(&format!("let value = vm.input({ip})?;"), true);
+            block.statement(&format!("vm.write(address, value, {next})?;"), true);
+            End::Continue
+        }
+        Opcode::Output => {
+            block.uses_vm |= operands[0].mode != ParameterMode::Immediate;
+            End::Exit(format!("Err(Exit::Output({}, {next}))", reads[0]))
+        }
+        Opcode::JumpIfTrue => jump(block, true),
+        Opcode::JumpIfFalse => jump(block, false),
+        Opcode::AdjustRelativeBase => {
+            let statement = format!("vm.adjust_relative_base({}, {ip})?;", reads[0]);
+            block.statement(&statement, true);
+            End::Continue
+        }
+        Opcode::Halt => End::Exit(format!("Err(Exit::Interpret({ip}))")),
+    }
+}
+
+/// Generates Rust source for the program in memory
+///
+/// Instructions are found by following jumps from address 0, along with the jump targets observed
+/// in the profile if one is given, and the return addresses of subroutine calls. The generated
+/// source uses items from this module, and can be included in a module with `include!`.
+pub fn translate(memory: &Memory, profile: Option<&Profile>) -> String {
+    let mut entries = Vec::new();
+    let graph = loop {
+        let graph = ControlFlowGraph::build_with_entries(memory, profile, &entries);
+        let returns: Vec<Address> = return_addresses(&graph)
+            .into_iter()
+            .filter(|address| !entries.contains(address))
+            .collect();
+        if returns.is_empty() {
+            break graph;
+        }
+        entries.extend(returns);
+    };
+
+    // Split blocks at input instructions and after output instructions, so that translated code
+    // can be resumed after either
+    let mut blocks: Vec<Vec<&Line>> = Vec::new();
+    for block in graph.blocks() {
+        let mut current = Vec::new();
+        for line in block.lines.iter() {
+            let opcode = match line {
+                Line::Instruction { instruction, .. } => Some(instruction.opcode),
+                Line::Data { .. } => None,
+            };
+            if opcode == Some(Opcode::Input) && !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+            current.push(line);
+            if opcode == Some(Opcode::Output) {
+                blocks.push(std::mem::take(&mut current));
+            }
+        }
+        if !current.is_empty() {
+            blocks.push(current);
+        }
+    }
+
+    let mut source = String::new();
+    writeln!(source, "// Generated by intcode::translate\n").unwrap();
+    writeln!(
+        source,
+        "use intcode::{{\n    translate::{{Exit, Translation, Vm}},\n    Address,\n}};\n"
+    )
+    .unwrap();
+
+    let memory_values: Vec<String> = memory
+        .read_range(0..memory.len())
+        .iter()
+        .map(Value::to_string)
+        .collect();
+    let mut code: Vec<Range<Address>> = Vec::new();
+    for line in blocks.iter().flatten() {
+        let range = line.address()..line.address() + line.size();
+        match code.last_mut() {
+            Some(last) if last.end == range.start => last.end = range.end,
+            _ => code.push(range),
+        }
+    }
+    let code: Vec<String> = code
+        .iter()
+        .map(|range| format!("{}..{}", range.start, range.end))
+        .collect();
+    writeln!(source, "#[allow(clippy::single_range_in_vec_init)]").unwrap();
+    writeln!(source, "pub static PROGRAM: Translation = Translation {{").unwrap();
+    writeln!(source, "    memory: &[{}],", memory_values.join(", ")).unwrap();
+    writeln!(source, "    code: &[{}],", code.join(", ")).unwrap();
+    writeln!(source, "    run,\n}};\n").unwrap();
+
+    writeln!(source, "fn run(vm: &mut Vm, mut ip: Address) -> Exit {{").unwrap();
+    writeln!(source, "    loop {{").unwrap();
+    writeln!(source, "        let next = match ip {{").unwrap();
+    for block in blocks.iter() {
+        let start = block[0].address();
+        writeln!(source, "            {start} => block_{start}(vm),").unwrap();
+    }
+    writeln!(source, "            _ => return Exit::Interpret(ip),").unwrap();
+    writeln!(source, "        }};").unwrap();
+    writeln!(source, "        match next {{").unwrap();
+    writeln!(source, "            Ok(next) => ip = next,").unwrap();
+    writeln!(source, "            Err(exit) => return exit,").unwrap();
+    writeln!(source, "        }}\n    }}\n}}").unwrap();
+
+    // Translate each block, noting the address that it always continues at, if any
+    let mut translated: BTreeMap<Address, (Block, Result<Address, String>)> = BTreeMap::new();
+    for lines in blocks.iter() {
+        let mut block = Block::default();
+        let mut end = End::Continue;
+        for line in lines.iter() {
+            block.statement(&format!("// {}", line.to_string().trim_start()), false);
+            end = translate_instruction(&mut block, line);
+        }
+        let successor = match end {
+            End::Continue => {
+                let last = lines[lines.len() - 1];
+                Ok(last.address() + last.size())
+            }
+            End::Jump(target) => Ok(target),
+            End::Exit(result) => Err(result),
+        };
+        translated.insert(lines[0].address(), (block, successor));
+    }
+
+    // Each block's function continues into the blocks that it always leads to, with a loop back
+    // to its own start when they lead back to it, so only dynamic jumps and exits return to run
+    for &start in translated.keys() {
+        let mut chain = vec![start];
+        let result = loop {
+            let (_, successor) = &translated[chain.last().unwrap()];
+            match successor {
+                Ok(target) if *target == start => break None,
+                Ok(target)
+                    if translated.contains_key(target)
+                        && !chain.contains(target)
+                        && chain.len() < MAX_CHAINED_BLOCKS =>
+                {
+                    chain.push(*target)
+                }
+                Ok(target) => break Some(format!("Ok({target})")),
+                Err(result) => break Some(result.clone()),
+            }
+        };
+        let blocks: Vec<&Block> = chain.iter().map(|address| &translated[address].0).collect();
+        let looped = result.is_none() || blocks.iter().any(|block| block.branches_to(start));
+
+        let uses_vm = blocks.iter().any(|block| block.uses_vm);
+        let vm = if uses_vm { "vm" } else { "_vm" };
+        writeln!(
+            source,
+            "\nfn block_{start}({vm}: &mut Vm) -> Result<Address, Exit> {{"
+        )
+        .unwrap();
+        if looped {
+            writeln!(source, "    loop {{").unwrap();
+        }
+        for block in blocks {
+            block.write(&mut source, start, looped);
+        }
+        match (result, looped) {
+            (Some(result), true) => writeln!(source, "        return {result};\n    }}").unwrap(),
+            (Some(result), false) => writeln!(source, "    {result}").unwrap(),
+            (None, _) => writeln!(source, "    }}").unwrap(),
+        }
+        writeln!(source, "}}").unwrap();
+    }
+
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler::assemble, *};
+
+    #[test]
+    fn test_translate() {
+        let source = "
+                in [n]
+            loop:
+                jf [n], #done
+                add #return, #0, rb+0
+                jt #1, #double
+            return:
+                add [n], #-1, [n]
+                jt #1, #loop
+            done:
+                hlt
+            double:
+                mul [n], #2, [t]
+                out [t]
+                jf #0, rb+0
+            n: data 0
+            t: data 0
+        ";
+        let program = Program::new(&assemble(source).unwrap());
+        let translated = translate(program.memory(), None);
+
+        assert!(translated.contains("    code: &[0..29],\n"));
+        // The return address is translated as an entry point
+        assert!(translated.contains("            12 => block_12(vm),\n"));
+        // Blocks continue directly into the blocks that they always lead to
+        assert!(translated.contains(
+            "\nfn block_12(vm: &mut Vm) -> Result<Address, Exit> {
+    // 12: add  [29], #-1, [29]
+    let a = vm.read(29);
+    let b = -1;
+    let address = 29;
+    vm.write(address, vm.add(a, b, 12)?, 16)?;
+    // 16: jt   #1, #2
+    // 2: jf   [29], #19
+    if vm.read(29) == 0 {
+        return Ok(19);
+    }
+    // 5: add  #12, #0, rb+0
+"
+        ));
+        assert!(translated.contains(
+            "
+    // 9: jt   #1, #20
+    // 20: mul  [29], #2, [30]
+"
+        ));
+        assert!(translated.contains("    Err(Exit::Output(vm.read(30), 26))\n}\n"));
+        assert!(translated.contains("    vm.jump(vm.read_relative(0, 26)?, 26)\n}\n"));
+    }
+
+    #[test]
+    fn test_loop() {
+        // Counts down from the input, outputting 0
+        let source = "
+                in [n]
+            loop:
+                add [n], #-1, [n]
+                jt [n], #loop
+                out [n]
+                hlt
+            n: data 0
+        ";
+        let program = Program::new(&assemble(source).unwrap());
+        let translated = translate(program.memory(), None);
+
+        // A jump back to the start of a block's function continues a loop within it
+        assert!(translated.contains(
+            "\nfn block_2(vm: &mut Vm) -> Result<Address, Exit> {
+    loop {
+        // 2: add  [12], #-1, [12]
+        let a = vm.read(12);
+        let b = -1;
+        let address = 12;
+        vm.write(address, vm.add(a, b, 2)?, 6)?;
+        // 6: jt   [12], #2
+        if vm.read(12) != 0 {
+            continue;
+        }
+        // 9: out  [12]
+        return Err(Exit::Output(vm.read(12), 11));
+    }
+}\n"
+        ));
+        // Elsewhere it's a jump to the block
+        assert!(translated.contains("\n    if vm.read(12) != 0 {\n        return Ok(2);\n"));
+    }
+}
@@ -0,0 +1,194 @@
+// The example programs from the puzzle descriptions of each day that uses Intcode
+
+use {
+    intcode::{parse_values, Program, Value},
+    std::sync::mpsc::channel,
+};
+
+fn final_memory(source: &str) -> Vec<Value> {
+    let mut program = Program::new(source);
+    assert_eq!(Ok(vec![]), program.try_run());
+    program.peek_range(0..program.memory().len())
+}
+
+fn output(source: &str, input: &[Value]) -> Vec<Value> {
+    let mut program = Program::new(source);
+    program.set_input(input);
+    program.try_run().unwrap()
+}
+
+// Runs a chain of amplifiers, each given their phase followed by the previous amp's output
+fn amplifiers(source: &str, phases: &[Value], feedback: bool) -> Value {
+    let (circuit_input, mut input) = channel();
+    let amps: Vec<_> = phases
+        .iter()
+        .map(|&phase| {
+            let (output, next_input) = channel();
+            let mut amp = Program::new(source);
+            amp.set_input(&[phase]);
+            amp.spawn_connected(std::mem::replace(&mut input, next_input), output)
+        })
+        .collect();
+
+    circuit_input.send(0).unwrap();
+    let mut result = None;
+    for signal in input {
+        result = Some(signal);
+        if feedback {
+            let _ = circuit_input.send(signal);
+        }
+    }
+    for amp in amps {
+        amp.join().unwrap();
+    }
+    result.unwrap()
+}
+
+#[test]
+fn test_day_2() {
+    assert_eq!(
+        vec![3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50],
+        final_memory("1,9,10,3,2,3,11,0,99,30,40,50")
+    );
+    assert_eq!(vec![2, 0, 0, 0, 99], final_memory("1,0,0,0,99"));
+    assert_eq!(vec![2, 3, 0, 6, 99], final_memory("2,3,0,3,99"));
+    assert_eq!(vec![2, 4, 4, 5, 99, 9801], final_memory("2,4,4,5,99,0"));
+    assert_eq!(
+        vec![30, 1, 1, 4, 2, 5, 6, 0, 99],
+        final_memory("1,1,1,4,99,5,6,0,99")
+    );
+}
+
+#[test]
+fn test_day_5_io_and_modes() {
+    assert_eq!(vec![42], output("3,0,4,0,99", &[42]));
+    assert_eq!(vec![1002, 4, 3, 4, 99], final_memory("1002,4,3,4,33"));
+    assert_eq!(vec![1101, 100, -1, 4, 99], final_memory("1101,100,-1,4,0"));
+}
+
+#[test]
+fn test_day_5_comparisons() {
+    let cases = [
+        ("3,9,8,9,10,9,4,9,99,-1,8", [0, 1, 0]),
+        ("3,9,7,9,10,9,4,9,99,-1,8", [1, 0, 0]),
+        ("3,3,1108,-1,8,3,4,3,99", [0, 1, 0]),
+        ("3,3,1107,-1,8,3,4,3,99", [1, 0, 0]),
+    ];
+    for (source, expected) in cases {
+        for (input, expected) in [7, 8, 9].into_iter().zip(expected) {
+            assert_eq!(vec![expected], output(source, &[input]), "{source}");
+        }
+    }
+}
+
+#[test]
+fn test_day_5_jumps() {
+    for source in [
+        "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9",
+        "3,3,1105,-1,9,1101,0,0,12,4,12,99,1",
+    ] {
+        assert_eq!(vec![0], output(source, &[0]));
+        assert_eq!(vec![1], output(source, &[1]));
+        assert_eq!(vec![1], output(source, &[-1]));
+    }
+
+    let source =
+        "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,\
+         20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+    assert_eq!(vec![999], output(source, &[7]));
+    assert_eq!(vec![1000], output(source, &[8]));
+    assert_eq!(vec![1001], output(source, &[9]));
+}
+
+#[test]
+fn test_day_5_diagnostics() {
+    // The diagnostic program outputs 0 for each test that passes, followed by a diagnostic code
+    let source = include_str!("../../src/bin/input/5");
+    for system_id in [1, 5] {
+        let output = output(source, &[system_id]);
+        assert!(output[..output.len() - 1].iter().all(|&x| x == 0));
+    }
+}
+
+#[test]
+fn test_day_7() {
+    assert_eq!(
+        43210,
+        amplifiers(
+            "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0",
+            &[4, 3, 2, 1, 0],
+            false
+        )
+    );
+    assert_eq!(
+        54321,
+        amplifiers(
+            "3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0",
+            &[0, 1, 2, 3, 4],
+            false
+        )
+    );
+    assert_eq!(
+        65210,
+        amplifiers(
+            "3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,\
+             31,4,31,99,0,0,0",
+            &[1, 0, 4, 3, 2],
+            false
+        )
+    );
+}
+
+#[test]
+fn test_day_7_feedback() {
+    assert_eq!(
+        139629729,
+        amplifiers(
+            "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,\
+             5",
+            &[9, 8, 7, 6, 5],
+            true
+        )
+    );
+    assert_eq!(
+        18216,
+        amplifiers(
+            "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,\
+             1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,\
+             0,0,10",
+            &[9, 7, 8, 5, 6],
+            true
+        )
+    );
+}
+
+#[test]
+fn test_day_9() {
+    let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    assert_eq!(parse_values(quine).unwrap(), output(quine, &[]));
+    assert_eq!(
+        vec![1219070632396864],
+        output("1102,34915192,34915192,7,4,7,99,0", &[])
+    );
+    assert_eq!(
+        vec![1125899906842624],
+        output("104,1125899906842624,99", &[])
+    );
+}
+
+#[test]
+fn test_day_9_boost_self_test() {
+    // In test mode the BOOST program outputs any opcodes that it finds to be malfunctioning,
+    // followed by a keycode
+    let output = output(include_str!("../../src/bin/input/9"), &[1]);
+    assert_eq!(1, output.len(), "Malfunctioning opcodes: {output:?}");
+}
+
+#[test]
+fn test_day_9_relative_base() {
+    // Writes to and then reads from address 20 in relative mode, with different relative bases
+    assert_eq!(
+        vec![42],
+        output("109,20,21101,40,2,0,109,-10,204,10,99", &[])
+    );
+}
@@ -0,0 +1,45 @@
+// Compares the VM against the reference interpreter on random programs and puzzle inputs
+
+use intcode::{
+    differential::{compare, random_program, Divergence, Rng},
+    parse_values, Value,
+};
+
+const BUDGET: u64 = 2000;
+
+fn check(divergence: Option<Divergence>) {
+    if let Some(divergence) = divergence {
+        let minimal = divergence.shrink();
+        panic!(
+            "The VM diverged from the reference interpreter\n\
+             program: {:?}\ninput: {:?}\nexpected: {:#?}\nactual: {:#?}",
+            minimal.program, minimal.input, minimal.expected, minimal.actual
+        );
+    }
+}
+
+#[test]
+fn test_random_programs() {
+    for seed in 0..20 {
+        let mut rng = Rng::new(seed);
+        for instructions in [1, 5, 20, 50] {
+            for _ in 0..25 {
+                let (program, input) = random_program(&mut rng, instructions);
+                check(compare(&program, &input, BUDGET));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_puzzle_inputs() {
+    let cases: [(&str, &[Value]); 4] = [
+        (include_str!("../../src/bin/input/2"), &[]),
+        (include_str!("../../src/bin/input/5"), &[1]),
+        (include_str!("../../src/bin/input/5"), &[5]),
+        (include_str!("../../src/bin/input/9"), &[1]),
+    ];
+    for (source, input) in cases {
+        check(compare(&parse_values(source).unwrap(), input, 100_000));
+    }
+}
@@ -0,0 +1,127 @@
+// Checks translated puzzle programs against the interpreter
+//
+// The translations in `translated/` are generated by intcode-translate and checked in, so that
+// building the puzzles doesn't depend on the translator. After changing the translator, update
+// them from the workspace directory with:
+//
+//   cargo run -p intcode --bin intcode-translate -- src/bin/input/9 > intcode/tests/translated/9.rs
+//   cargo run -p intcode --bin intcode-translate -- src/bin/input/13 > intcode/tests/translated/13.rs
+//   cargo run -p intcode --bin intcode-translate -- intcode/tests/translated/self_modifying \
+//       > intcode/tests/translated/self_modifying.rs
+
+use intcode::{
+    translate::{translate, CompiledProgram, Translation},
+    Event, Program, Value,
+};
+
+mod day_9 {
+    include!("translated/9.rs");
+}
+
+mod day_13 {
+    include!("translated/13.rs");
+}
+
+mod self_modifying {
+    include!("translated/self_modifying.rs");
+}
+
+const BOOST: &str = include_str!("../../src/bin/input/9");
+const ARCADE: &str = include_str!("../../src/bin/input/13");
+const SELF_MODIFYING: &str = include_str!("translated/self_modifying");
+
+/// Runs a translated program alongside the interpreter until they halt, checking that they
+/// produce the same events and end in the same state
+///
+/// Each time input is needed, the next value is taken from `inputs`, repeating as necessary.
+fn compare_with_interpreter(
+    mut compiled: CompiledProgram,
+    mut interpreted: Program,
+    inputs: &[Value],
+) -> CompiledProgram {
+    let mut inputs = inputs.iter().cycle();
+    loop {
+        let event = interpreted.run_until_event();
+        assert_eq!(event, compiled.run_until_event());
+        match event.unwrap() {
+            Event::Output(_) => {}
+            Event::NeedsInput => {
+                let input = *inputs.next().expect("Unexpected input request");
+                interpreted.add_input(&[input]);
+                compiled.add_input(&[input]);
+            }
+            Event::Halted => break,
+        }
+    }
+
+    assert_eq!(interpreted.memory(), compiled.memory());
+    assert_eq!(interpreted.ip(), compiled.program().ip());
+    assert_eq!(
+        interpreted.relative_base(),
+        compiled.program().relative_base()
+    );
+    compiled
+}
+
+fn compare(translation: &'static Translation, source: &str, inputs: &[Value]) -> CompiledProgram {
+    compare_with_interpreter(
+        CompiledProgram::new(translation),
+        Program::new(source),
+        inputs,
+    )
+}
+
+#[test]
+fn test_translations_are_current() {
+    for (path, source, translation) in [
+        ("translated/9.rs", BOOST, include_str!("translated/9.rs")),
+        ("translated/13.rs", ARCADE, include_str!("translated/13.rs")),
+        (
+            "translated/self_modifying.rs",
+            SELF_MODIFYING,
+            include_str!("translated/self_modifying.rs"),
+        ),
+    ] {
+        assert!(
+            translate(Program::new(source).memory(), None) == translation,
+            "{path} is out of date, see the top of this file for how to update it"
+        );
+    }
+}
+
+#[test]
+fn test_boost() {
+    for input in [1, 2] {
+        let compiled = compare(&day_9::PROGRAM, BOOST, &[input]);
+        assert!(!compiled.is_interpreting());
+    }
+}
+
+#[test]
+fn test_self_modifying() {
+    // Translated code overwrites the output instruction, so the interpreter runs it as a halt
+    let compiled = compare(&self_modifying::PROGRAM, SELF_MODIFYING, &[]);
+    assert!(compiled.is_interpreting());
+}
+
+#[test]
+fn test_arcade() {
+    compare(&day_13::PROGRAM, ARCADE, &[]);
+
+    // Inserting quarters writes to the first instruction
+    let mut compiled = CompiledProgram::new(&day_13::PROGRAM);
+    let mut interpreted = Program::new(ARCADE);
+    compiled.write(0, 2);
+    interpreted.write(0, 2);
+    assert!(compiled.is_interpreting());
+    compare_with_interpreter(compiled, interpreted, &[-1, 0, 1, 1, 0, -1]);
+}
+
+#[test]
+fn test_errors() {
+    // The BOOST program reports an error when run without input
+    let mut compiled = CompiledProgram::new(&day_9::PROGRAM);
+    let mut interpreted = Program::new(BOOST);
+    assert_eq!(Ok(Event::NeedsInput), compiled.run_until_event());
+    assert_eq!(interpreted.try_run(), compiled.try_run());
+}
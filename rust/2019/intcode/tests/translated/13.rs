@@ -0,0 +1,3186 @@
+// Generated by intcode::translate
+
+use intcode::{
+    translate::{Exit, Translation, Vm},
+    Address,
+};
+
+#[allow(clippy::single_range_in_vec_init)]
+pub static PROGRAM: Translation = Translation {
+    memory: &[1, 380, 379, 385, 1008, 2655, 725232, 381, 1005, 381, 12, 99, 109, 2656, 1101, 0, 0, 383, 1102, 1, 0, 382, 21002, 382, 1, 1, 21001, 383, 0, 2, 21101, 37, 0, 0, 1106, 0, 578, 4, 382, 4, 383, 204, 1, 1001, 382, 1, 382, 1007, 382, 42, 381, 1005, 381, 22, 1001, 383, 1, 383, 1007, 383, 24, 381, 1005, 381, 18, 1006, 385, 69, 99, 104, -1, 104, 0, 4, 386, 3, 384, 1007, 384, 0, 381, 1005, 381, 94, 107, 0, 384, 381, 1005, 381, 108, 1105, 1, 161, 107, 1, 392, 381, 1006, 381, 161, 1102, -1, 1, 384, 1106, 0, 119, 1007, 392, 40, 381, 1006, 381, 161, 1102, 1, 1, 384, 21002, 392, 1, 1, 21101, 22, 0, 2, 21101, 0, 0, 3, 21101, 138, 0, 0, 1106, 0, 549, 1, 392, 384, 392, 20102, 1, 392, 1, 21102, 22, 1, 2, 21102, 1, 3, 3, 21102, 161, 1, 0, 1105, 1, 549, 1101, 0, 0, 384, 20001, 388, 390, 1, 21001, 389, 0, 2, 21101, 0, 180, 0, 1106, 0, 578, 1206, 1, 213, 1208, 1, 2, 381, 1006, 381, 205, 20001, 388, 390, 1, 21002, 389, 1, 2, 21101, 0, 205, 0, 1105, 1, 393, 1002, 390, -1, 390, 1101, 1, 0, 384, 20101, 0, 388, 1, 20001, 389, 391, 2, 21101, 228, 0, 0, 1106, 0, 578, 1206, 1, 261, 1208, 1, 2, 381, 1006, 381, 253, 21002, 388, 1, 1, 20001, 389, 391, 2, 21101, 253, 0, 0, 1105, 1, 393, 1002, 391, -1, 391, 1102, 1, 1, 384, 1005, 384, 161, 20001, 388, 390, 1, 20001, 389, 391, 2, 21102, 279, 1, 0, 1106, 0, 578, 1206, 1, 316, 1208, 1, 2, 381, 1006, 381, 304, 20001, 388, 390, 1, 20001, 389, 391, 2, 21102, 304, 1, 0, 1105, 1, 393, 1002, 390, -1, 390, 1002, 391, -1, 391, 1102, 1, 1, 384, 1005, 384, 161, 21002, 388, 1, 1, 21002, 389, 1, 2, 21102, 0, 1, 3, 21101, 338, 0, 0, 1106, 0, 549, 1, 388, 390, 388, 1, 389, 391, 389, 20101, 0, 388, 1, 21001, 389, 0, 2, 21102, 1, 4, 3, 21101, 0, 365, 0, 1105, 1, 549, 1007, 389, 23, 381, 1005, 381, 75, 104, -1, 104, 0, 104, 0, 99, 0, 1, 0, 0, 0, 0, 0, 0, 312, 19, 19, 1, 1, 21, 109, 3, 22101, 0, -2, 1, 22102, 1, -1, 2, 21102, 0, 1, 3, 21101, 0, 414, 0, 1106, 0, 549, 22101, 0, -2, 1, 22101, 0, -1, 2, 21102, 1, 429, 0, 1106, 0, 601, 2101, 0, 1, 435, 1, 386, 0, 386, 104, -1, 104, 0, 4, 386, 1001, 387, -1, 387, 1005, 387, 451, 99, 109, -3, 2106, 0, 0, 109, 8, 22202, -7, -6, -3, 22201, -3, -5, -3, 21202, -4, 64, -2, 2207, -3, -2, 381, 1005, 381, 492, 21202, -2, -1, -1, 22201, -3, -1, -3, 2207, -3, -2, 381, 1006, 381, 481, 21202, -4, 8, -2, 2207, -3, -2, 381, 1005, 381, 518, 21202, -2, -1, -1, 22201, -3, -1, -3, 2207, -3, -2, 381, 1006, 381, 507, 2207, -3, -4, 381, 1005, 381, 540, 21202, -4, -1, -1, 22201, -3, -1, -3, 2207, -3, -4, 381, 1006, 381, 529, 21202, -3, 1, -7, 109, -8, 2105, 1, 0, 109, 4, 1202, -2, 42, 566, 201, -3, 566, 566, 101, 639, 566, 566, 2101, 0, -1, 0, 204, -3, 204, -2, 204, -1, 109, -4, 2106, 0, 0, 109, 3, 1202, -1, 42, 594, 201, -2, 594, 594, 101, 639, 594, 594, 20102, 1, 0, -2, 109, -3, 2105, 1, 0, 109, 3, 22102, 24, -2, 1, 22201, 1, -1, 1, 21102, 509, 1, 2, 21101, 480, 0, 3, 21102, 1008, 1, 4, 21102, 1, 630, 0, 1105, 1, 456, 21201, 1, 1647, -2, 109, -3, 2106, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 2, 2, 2, 0, 2, 2, 2, 2, 0, 0, 2, 2, 2, 0, 0, 2, 2, 0, 2, 2, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 2, 2, 0, 2, 0, 0, 2, 0, 0, 2, 2, 0, 2, 2, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 2, 2, 2, 2, 0, 2, 0, 2, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 2, 2, 0, 2, 2, 0, 2, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 2, 0, 0, 2, 2, 2, 0, 0, 2, 2, 0, 0, 1, 1, 0, 2, 2, 0, 2, 2, 2, 0, 2, 2, 0, 2, 0, 2, 0, 2, 2, 2, 2, 0, 0, 2, 0, 2, 2, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 0, 0, 2, 2, 0, 1, 1, 0, 0, 2, 2, 0, 2, 2, 0, 0, 2, 2, 0, 0, 2, 2, 0, 0, 2, 2, 2, 0, 0, 2, 0, 2, 0, 0, 2, 0, 2, 2, 2, 2, 2, 2, 0, 2, 0, 0, 0, 1, 1, 0, 0, 0, 2, 2, 0, 0, 2, 0, 2, 0, 2, 2, 2, 2, 2, 2, 2, 0, 2, 2, 2, 0, 0, 0, 0, 2, 0, 2, 0, 2, 0, 2, 0, 2, 0, 2, 2, 0, 0, 1, 1, 0, 0, 2, 2, 2, 2, 2, 2, 2, 0, 2, 2, 2, 0, 2, 0, 2, 0, 2, 2, 0, 0, 2, 0, 0, 0, 0, 2, 2, 0, 0, 2, 0, 2, 0, 0, 0, 2, 0, 0, 1, 1, 0, 2, 0, 0, 2, 2, 2, 2, 2, 0, 2, 0, 0, 0, 2, 0, 2, 0, 2, 2, 2, 2, 2, 2, 2, 2, 0, 2, 2, 2, 0, 0, 2, 2, 2, 2, 2, 0, 0, 0, 1, 1, 0, 0, 0, 0, 2, 2, 2, 2, 2, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 2, 2, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0, 2, 2, 2, 2, 0, 0, 2, 2, 2, 2, 2, 2, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 2, 2, 2, 2, 0, 2, 0, 2, 2, 2, 0, 1, 1, 0, 2, 0, 2, 0, 2, 0, 0, 2, 0, 0, 2, 0, 0, 0, 2, 2, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 2, 2, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 2, 2, 2, 2, 2, 2, 0, 2, 0, 0, 2, 0, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0, 2, 2, 2, 2, 0, 0, 2, 2, 2, 2, 0, 2, 2, 2, 2, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 2, 2, 0, 0, 2, 2, 0, 2, 0, 2, 0, 0, 2, 2, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 0, 2, 2, 0, 2, 0, 2, 0, 0, 1, 1, 0, 0, 2, 0, 2, 2, 2, 0, 0, 0, 2, 2, 2, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 2, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 1, 1, 0, 2, 2, 2, 2, 2, 0, 2, 0, 0, 2, 0, 2, 2, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 0, 2, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 2, 0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 90, 66, 5, 79, 27, 87, 67, 84, 18, 77, 20, 97, 79, 59, 38, 12, 54, 16, 15, 83, 77, 22, 98, 1, 84, 89, 72, 20, 64, 38, 74, 10, 19, 81, 77, 18, 18, 79, 60, 64, 49, 19, 65, 73, 57, 65, 57, 22, 12, 50, 6, 28, 49, 28, 83, 2, 85, 53, 8, 59, 96, 16, 64, 65, 38, 32, 27, 75, 93, 46, 16, 8, 43, 50, 83, 18, 49, 28, 18, 79, 16, 82, 86, 12, 53, 5, 80, 52, 23, 66, 48, 37, 81, 12, 93, 12, 84, 1, 32, 35, 93, 19, 90, 65, 40, 52, 44, 71, 34, 52, 54, 59, 17, 34, 69, 77, 35, 97, 89, 38, 60, 53, 16, 77, 20, 65, 66, 61, 39, 26, 98, 41, 58, 96, 20, 70, 13, 9, 21, 3, 64, 68, 70, 84, 45, 43, 15, 60, 82, 25, 91, 93, 68, 71, 20, 91, 38, 38, 74, 63, 62, 95, 5, 73, 12, 52, 32, 14, 89, 64, 37, 85, 80, 39, 50, 27, 55, 39, 69, 19, 83, 77, 26, 31, 60, 23, 70, 51, 35, 80, 70, 39, 28, 40, 84, 32, 67, 44, 49, 83, 42, 53, 89, 54, 31, 85, 7, 94, 69, 65, 80, 2, 21, 70, 76, 19, 77, 53, 3, 82, 1, 56, 62, 30, 2, 72, 22, 43, 7, 65, 54, 52, 66, 55, 53, 26, 85, 13, 79, 34, 97, 9, 61, 41, 24, 30, 71, 12, 55, 17, 22, 25, 7, 32, 72, 72, 21, 25, 22, 79, 23, 36, 71, 13, 70, 29, 55, 3, 29, 18, 91, 85, 30, 10, 56, 84, 90, 66, 45, 63, 13, 74, 40, 43, 46, 3, 74, 94, 10, 12, 11, 43, 17, 46, 50, 79, 88, 2, 11, 1, 72, 92, 35, 5, 83, 49, 48, 97, 22, 64, 14, 69, 45, 10, 51, 95, 36, 70, 54, 49, 10, 85, 91, 27, 87, 73, 52, 69, 47, 82, 21, 63, 50, 11, 65, 23, 4, 35, 14, 23, 79, 81, 93, 35, 81, 95, 88, 70, 73, 13, 44, 84, 8, 18, 50, 78, 48, 62, 34, 2, 32, 89, 89, 18, 63, 90, 8, 78, 71, 73, 91, 52, 21, 8, 44, 94, 75, 20, 20, 94, 85, 45, 69, 36, 41, 62, 29, 42, 11, 42, 88, 8, 88, 7, 64, 55, 33, 65, 65, 82, 71, 97, 73, 58, 27, 63, 93, 70, 92, 93, 48, 69, 20, 66, 57, 75, 63, 96, 81, 46, 18, 60, 57, 79, 74, 35, 93, 33, 20, 86, 76, 82, 94, 3, 62, 26, 47, 36, 71, 54, 32, 43, 74, 59, 13, 78, 36, 30, 44, 11, 1, 60, 25, 70, 83, 13, 43, 14, 24, 21, 16, 94, 82, 67, 74, 27, 32, 50, 33, 88, 76, 89, 31, 50, 73, 70, 94, 80, 24, 85, 40, 5, 48, 50, 18, 40, 98, 81, 74, 89, 8, 28, 42, 11, 1, 89, 10, 51, 32, 94, 91, 67, 65, 75, 20, 59, 73, 42, 27, 43, 46, 62, 84, 9, 42, 79, 17, 33, 65, 66, 45, 98, 86, 56, 46, 12, 78, 66, 24, 74, 85, 7, 43, 84, 83, 39, 79, 46, 81, 46, 6, 97, 36, 54, 4, 40, 32, 74, 31, 15, 62, 32, 72, 36, 86, 87, 65, 82, 54, 6, 71, 14, 29, 82, 72, 55, 72, 72, 86, 26, 30, 58, 1, 26, 36, 9, 23, 7, 8, 96, 84, 33, 53, 18, 72, 93, 46, 17, 72, 87, 25, 65, 96, 67, 42, 77, 83, 23, 85, 86, 71, 46, 82, 45, 41, 66, 77, 4, 21, 37, 18, 82, 34, 87, 8, 69, 86, 90, 31, 55, 79, 18, 75, 8, 29, 43, 60, 26, 78, 40, 4, 91, 1, 54, 29, 62, 76, 80, 98, 23, 5, 63, 28, 17, 29, 85, 7, 87, 88, 76, 45, 90, 22, 41, 74, 17, 34, 66, 47, 75, 12, 97, 84, 73, 3, 78, 67, 38, 4, 49, 92, 94, 96, 62, 40, 5, 93, 58, 49, 29, 43, 9, 30, 41, 63, 52, 2, 77, 10, 21, 27, 82, 39, 65, 8, 73, 10, 33, 86, 31, 38, 71, 20, 2, 1, 70, 27, 70, 3, 45, 30, 12, 41, 49, 62, 27, 3, 65, 8, 15, 36, 14, 20, 5, 68, 61, 24, 61, 63, 57, 68, 53, 64, 80, 86, 22, 94, 83, 20, 14, 88, 22, 12, 40, 38, 71, 67, 73, 17, 96, 10, 48, 97, 1, 98, 61, 95, 16, 74, 93, 64, 56, 84, 25, 48, 70, 93, 50, 79, 86, 33, 14, 38, 58, 21, 15, 96, 46, 82, 51, 78, 39, 29, 63, 49, 17, 80, 92, 42, 59, 41, 86, 58, 30, 34, 85, 89, 24, 75, 70, 21, 39, 29, 80, 91, 13, 16, 78, 12, 47, 58, 33, 69, 58, 87, 44, 27, 70, 31, 45, 52, 64, 11, 20, 56, 94, 46, 17, 17, 68, 24, 55, 74, 7, 50, 51, 46, 16, 74, 88, 39, 51, 54, 82, 7, 42, 28, 97, 96, 64, 42, 59, 43, 12, 53, 15, 8, 36, 18, 48, 97, 83, 4, 8, 55, 78, 46, 16, 22, 42, 8, 72, 94, 94, 24, 74, 84, 53, 75, 97, 13, 64, 6, 31, 9, 20, 4, 38, 56, 2, 45, 14, 62, 8, 86, 42, 27, 54, 73, 87, 97, 62, 4, 52, 27, 98, 23, 11, 52, 66, 75, 22, 88, 8, 19, 1, 16, 64, 47, 98, 94, 30, 54, 17, 61, 5, 92, 53, 83, 17, 11, 93, 91, 75, 2, 17, 30, 23, 31, 72, 25, 10, 13, 57, 45, 59, 48, 52, 35, 4, 8, 20, 98, 18, 53, 92, 95, 21, 84, 22, 68, 90, 33, 72, 5, 32, 95, 64, 28, 53, 7, 96, 39, 80, 15, 43, 80, 91, 53, 26, 28, 71, 5, 19, 96, 19, 17, 5, 32, 85, 76, 5, 8, 75, 24, 92, 6, 74, 51, 19, 20, 36, 84, 65, 63, 43, 36, 65, 725232],
+    code: &[0..379, 393..683],
+    run,
+};
+
+fn run(vm: &mut Vm, mut ip: Address) -> Exit {
+    loop {
+        let next = match ip {
+            0 => block_0(vm),
+            11 => block_11(vm),
+            12 => block_12(vm),
+            18 => block_18(vm),
+            22 => block_22(vm),
+            37 => block_37(vm),
+            39 => block_39(vm),
+            41 => block_41(vm),
+            43 => block_43(vm),
+            54 => block_54(vm),
+            65 => block_65(vm),
+            68 => block_68(vm),
+            69 => block_69(vm),
+            71 => block_71(vm),
+            73 => block_73(vm),
+            75 => block_75(vm),
+            84 => block_84(vm),
+            91 => block_91(vm),
+            94 => block_94(vm),
+            101 => block_101(vm),
+            108 => block_108(vm),
+            115 => block_115(vm),
+            119 => block_119(vm),
+            138 => block_138(vm),
+            161 => block_161(vm),
+            180 => block_180(vm),
+            183 => block_183(vm),
+            190 => block_190(vm),
+            205 => block_205(vm),
+            213 => block_213(vm),
+            228 => block_228(vm),
+            231 => block_231(vm),
+            238 => block_238(vm),
+            253 => block_253(vm),
+            261 => block_261(vm),
+            264 => block_264(vm),
+            279 => block_279(vm),
+            282 => block_282(vm),
+            289 => block_289(vm),
+            304 => block_304(vm),
+            316 => block_316(vm),
+            319 => block_319(vm),
+            338 => block_338(vm),
+            365 => block_365(vm),
+            372 => block_372(vm),
+            374 => block_374(vm),
+            376 => block_376(vm),
+            378 => block_378(vm),
+            393 => block_393(vm),
+            414 => block_414(vm),
+            429 => block_429(vm),
+            439 => block_439(vm),
+            441 => block_441(vm),
+            443 => block_443(vm),
+            450 => block_450(vm),
+            451 => block_451(vm),
+            456 => block_456(vm),
+            477 => block_477(vm),
+            481 => block_481(vm),
+            492 => block_492(vm),
+            503 => block_503(vm),
+            507 => block_507(vm),
+            518 => block_518(vm),
+            525 => block_525(vm),
+            529 => block_529(vm),
+            540 => block_540(vm),
+            549 => block_549(vm),
+            569 => block_569(vm),
+            571 => block_571(vm),
+            573 => block_573(vm),
+            578 => block_578(vm),
+            601 => block_601(vm),
+            630 => block_630(vm),
+            639 => block_639(vm),
+            _ => return Exit::Interpret(ip),
+        };
+        match next {
+            Ok(next) => ip = next,
+            Err(exit) => return exit,
+        }
+    }
+}
+
+fn block_0(vm: &mut Vm) -> Result<Address, Exit> {
+    // 0: add  [380], [379], [385]
+    let a = vm.read(380);
+    let b = vm.read(379);
+    let address = 385;
+    vm.write(address, vm.add(a, b, 0)?, 4)?;
+    // 4: eq   [2655], #725232, [381]
+    let a = vm.read(2655);
+    let b = 725232;
+    let address = 381;
+    vm.write(address, (a == b) as intcode::Value, 8)?;
+    // 8: jt   [381], #12
+    if vm.read(381) != 0 {
+        return Ok(12);
+    }
+    // 11: hlt
+    Err(Exit::Interpret(11))
+}
+
+fn block_11(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 11: hlt
+    Err(Exit::Interpret(11))
+}
+
+fn block_12(vm: &mut Vm) -> Result<Address, Exit> {
+    // 12: arb  #2656
+    vm.adjust_relative_base(2656, 12)?;
+    // 14: add  #0, #0, [383]
+    let a = 0;
+    let b = 0;
+    let address = 383;
+    vm.write(address, vm.add(a, b, 14)?, 18)?;
+    // 18: mul  #1, #0, [382]
+    let a = 1;
+    let b = 0;
+    let address = 382;
+    vm.write(address, vm.multiply(a, b, 18)?, 22)?;
+    // 22: mul  [382], #1, rb+1
+    let a = vm.read(382);
+    let b = 1;
+    let address = vm.relative_address(1, 22)?;
+    vm.write(address, vm.multiply(a, b, 22)?, 26)?;
+    // 26: add  [383], #0, rb+2
+    let a = vm.read(383);
+    let b = 0;
+    let address = vm.relative_address(2, 26)?;
+    vm.write(address, vm.add(a, b, 26)?, 30)?;
+    // 30: add  #37, #0, rb+0
+    let a = 37;
+    let b = 0;
+    let address = vm.relative_address(0, 30)?;
+    vm.write(address, vm.add(a, b, 30)?, 34)?;
+    // 34: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_18(vm: &mut Vm) -> Result<Address, Exit> {
+    // 18: mul  #1, #0, [382]
+    let a = 1;
+    let b = 0;
+    let address = 382;
+    vm.write(address, vm.multiply(a, b, 18)?, 22)?;
+    // 22: mul  [382], #1, rb+1
+    let a = vm.read(382);
+    let b = 1;
+    let address = vm.relative_address(1, 22)?;
+    vm.write(address, vm.multiply(a, b, 22)?, 26)?;
+    // 26: add  [383], #0, rb+2
+    let a = vm.read(383);
+    let b = 0;
+    let address = vm.relative_address(2, 26)?;
+    vm.write(address, vm.add(a, b, 26)?, 30)?;
+    // 30: add  #37, #0, rb+0
+    let a = 37;
+    let b = 0;
+    let address = vm.relative_address(0, 30)?;
+    vm.write(address, vm.add(a, b, 30)?, 34)?;
+    // 34: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_22(vm: &mut Vm) -> Result<Address, Exit> {
+    // 22: mul  [382], #1, rb+1
+    let a = vm.read(382);
+    let b = 1;
+    let address = vm.relative_address(1, 22)?;
+    vm.write(address, vm.multiply(a, b, 22)?, 26)?;
+    // 26: add  [383], #0, rb+2
+    let a = vm.read(383);
+    let b = 0;
+    let address = vm.relative_address(2, 26)?;
+    vm.write(address, vm.add(a, b, 26)?, 30)?;
+    // 30: add  #37, #0, rb+0
+    let a = 37;
+    let b = 0;
+    let address = vm.relative_address(0, 30)?;
+    vm.write(address, vm.add(a, b, 30)?, 34)?;
+    // 34: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_37(vm: &mut Vm) -> Result<Address, Exit> {
+    // 37: out  [382]
+    Err(Exit::Output(vm.read(382), 39))
+}
+
+fn block_39(vm: &mut Vm) -> Result<Address, Exit> {
+    // 39: out  [383]
+    Err(Exit::Output(vm.read(383), 41))
+}
+
+fn block_41(vm: &mut Vm) -> Result<Address, Exit> {
+    // 41: out  rb+1
+    Err(Exit::Output(vm.read_relative(1, 41)?, 43))
+}
+
+fn block_43(vm: &mut Vm) -> Result<Address, Exit> {
+    // 43: add  [382], #1, [382]
+    let a = vm.read(382);
+    let b = 1;
+    let address = 382;
+    vm.write(address, vm.add(a, b, 43)?, 47)?;
+    // 47: lt   [382], #42, [381]
+    let a = vm.read(382);
+    let b = 42;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 51)?;
+    // 51: jt   [381], #22
+    if vm.read(381) != 0 {
+        return Ok(22);
+    }
+    // 54: add  [383], #1, [383]
+    let a = vm.read(383);
+    let b = 1;
+    let address = 383;
+    vm.write(address, vm.add(a, b, 54)?, 58)?;
+    // 58: lt   [383], #24, [381]
+    let a = vm.read(383);
+    let b = 24;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 62)?;
+    // 62: jt   [381], #18
+    if vm.read(381) != 0 {
+        return Ok(18);
+    }
+    // 65: jf   [385], #69
+    if vm.read(385) == 0 {
+        return Ok(69);
+    }
+    // 68: hlt
+    Err(Exit::Interpret(68))
+}
+
+fn block_54(vm: &mut Vm) -> Result<Address, Exit> {
+    // 54: add  [383], #1, [383]
+    let a = vm.read(383);
+    let b = 1;
+    let address = 383;
+    vm.write(address, vm.add(a, b, 54)?, 58)?;
+    // 58: lt   [383], #24, [381]
+    let a = vm.read(383);
+    let b = 24;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 62)?;
+    // 62: jt   [381], #18
+    if vm.read(381) != 0 {
+        return Ok(18);
+    }
+    // 65: jf   [385], #69
+    if vm.read(385) == 0 {
+        return Ok(69);
+    }
+    // 68: hlt
+    Err(Exit::Interpret(68))
+}
+
+fn block_65(vm: &mut Vm) -> Result<Address, Exit> {
+    // 65: jf   [385], #69
+    if vm.read(385) == 0 {
+        return Ok(69);
+    }
+    // 68: hlt
+    Err(Exit::Interpret(68))
+}
+
+fn block_68(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 68: hlt
+    Err(Exit::Interpret(68))
+}
+
+fn block_69(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 69: out  #-1
+    Err(Exit::Output(-1, 71))
+}
+
+fn block_71(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 71: out  #0
+    Err(Exit::Output(0, 73))
+}
+
+fn block_73(vm: &mut Vm) -> Result<Address, Exit> {
+    // 73: out  [386]
+    Err(Exit::Output(vm.read(386), 75))
+}
+
+fn block_75(vm: &mut Vm) -> Result<Address, Exit> {
+    // 75: in   [384]
+    let address = 384;
+    let value = vm.input(75)?;
+    vm.write(address, value, 77)?;
+    // 77: lt   [384], #0, [381]
+    let a = vm.read(384);
+    let b = 0;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 81)?;
+    // 81: jt   [381], #94
+    if vm.read(381) != 0 {
+        return Ok(94);
+    }
+    // 84: lt   #0, [384], [381]
+    let a = 0;
+    let b = vm.read(384);
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 88)?;
+    // 88: jt   [381], #108
+    if vm.read(381) != 0 {
+        return Ok(108);
+    }
+    // 91: jt   #1, #161
+    // 161: add  #0, #0, [384]
+    let a = 0;
+    let b = 0;
+    let address = 384;
+    vm.write(address, vm.add(a, b, 161)?, 165)?;
+    // 165: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 165)?;
+    vm.write(address, vm.add(a, b, 165)?, 169)?;
+    // 169: add  [389], #0, rb+2
+    let a = vm.read(389);
+    let b = 0;
+    let address = vm.relative_address(2, 169)?;
+    vm.write(address, vm.add(a, b, 169)?, 173)?;
+    // 173: add  #0, #180, rb+0
+    let a = 0;
+    let b = 180;
+    let address = vm.relative_address(0, 173)?;
+    vm.write(address, vm.add(a, b, 173)?, 177)?;
+    // 177: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_84(vm: &mut Vm) -> Result<Address, Exit> {
+    // 84: lt   #0, [384], [381]
+    let a = 0;
+    let b = vm.read(384);
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 88)?;
+    // 88: jt   [381], #108
+    if vm.read(381) != 0 {
+        return Ok(108);
+    }
+    // 91: jt   #1, #161
+    // 161: add  #0, #0, [384]
+    let a = 0;
+    let b = 0;
+    let address = 384;
+    vm.write(address, vm.add(a, b, 161)?, 165)?;
+    // 165: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 165)?;
+    vm.write(address, vm.add(a, b, 165)?, 169)?;
+    // 169: add  [389], #0, rb+2
+    let a = vm.read(389);
+    let b = 0;
+    let address = vm.relative_address(2, 169)?;
+    vm.write(address, vm.add(a, b, 169)?, 173)?;
+    // 173: add  #0, #180, rb+0
+    let a = 0;
+    let b = 180;
+    let address = vm.relative_address(0, 173)?;
+    vm.write(address, vm.add(a, b, 173)?, 177)?;
+    // 177: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_91(vm: &mut Vm) -> Result<Address, Exit> {
+    // 91: jt   #1, #161
+    // 161: add  #0, #0, [384]
+    let a = 0;
+    let b = 0;
+    let address = 384;
+    vm.write(address, vm.add(a, b, 161)?, 165)?;
+    // 165: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 165)?;
+    vm.write(address, vm.add(a, b, 165)?, 169)?;
+    // 169: add  [389], #0, rb+2
+    let a = vm.read(389);
+    let b = 0;
+    let address = vm.relative_address(2, 169)?;
+    vm.write(address, vm.add(a, b, 169)?, 173)?;
+    // 173: add  #0, #180, rb+0
+    let a = 0;
+    let b = 180;
+    let address = vm.relative_address(0, 173)?;
+    vm.write(address, vm.add(a, b, 173)?, 177)?;
+    // 177: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_94(vm: &mut Vm) -> Result<Address, Exit> {
+    // 94: lt   #1, [392], [381]
+    let a = 1;
+    let b = vm.read(392);
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 98)?;
+    // 98: jf   [381], #161
+    if vm.read(381) == 0 {
+        return Ok(161);
+    }
+    // 101: mul  #-1, #1, [384]
+    let a = -1;
+    let b = 1;
+    let address = 384;
+    vm.write(address, vm.multiply(a, b, 101)?, 105)?;
+    // 105: jf   #0, #119
+    // 119: mul  [392], #1, rb+1
+    let a = vm.read(392);
+    let b = 1;
+    let address = vm.relative_address(1, 119)?;
+    vm.write(address, vm.multiply(a, b, 119)?, 123)?;
+    // 123: add  #22, #0, rb+2
+    let a = 22;
+    let b = 0;
+    let address = vm.relative_address(2, 123)?;
+    vm.write(address, vm.add(a, b, 123)?, 127)?;
+    // 127: add  #0, #0, rb+3
+    let a = 0;
+    let b = 0;
+    let address = vm.relative_address(3, 127)?;
+    vm.write(address, vm.add(a, b, 127)?, 131)?;
+    // 131: add  #138, #0, rb+0
+    let a = 138;
+    let b = 0;
+    let address = vm.relative_address(0, 131)?;
+    vm.write(address, vm.add(a, b, 131)?, 135)?;
+    // 135: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_101(vm: &mut Vm) -> Result<Address, Exit> {
+    // 101: mul  #-1, #1, [384]
+    let a = -1;
+    let b = 1;
+    let address = 384;
+    vm.write(address, vm.multiply(a, b, 101)?, 105)?;
+    // 105: jf   #0, #119
+    // 119: mul  [392], #1, rb+1
+    let a = vm.read(392);
+    let b = 1;
+    let address = vm.relative_address(1, 119)?;
+    vm.write(address, vm.multiply(a, b, 119)?, 123)?;
+    // 123: add  #22, #0, rb+2
+    let a = 22;
+    let b = 0;
+    let address = vm.relative_address(2, 123)?;
+    vm.write(address, vm.add(a, b, 123)?, 127)?;
+    // 127: add  #0, #0, rb+3
+    let a = 0;
+    let b = 0;
+    let address = vm.relative_address(3, 127)?;
+    vm.write(address, vm.add(a, b, 127)?, 131)?;
+    // 131: add  #138, #0, rb+0
+    let a = 138;
+    let b = 0;
+    let address = vm.relative_address(0, 131)?;
+    vm.write(address, vm.add(a, b, 131)?, 135)?;
+    // 135: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_108(vm: &mut Vm) -> Result<Address, Exit> {
+    // 108: lt   [392], #40, [381]
+    let a = vm.read(392);
+    let b = 40;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 112)?;
+    // 112: jf   [381], #161
+    if vm.read(381) == 0 {
+        return Ok(161);
+    }
+    // 115: mul  #1, #1, [384]
+    let a = 1;
+    let b = 1;
+    let address = 384;
+    vm.write(address, vm.multiply(a, b, 115)?, 119)?;
+    // 119: mul  [392], #1, rb+1
+    let a = vm.read(392);
+    let b = 1;
+    let address = vm.relative_address(1, 119)?;
+    vm.write(address, vm.multiply(a, b, 119)?, 123)?;
+    // 123: add  #22, #0, rb+2
+    let a = 22;
+    let b = 0;
+    let address = vm.relative_address(2, 123)?;
+    vm.write(address, vm.add(a, b, 123)?, 127)?;
+    // 127: add  #0, #0, rb+3
+    let a = 0;
+    let b = 0;
+    let address = vm.relative_address(3, 127)?;
+    vm.write(address, vm.add(a, b, 127)?, 131)?;
+    // 131: add  #138, #0, rb+0
+    let a = 138;
+    let b = 0;
+    let address = vm.relative_address(0, 131)?;
+    vm.write(address, vm.add(a, b, 131)?, 135)?;
+    // 135: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_115(vm: &mut Vm) -> Result<Address, Exit> {
+    // 115: mul  #1, #1, [384]
+    let a = 1;
+    let b = 1;
+    let address = 384;
+    vm.write(address, vm.multiply(a, b, 115)?, 119)?;
+    // 119: mul  [392], #1, rb+1
+    let a = vm.read(392);
+    let b = 1;
+    let address = vm.relative_address(1, 119)?;
+    vm.write(address, vm.multiply(a, b, 119)?, 123)?;
+    // 123: add  #22, #0, rb+2
+    let a = 22;
+    let b = 0;
+    let address = vm.relative_address(2, 123)?;
+    vm.write(address, vm.add(a, b, 123)?, 127)?;
+    // 127: add  #0, #0, rb+3
+    let a = 0;
+    let b = 0;
+    let address = vm.relative_address(3, 127)?;
+    vm.write(address, vm.add(a, b, 127)?, 131)?;
+    // 131: add  #138, #0, rb+0
+    let a = 138;
+    let b = 0;
+    let address = vm.relative_address(0, 131)?;
+    vm.write(address, vm.add(a, b, 131)?, 135)?;
+    // 135: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_119(vm: &mut Vm) -> Result<Address, Exit> {
+    // 119: mul  [392], #1, rb+1
+    let a = vm.read(392);
+    let b = 1;
+    let address = vm.relative_address(1, 119)?;
+    vm.write(address, vm.multiply(a, b, 119)?, 123)?;
+    // 123: add  #22, #0, rb+2
+    let a = 22;
+    let b = 0;
+    let address = vm.relative_address(2, 123)?;
+    vm.write(address, vm.add(a, b, 123)?, 127)?;
+    // 127: add  #0, #0, rb+3
+    let a = 0;
+    let b = 0;
+    let address = vm.relative_address(3, 127)?;
+    vm.write(address, vm.add(a, b, 127)?, 131)?;
+    // 131: add  #138, #0, rb+0
+    let a = 138;
+    let b = 0;
+    let address = vm.relative_address(0, 131)?;
+    vm.write(address, vm.add(a, b, 131)?, 135)?;
+    // 135: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_138(vm: &mut Vm) -> Result<Address, Exit> {
+    // 138: add  [392], [384], [392]
+    let a = vm.read(392);
+    let b = vm.read(384);
+    let address = 392;
+    vm.write(address, vm.add(a, b, 138)?, 142)?;
+    // 142: mul  #1, [392], rb+1
+    let a = 1;
+    let b = vm.read(392);
+    let address = vm.relative_address(1, 142)?;
+    vm.write(address, vm.multiply(a, b, 142)?, 146)?;
+    // 146: mul  #22, #1, rb+2
+    let a = 22;
+    let b = 1;
+    let address = vm.relative_address(2, 146)?;
+    vm.write(address, vm.multiply(a, b, 146)?, 150)?;
+    // 150: mul  #1, #3, rb+3
+    let a = 1;
+    let b = 3;
+    let address = vm.relative_address(3, 150)?;
+    vm.write(address, vm.multiply(a, b, 150)?, 154)?;
+    // 154: mul  #161, #1, rb+0
+    let a = 161;
+    let b = 1;
+    let address = vm.relative_address(0, 154)?;
+    vm.write(address, vm.multiply(a, b, 154)?, 158)?;
+    // 158: jt   #1, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_161(vm: &mut Vm) -> Result<Address, Exit> {
+    // 161: add  #0, #0, [384]
+    let a = 0;
+    let b = 0;
+    let address = 384;
+    vm.write(address, vm.add(a, b, 161)?, 165)?;
+    // 165: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 165)?;
+    vm.write(address, vm.add(a, b, 165)?, 169)?;
+    // 169: add  [389], #0, rb+2
+    let a = vm.read(389);
+    let b = 0;
+    let address = vm.relative_address(2, 169)?;
+    vm.write(address, vm.add(a, b, 169)?, 173)?;
+    // 173: add  #0, #180, rb+0
+    let a = 0;
+    let b = 180;
+    let address = vm.relative_address(0, 173)?;
+    vm.write(address, vm.add(a, b, 173)?, 177)?;
+    // 177: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_180(vm: &mut Vm) -> Result<Address, Exit> {
+    // 180: jf   rb+1, #213
+    if vm.read_relative(1, 180)? == 0 {
+        return Ok(213);
+    }
+    // 183: eq   rb+1, #2, [381]
+    let a = vm.read_relative(1, 183)?;
+    let b = 2;
+    let address = 381;
+    vm.write(address, (a == b) as intcode::Value, 187)?;
+    // 187: jf   [381], #205
+    if vm.read(381) == 0 {
+        return Ok(205);
+    }
+    // 190: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 190)?;
+    vm.write(address, vm.add(a, b, 190)?, 194)?;
+    // 194: mul  [389], #1, rb+2
+    let a = vm.read(389);
+    let b = 1;
+    let address = vm.relative_address(2, 194)?;
+    vm.write(address, vm.multiply(a, b, 194)?, 198)?;
+    // 198: add  #0, #205, rb+0
+    let a = 0;
+    let b = 205;
+    let address = vm.relative_address(0, 198)?;
+    vm.write(address, vm.add(a, b, 198)?, 202)?;
+    // 202: jt   #1, #393
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_183(vm: &mut Vm) -> Result<Address, Exit> {
+    // 183: eq   rb+1, #2, [381]
+    let a = vm.read_relative(1, 183)?;
+    let b = 2;
+    let address = 381;
+    vm.write(address, (a == b) as intcode::Value, 187)?;
+    // 187: jf   [381], #205
+    if vm.read(381) == 0 {
+        return Ok(205);
+    }
+    // 190: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 190)?;
+    vm.write(address, vm.add(a, b, 190)?, 194)?;
+    // 194: mul  [389], #1, rb+2
+    let a = vm.read(389);
+    let b = 1;
+    let address = vm.relative_address(2, 194)?;
+    vm.write(address, vm.multiply(a, b, 194)?, 198)?;
+    // 198: add  #0, #205, rb+0
+    let a = 0;
+    let b = 205;
+    let address = vm.relative_address(0, 198)?;
+    vm.write(address, vm.add(a, b, 198)?, 202)?;
+    // 202: jt   #1, #393
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_190(vm: &mut Vm) -> Result<Address, Exit> {
+    // 190: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 190)?;
+    vm.write(address, vm.add(a, b, 190)?, 194)?;
+    // 194: mul  [389], #1, rb+2
+    let a = vm.read(389);
+    let b = 1;
+    let address = vm.relative_address(2, 194)?;
+    vm.write(address, vm.multiply(a, b, 194)?, 198)?;
+    // 198: add  #0, #205, rb+0
+    let a = 0;
+    let b = 205;
+    let address = vm.relative_address(0, 198)?;
+    vm.write(address, vm.add(a, b, 198)?, 202)?;
+    // 202: jt   #1, #393
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_205(vm: &mut Vm) -> Result<Address, Exit> {
+    // 205: mul  [390], #-1, [390]
+    let a = vm.read(390);
+    let b = -1;
+    let address = 390;
+    vm.write(address, vm.multiply(a, b, 205)?, 209)?;
+    // 209: add  #1, #0, [384]
+    let a = 1;
+    let b = 0;
+    let address = 384;
+    vm.write(address, vm.add(a, b, 209)?, 213)?;
+    // 213: add  #0, [388], rb+1
+    let a = 0;
+    let b = vm.read(388);
+    let address = vm.relative_address(1, 213)?;
+    vm.write(address, vm.add(a, b, 213)?, 217)?;
+    // 217: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 217)?;
+    vm.write(address, vm.add(a, b, 217)?, 221)?;
+    // 221: add  #228, #0, rb+0
+    let a = 228;
+    let b = 0;
+    let address = vm.relative_address(0, 221)?;
+    vm.write(address, vm.add(a, b, 221)?, 225)?;
+    // 225: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_213(vm: &mut Vm) -> Result<Address, Exit> {
+    // 213: add  #0, [388], rb+1
+    let a = 0;
+    let b = vm.read(388);
+    let address = vm.relative_address(1, 213)?;
+    vm.write(address, vm.add(a, b, 213)?, 217)?;
+    // 217: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 217)?;
+    vm.write(address, vm.add(a, b, 217)?, 221)?;
+    // 221: add  #228, #0, rb+0
+    let a = 228;
+    let b = 0;
+    let address = vm.relative_address(0, 221)?;
+    vm.write(address, vm.add(a, b, 221)?, 225)?;
+    // 225: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_228(vm: &mut Vm) -> Result<Address, Exit> {
+    // 228: jf   rb+1, #261
+    if vm.read_relative(1, 228)? == 0 {
+        return Ok(261);
+    }
+    // 231: eq   rb+1, #2, [381]
+    let a = vm.read_relative(1, 231)?;
+    let b = 2;
+    let address = 381;
+    vm.write(address, (a == b) as intcode::Value, 235)?;
+    // 235: jf   [381], #253
+    if vm.read(381) == 0 {
+        return Ok(253);
+    }
+    // 238: mul  [388], #1, rb+1
+    let a = vm.read(388);
+    let b = 1;
+    let address = vm.relative_address(1, 238)?;
+    vm.write(address, vm.multiply(a, b, 238)?, 242)?;
+    // 242: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 242)?;
+    vm.write(address, vm.add(a, b, 242)?, 246)?;
+    // 246: add  #253, #0, rb+0
+    let a = 253;
+    let b = 0;
+    let address = vm.relative_address(0, 246)?;
+    vm.write(address, vm.add(a, b, 246)?, 250)?;
+    // 250: jt   #1, #393
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_231(vm: &mut Vm) -> Result<Address, Exit> {
+    // 231: eq   rb+1, #2, [381]
+    let a = vm.read_relative(1, 231)?;
+    let b = 2;
+    let address = 381;
+    vm.write(address, (a == b) as intcode::Value, 235)?;
+    // 235: jf   [381], #253
+    if vm.read(381) == 0 {
+        return Ok(253);
+    }
+    // 238: mul  [388], #1, rb+1
+    let a = vm.read(388);
+    let b = 1;
+    let address = vm.relative_address(1, 238)?;
+    vm.write(address, vm.multiply(a, b, 238)?, 242)?;
+    // 242: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 242)?;
+    vm.write(address, vm.add(a, b, 242)?, 246)?;
+    // 246: add  #253, #0, rb+0
+    let a = 253;
+    let b = 0;
+    let address = vm.relative_address(0, 246)?;
+    vm.write(address, vm.add(a, b, 246)?, 250)?;
+    // 250: jt   #1, #393
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_238(vm: &mut Vm) -> Result<Address, Exit> {
+    // 238: mul  [388], #1, rb+1
+    let a = vm.read(388);
+    let b = 1;
+    let address = vm.relative_address(1, 238)?;
+    vm.write(address, vm.multiply(a, b, 238)?, 242)?;
+    // 242: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 242)?;
+    vm.write(address, vm.add(a, b, 242)?, 246)?;
+    // 246: add  #253, #0, rb+0
+    let a = 253;
+    let b = 0;
+    let address = vm.relative_address(0, 246)?;
+    vm.write(address, vm.add(a, b, 246)?, 250)?;
+    // 250: jt   #1, #393
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_253(vm: &mut Vm) -> Result<Address, Exit> {
+    // 253: mul  [391], #-1, [391]
+    let a = vm.read(391);
+    let b = -1;
+    let address = 391;
+    vm.write(address, vm.multiply(a, b, 253)?, 257)?;
+    // 257: mul  #1, #1, [384]
+    let a = 1;
+    let b = 1;
+    let address = 384;
+    vm.write(address, vm.multiply(a, b, 257)?, 261)?;
+    // 261: jt   [384], #161
+    if vm.read(384) != 0 {
+        return Ok(161);
+    }
+    // 264: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 264)?;
+    vm.write(address, vm.add(a, b, 264)?, 268)?;
+    // 268: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 268)?;
+    vm.write(address, vm.add(a, b, 268)?, 272)?;
+    // 272: mul  #279, #1, rb+0
+    let a = 279;
+    let b = 1;
+    let address = vm.relative_address(0, 272)?;
+    vm.write(address, vm.multiply(a, b, 272)?, 276)?;
+    // 276: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_261(vm: &mut Vm) -> Result<Address, Exit> {
+    // 261: jt   [384], #161
+    if vm.read(384) != 0 {
+        return Ok(161);
+    }
+    // 264: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 264)?;
+    vm.write(address, vm.add(a, b, 264)?, 268)?;
+    // 268: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 268)?;
+    vm.write(address, vm.add(a, b, 268)?, 272)?;
+    // 272: mul  #279, #1, rb+0
+    let a = 279;
+    let b = 1;
+    let address = vm.relative_address(0, 272)?;
+    vm.write(address, vm.multiply(a, b, 272)?, 276)?;
+    // 276: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_264(vm: &mut Vm) -> Result<Address, Exit> {
+    // 264: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 264)?;
+    vm.write(address, vm.add(a, b, 264)?, 268)?;
+    // 268: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 268)?;
+    vm.write(address, vm.add(a, b, 268)?, 272)?;
+    // 272: mul  #279, #1, rb+0
+    let a = 279;
+    let b = 1;
+    let address = vm.relative_address(0, 272)?;
+    vm.write(address, vm.multiply(a, b, 272)?, 276)?;
+    // 276: jf   #0, #578
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_279(vm: &mut Vm) -> Result<Address, Exit> {
+    // 279: jf   rb+1, #316
+    if vm.read_relative(1, 279)? == 0 {
+        return Ok(316);
+    }
+    // 282: eq   rb+1, #2, [381]
+    let a = vm.read_relative(1, 282)?;
+    let b = 2;
+    let address = 381;
+    vm.write(address, (a == b) as intcode::Value, 286)?;
+    // 286: jf   [381], #304
+    if vm.read(381) == 0 {
+        return Ok(304);
+    }
+    // 289: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 289)?;
+    vm.write(address, vm.add(a, b, 289)?, 293)?;
+    // 293: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 293)?;
+    vm.write(address, vm.add(a, b, 293)?, 297)?;
+    // 297: mul  #304, #1, rb+0
+    let a = 304;
+    let b = 1;
+    let address = vm.relative_address(0, 297)?;
+    vm.write(address, vm.multiply(a, b, 297)?, 301)?;
+    // 301: jt   #1, #393
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_282(vm: &mut Vm) -> Result<Address, Exit> {
+    // 282: eq   rb+1, #2, [381]
+    let a = vm.read_relative(1, 282)?;
+    let b = 2;
+    let address = 381;
+    vm.write(address, (a == b) as intcode::Value, 286)?;
+    // 286: jf   [381], #304
+    if vm.read(381) == 0 {
+        return Ok(304);
+    }
+    // 289: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 289)?;
+    vm.write(address, vm.add(a, b, 289)?, 293)?;
+    // 293: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 293)?;
+    vm.write(address, vm.add(a, b, 293)?, 297)?;
+    // 297: mul  #304, #1, rb+0
+    let a = 304;
+    let b = 1;
+    let address = vm.relative_address(0, 297)?;
+    vm.write(address, vm.multiply(a, b, 297)?, 301)?;
+    // 301: jt   #1, #393
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_289(vm: &mut Vm) -> Result<Address, Exit> {
+    // 289: add  [388], [390], rb+1
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = vm.relative_address(1, 289)?;
+    vm.write(address, vm.add(a, b, 289)?, 293)?;
+    // 293: add  [389], [391], rb+2
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = vm.relative_address(2, 293)?;
+    vm.write(address, vm.add(a, b, 293)?, 297)?;
+    // 297: mul  #304, #1, rb+0
+    let a = 304;
+    let b = 1;
+    let address = vm.relative_address(0, 297)?;
+    vm.write(address, vm.multiply(a, b, 297)?, 301)?;
+    // 301: jt   #1, #393
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_304(vm: &mut Vm) -> Result<Address, Exit> {
+    // 304: mul  [390], #-1, [390]
+    let a = vm.read(390);
+    let b = -1;
+    let address = 390;
+    vm.write(address, vm.multiply(a, b, 304)?, 308)?;
+    // 308: mul  [391], #-1, [391]
+    let a = vm.read(391);
+    let b = -1;
+    let address = 391;
+    vm.write(address, vm.multiply(a, b, 308)?, 312)?;
+    // 312: mul  #1, #1, [384]
+    let a = 1;
+    let b = 1;
+    let address = 384;
+    vm.write(address, vm.multiply(a, b, 312)?, 316)?;
+    // 316: jt   [384], #161
+    if vm.read(384) != 0 {
+        return Ok(161);
+    }
+    // 319: mul  [388], #1, rb+1
+    let a = vm.read(388);
+    let b = 1;
+    let address = vm.relative_address(1, 319)?;
+    vm.write(address, vm.multiply(a, b, 319)?, 323)?;
+    // 323: mul  [389], #1, rb+2
+    let a = vm.read(389);
+    let b = 1;
+    let address = vm.relative_address(2, 323)?;
+    vm.write(address, vm.multiply(a, b, 323)?, 327)?;
+    // 327: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 327)?;
+    vm.write(address, vm.multiply(a, b, 327)?, 331)?;
+    // 331: add  #338, #0, rb+0
+    let a = 338;
+    let b = 0;
+    let address = vm.relative_address(0, 331)?;
+    vm.write(address, vm.add(a, b, 331)?, 335)?;
+    // 335: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_316(vm: &mut Vm) -> Result<Address, Exit> {
+    // 316: jt   [384], #161
+    if vm.read(384) != 0 {
+        return Ok(161);
+    }
+    // 319: mul  [388], #1, rb+1
+    let a = vm.read(388);
+    let b = 1;
+    let address = vm.relative_address(1, 319)?;
+    vm.write(address, vm.multiply(a, b, 319)?, 323)?;
+    // 323: mul  [389], #1, rb+2
+    let a = vm.read(389);
+    let b = 1;
+    let address = vm.relative_address(2, 323)?;
+    vm.write(address, vm.multiply(a, b, 323)?, 327)?;
+    // 327: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 327)?;
+    vm.write(address, vm.multiply(a, b, 327)?, 331)?;
+    // 331: add  #338, #0, rb+0
+    let a = 338;
+    let b = 0;
+    let address = vm.relative_address(0, 331)?;
+    vm.write(address, vm.add(a, b, 331)?, 335)?;
+    // 335: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_319(vm: &mut Vm) -> Result<Address, Exit> {
+    // 319: mul  [388], #1, rb+1
+    let a = vm.read(388);
+    let b = 1;
+    let address = vm.relative_address(1, 319)?;
+    vm.write(address, vm.multiply(a, b, 319)?, 323)?;
+    // 323: mul  [389], #1, rb+2
+    let a = vm.read(389);
+    let b = 1;
+    let address = vm.relative_address(2, 323)?;
+    vm.write(address, vm.multiply(a, b, 323)?, 327)?;
+    // 327: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 327)?;
+    vm.write(address, vm.multiply(a, b, 327)?, 331)?;
+    // 331: add  #338, #0, rb+0
+    let a = 338;
+    let b = 0;
+    let address = vm.relative_address(0, 331)?;
+    vm.write(address, vm.add(a, b, 331)?, 335)?;
+    // 335: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_338(vm: &mut Vm) -> Result<Address, Exit> {
+    // 338: add  [388], [390], [388]
+    let a = vm.read(388);
+    let b = vm.read(390);
+    let address = 388;
+    vm.write(address, vm.add(a, b, 338)?, 342)?;
+    // 342: add  [389], [391], [389]
+    let a = vm.read(389);
+    let b = vm.read(391);
+    let address = 389;
+    vm.write(address, vm.add(a, b, 342)?, 346)?;
+    // 346: add  #0, [388], rb+1
+    let a = 0;
+    let b = vm.read(388);
+    let address = vm.relative_address(1, 346)?;
+    vm.write(address, vm.add(a, b, 346)?, 350)?;
+    // 350: add  [389], #0, rb+2
+    let a = vm.read(389);
+    let b = 0;
+    let address = vm.relative_address(2, 350)?;
+    vm.write(address, vm.add(a, b, 350)?, 354)?;
+    // 354: mul  #1, #4, rb+3
+    let a = 1;
+    let b = 4;
+    let address = vm.relative_address(3, 354)?;
+    vm.write(address, vm.multiply(a, b, 354)?, 358)?;
+    // 358: add  #0, #365, rb+0
+    let a = 0;
+    let b = 365;
+    let address = vm.relative_address(0, 358)?;
+    vm.write(address, vm.add(a, b, 358)?, 362)?;
+    // 362: jt   #1, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_365(vm: &mut Vm) -> Result<Address, Exit> {
+    // 365: lt   [389], #23, [381]
+    let a = vm.read(389);
+    let b = 23;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 369)?;
+    // 369: jt   [381], #75
+    if vm.read(381) != 0 {
+        return Ok(75);
+    }
+    // 372: out  #-1
+    Err(Exit::Output(-1, 374))
+}
+
+fn block_372(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 372: out  #-1
+    Err(Exit::Output(-1, 374))
+}
+
+fn block_374(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 374: out  #0
+    Err(Exit::Output(0, 376))
+}
+
+fn block_376(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 376: out  #0
+    Err(Exit::Output(0, 378))
+}
+
+fn block_378(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 378: hlt
+    Err(Exit::Interpret(378))
+}
+
+fn block_393(vm: &mut Vm) -> Result<Address, Exit> {
+    // 393: arb  #3
+    vm.adjust_relative_base(3, 393)?;
+    // 395: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 395)?;
+    let address = vm.relative_address(1, 395)?;
+    vm.write(address, vm.add(a, b, 395)?, 399)?;
+    // 399: mul  #1, rb-1, rb+2
+    let a = 1;
+    let b = vm.read_relative(-1, 399)?;
+    let address = vm.relative_address(2, 399)?;
+    vm.write(address, vm.multiply(a, b, 399)?, 403)?;
+    // 403: mul  #0, #1, rb+3
+    let a = 0;
+    let b = 1;
+    let address = vm.relative_address(3, 403)?;
+    vm.write(address, vm.multiply(a, b, 403)?, 407)?;
+    // 407: add  #0, #414, rb+0
+    let a = 0;
+    let b = 414;
+    let address = vm.relative_address(0, 407)?;
+    vm.write(address, vm.add(a, b, 407)?, 411)?;
+    // 411: jf   #0, #549
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_414(vm: &mut Vm) -> Result<Address, Exit> {
+    // 414: add  #0, rb-2, rb+1
+    let a = 0;
+    let b = vm.read_relative(-2, 414)?;
+    let address = vm.relative_address(1, 414)?;
+    vm.write(address, vm.add(a, b, 414)?, 418)?;
+    // 418: add  #0, rb-1, rb+2
+    let a = 0;
+    let b = vm.read_relative(-1, 418)?;
+    let address = vm.relative_address(2, 418)?;
+    vm.write(address, vm.add(a, b, 418)?, 422)?;
+    // 422: mul  #1, #429, rb+0
+    let a = 1;
+    let b = 429;
+    let address = vm.relative_address(0, 422)?;
+    vm.write(address, vm.multiply(a, b, 422)?, 426)?;
+    // 426: jf   #0, #601
+    // 601: arb  #3
+    vm.adjust_relative_base(3, 601)?;
+    // 603: mul  #24, rb-2, rb+1
+    let a = 24;
+    let b = vm.read_relative(-2, 603)?;
+    let address = vm.relative_address(1, 603)?;
+    vm.write(address, vm.multiply(a, b, 603)?, 607)?;
+    // 607: add  rb+1, rb-1, rb+1
+    let a = vm.read_relative(1, 607)?;
+    let b = vm.read_relative(-1, 607)?;
+    let address = vm.relative_address(1, 607)?;
+    vm.write(address, vm.add(a, b, 607)?, 611)?;
+    // 611: mul  #509, #1, rb+2
+    let a = 509;
+    let b = 1;
+    let address = vm.relative_address(2, 611)?;
+    vm.write(address, vm.multiply(a, b, 611)?, 615)?;
+    // 615: add  #480, #0, rb+3
+    let a = 480;
+    let b = 0;
+    let address = vm.relative_address(3, 615)?;
+    vm.write(address, vm.add(a, b, 615)?, 619)?;
+    // 619: mul  #1008, #1, rb+4
+    let a = 1008;
+    let b = 1;
+    let address = vm.relative_address(4, 619)?;
+    vm.write(address, vm.multiply(a, b, 619)?, 623)?;
+    // 623: mul  #1, #630, rb+0
+    let a = 1;
+    let b = 630;
+    let address = vm.relative_address(0, 623)?;
+    vm.write(address, vm.multiply(a, b, 623)?, 627)?;
+    // 627: jt   #1, #456
+    // 456: arb  #8
+    vm.adjust_relative_base(8, 456)?;
+    // 458: mul  rb-7, rb-6, rb-3
+    let a = vm.read_relative(-7, 458)?;
+    let b = vm.read_relative(-6, 458)?;
+    let address = vm.relative_address(-3, 458)?;
+    vm.write(address, vm.multiply(a, b, 458)?, 462)?;
+    // 462: add  rb-3, rb-5, rb-3
+    let a = vm.read_relative(-3, 462)?;
+    let b = vm.read_relative(-5, 462)?;
+    let address = vm.relative_address(-3, 462)?;
+    vm.write(address, vm.add(a, b, 462)?, 466)?;
+    // 466: mul  rb-4, #64, rb-2
+    let a = vm.read_relative(-4, 466)?;
+    let b = 64;
+    let address = vm.relative_address(-2, 466)?;
+    vm.write(address, vm.multiply(a, b, 466)?, 470)?;
+    // 470: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 470)?;
+    let b = vm.read_relative(-2, 470)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 474)?;
+    // 474: jt   [381], #492
+    if vm.read(381) != 0 {
+        return Ok(492);
+    }
+    // 477: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 477)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 477)?;
+    vm.write(address, vm.multiply(a, b, 477)?, 481)?;
+    // 481: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 481)?;
+    let b = vm.read_relative(-1, 481)?;
+    let address = vm.relative_address(-3, 481)?;
+    vm.write(address, vm.add(a, b, 481)?, 485)?;
+    // 485: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 485)?;
+    let b = vm.read_relative(-2, 485)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 489)?;
+    // 489: jf   [381], #481
+    if vm.read(381) == 0 {
+        return Ok(481);
+    }
+    // 492: mul  rb-4, #8, rb-2
+    let a = vm.read_relative(-4, 492)?;
+    let b = 8;
+    let address = vm.relative_address(-2, 492)?;
+    vm.write(address, vm.multiply(a, b, 492)?, 496)?;
+    // 496: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 496)?;
+    let b = vm.read_relative(-2, 496)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 500)?;
+    // 500: jt   [381], #518
+    if vm.read(381) != 0 {
+        return Ok(518);
+    }
+    // 503: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 503)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 503)?;
+    vm.write(address, vm.multiply(a, b, 503)?, 507)?;
+    // 507: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 507)?;
+    let b = vm.read_relative(-1, 507)?;
+    let address = vm.relative_address(-3, 507)?;
+    vm.write(address, vm.add(a, b, 507)?, 511)?;
+    // 511: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 511)?;
+    let b = vm.read_relative(-2, 511)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 515)?;
+    // 515: jf   [381], #507
+    if vm.read(381) == 0 {
+        return Ok(507);
+    }
+    Ok(518)
+}
+
+fn block_429(vm: &mut Vm) -> Result<Address, Exit> {
+    // 429: add  #0, rb+1, [435]
+    let a = 0;
+    let b = vm.read_relative(1, 429)?;
+    let address = 435;
+    vm.write(address, vm.add(a, b, 429)?, 433)?;
+    // 433: add  [386], [0], [386]
+    let a = vm.read(386);
+    let b = vm.read(0);
+    let address = 386;
+    vm.write(address, vm.add(a, b, 433)?, 437)?;
+    // 437: out  #-1
+    Err(Exit::Output(-1, 439))
+}
+
+fn block_439(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 439: out  #0
+    Err(Exit::Output(0, 441))
+}
+
+fn block_441(vm: &mut Vm) -> Result<Address, Exit> {
+    // 441: out  [386]
+    Err(Exit::Output(vm.read(386), 443))
+}
+
+fn block_443(vm: &mut Vm) -> Result<Address, Exit> {
+    // 443: add  [387], #-1, [387]
+    let a = vm.read(387);
+    let b = -1;
+    let address = 387;
+    vm.write(address, vm.add(a, b, 443)?, 447)?;
+    // 447: jt   [387], #451
+    if vm.read(387) != 0 {
+        return Ok(451);
+    }
+    // 450: hlt
+    Err(Exit::Interpret(450))
+}
+
+fn block_450(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 450: hlt
+    Err(Exit::Interpret(450))
+}
+
+fn block_451(vm: &mut Vm) -> Result<Address, Exit> {
+    // 451: arb  #-3
+    vm.adjust_relative_base(-3, 451)?;
+    // 453: jf   #0, rb+0
+    vm.jump(vm.read_relative(0, 453)?, 453)
+}
+
+fn block_456(vm: &mut Vm) -> Result<Address, Exit> {
+    // 456: arb  #8
+    vm.adjust_relative_base(8, 456)?;
+    // 458: mul  rb-7, rb-6, rb-3
+    let a = vm.read_relative(-7, 458)?;
+    let b = vm.read_relative(-6, 458)?;
+    let address = vm.relative_address(-3, 458)?;
+    vm.write(address, vm.multiply(a, b, 458)?, 462)?;
+    // 462: add  rb-3, rb-5, rb-3
+    let a = vm.read_relative(-3, 462)?;
+    let b = vm.read_relative(-5, 462)?;
+    let address = vm.relative_address(-3, 462)?;
+    vm.write(address, vm.add(a, b, 462)?, 466)?;
+    // 466: mul  rb-4, #64, rb-2
+    let a = vm.read_relative(-4, 466)?;
+    let b = 64;
+    let address = vm.relative_address(-2, 466)?;
+    vm.write(address, vm.multiply(a, b, 466)?, 470)?;
+    // 470: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 470)?;
+    let b = vm.read_relative(-2, 470)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 474)?;
+    // 474: jt   [381], #492
+    if vm.read(381) != 0 {
+        return Ok(492);
+    }
+    // 477: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 477)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 477)?;
+    vm.write(address, vm.multiply(a, b, 477)?, 481)?;
+    // 481: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 481)?;
+    let b = vm.read_relative(-1, 481)?;
+    let address = vm.relative_address(-3, 481)?;
+    vm.write(address, vm.add(a, b, 481)?, 485)?;
+    // 485: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 485)?;
+    let b = vm.read_relative(-2, 485)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 489)?;
+    // 489: jf   [381], #481
+    if vm.read(381) == 0 {
+        return Ok(481);
+    }
+    // 492: mul  rb-4, #8, rb-2
+    let a = vm.read_relative(-4, 492)?;
+    let b = 8;
+    let address = vm.relative_address(-2, 492)?;
+    vm.write(address, vm.multiply(a, b, 492)?, 496)?;
+    // 496: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 496)?;
+    let b = vm.read_relative(-2, 496)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 500)?;
+    // 500: jt   [381], #518
+    if vm.read(381) != 0 {
+        return Ok(518);
+    }
+    // 503: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 503)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 503)?;
+    vm.write(address, vm.multiply(a, b, 503)?, 507)?;
+    // 507: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 507)?;
+    let b = vm.read_relative(-1, 507)?;
+    let address = vm.relative_address(-3, 507)?;
+    vm.write(address, vm.add(a, b, 507)?, 511)?;
+    // 511: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 511)?;
+    let b = vm.read_relative(-2, 511)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 515)?;
+    // 515: jf   [381], #507
+    if vm.read(381) == 0 {
+        return Ok(507);
+    }
+    // 518: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 518)?;
+    let b = vm.read_relative(-4, 518)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 522)?;
+    // 522: jt   [381], #540
+    if vm.read(381) != 0 {
+        return Ok(540);
+    }
+    // 525: mul  rb-4, #-1, rb-1
+    let a = vm.read_relative(-4, 525)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 525)?;
+    vm.write(address, vm.multiply(a, b, 525)?, 529)?;
+    Ok(529)
+}
+
+fn block_477(vm: &mut Vm) -> Result<Address, Exit> {
+    // 477: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 477)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 477)?;
+    vm.write(address, vm.multiply(a, b, 477)?, 481)?;
+    // 481: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 481)?;
+    let b = vm.read_relative(-1, 481)?;
+    let address = vm.relative_address(-3, 481)?;
+    vm.write(address, vm.add(a, b, 481)?, 485)?;
+    // 485: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 485)?;
+    let b = vm.read_relative(-2, 485)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 489)?;
+    // 489: jf   [381], #481
+    if vm.read(381) == 0 {
+        return Ok(481);
+    }
+    // 492: mul  rb-4, #8, rb-2
+    let a = vm.read_relative(-4, 492)?;
+    let b = 8;
+    let address = vm.relative_address(-2, 492)?;
+    vm.write(address, vm.multiply(a, b, 492)?, 496)?;
+    // 496: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 496)?;
+    let b = vm.read_relative(-2, 496)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 500)?;
+    // 500: jt   [381], #518
+    if vm.read(381) != 0 {
+        return Ok(518);
+    }
+    // 503: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 503)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 503)?;
+    vm.write(address, vm.multiply(a, b, 503)?, 507)?;
+    // 507: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 507)?;
+    let b = vm.read_relative(-1, 507)?;
+    let address = vm.relative_address(-3, 507)?;
+    vm.write(address, vm.add(a, b, 507)?, 511)?;
+    // 511: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 511)?;
+    let b = vm.read_relative(-2, 511)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 515)?;
+    // 515: jf   [381], #507
+    if vm.read(381) == 0 {
+        return Ok(507);
+    }
+    // 518: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 518)?;
+    let b = vm.read_relative(-4, 518)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 522)?;
+    // 522: jt   [381], #540
+    if vm.read(381) != 0 {
+        return Ok(540);
+    }
+    // 525: mul  rb-4, #-1, rb-1
+    let a = vm.read_relative(-4, 525)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 525)?;
+    vm.write(address, vm.multiply(a, b, 525)?, 529)?;
+    // 529: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 529)?;
+    let b = vm.read_relative(-1, 529)?;
+    let address = vm.relative_address(-3, 529)?;
+    vm.write(address, vm.add(a, b, 529)?, 533)?;
+    // 533: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 533)?;
+    let b = vm.read_relative(-4, 533)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 537)?;
+    // 537: jf   [381], #529
+    if vm.read(381) == 0 {
+        return Ok(529);
+    }
+    Ok(540)
+}
+
+fn block_481(vm: &mut Vm) -> Result<Address, Exit> {
+    loop {
+        // 481: add  rb-3, rb-1, rb-3
+        let a = vm.read_relative(-3, 481)?;
+        let b = vm.read_relative(-1, 481)?;
+        let address = vm.relative_address(-3, 481)?;
+        vm.write(address, vm.add(a, b, 481)?, 485)?;
+        // 485: lt   rb-3, rb-2, [381]
+        let a = vm.read_relative(-3, 485)?;
+        let b = vm.read_relative(-2, 485)?;
+        let address = 381;
+        vm.write(address, (a < b) as intcode::Value, 489)?;
+        // 489: jf   [381], #481
+        if vm.read(381) == 0 {
+            continue;
+        }
+        // 492: mul  rb-4, #8, rb-2
+        let a = vm.read_relative(-4, 492)?;
+        let b = 8;
+        let address = vm.relative_address(-2, 492)?;
+        vm.write(address, vm.multiply(a, b, 492)?, 496)?;
+        // 496: lt   rb-3, rb-2, [381]
+        let a = vm.read_relative(-3, 496)?;
+        let b = vm.read_relative(-2, 496)?;
+        let address = 381;
+        vm.write(address, (a < b) as intcode::Value, 500)?;
+        // 500: jt   [381], #518
+        if vm.read(381) != 0 {
+            return Ok(518);
+        }
+        // 503: mul  rb-2, #-1, rb-1
+        let a = vm.read_relative(-2, 503)?;
+        let b = -1;
+        let address = vm.relative_address(-1, 503)?;
+        vm.write(address, vm.multiply(a, b, 503)?, 507)?;
+        // 507: add  rb-3, rb-1, rb-3
+        let a = vm.read_relative(-3, 507)?;
+        let b = vm.read_relative(-1, 507)?;
+        let address = vm.relative_address(-3, 507)?;
+        vm.write(address, vm.add(a, b, 507)?, 511)?;
+        // 511: lt   rb-3, rb-2, [381]
+        let a = vm.read_relative(-3, 511)?;
+        let b = vm.read_relative(-2, 511)?;
+        let address = 381;
+        vm.write(address, (a < b) as intcode::Value, 515)?;
+        // 515: jf   [381], #507
+        if vm.read(381) == 0 {
+            return Ok(507);
+        }
+        // 518: lt   rb-3, rb-4, [381]
+        let a = vm.read_relative(-3, 518)?;
+        let b = vm.read_relative(-4, 518)?;
+        let address = 381;
+        vm.write(address, (a < b) as intcode::Value, 522)?;
+        // 522: jt   [381], #540
+        if vm.read(381) != 0 {
+            return Ok(540);
+        }
+        // 525: mul  rb-4, #-1, rb-1
+        let a = vm.read_relative(-4, 525)?;
+        let b = -1;
+        let address = vm.relative_address(-1, 525)?;
+        vm.write(address, vm.multiply(a, b, 525)?, 529)?;
+        // 529: add  rb-3, rb-1, rb-3
+        let a = vm.read_relative(-3, 529)?;
+        let b = vm.read_relative(-1, 529)?;
+        let address = vm.relative_address(-3, 529)?;
+        vm.write(address, vm.add(a, b, 529)?, 533)?;
+        // 533: lt   rb-3, rb-4, [381]
+        let a = vm.read_relative(-3, 533)?;
+        let b = vm.read_relative(-4, 533)?;
+        let address = 381;
+        vm.write(address, (a < b) as intcode::Value, 537)?;
+        // 537: jf   [381], #529
+        if vm.read(381) == 0 {
+            return Ok(529);
+        }
+        // 540: mul  rb-3, #1, rb-7
+        let a = vm.read_relative(-3, 540)?;
+        let b = 1;
+        let address = vm.relative_address(-7, 540)?;
+        vm.write(address, vm.multiply(a, b, 540)?, 544)?;
+        // 544: arb  #-8
+        vm.adjust_relative_base(-8, 544)?;
+        // 546: jt   #1, rb+0
+        return vm.jump(vm.read_relative(0, 546)?, 546);
+    }
+}
+
+fn block_492(vm: &mut Vm) -> Result<Address, Exit> {
+    // 492: mul  rb-4, #8, rb-2
+    let a = vm.read_relative(-4, 492)?;
+    let b = 8;
+    let address = vm.relative_address(-2, 492)?;
+    vm.write(address, vm.multiply(a, b, 492)?, 496)?;
+    // 496: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 496)?;
+    let b = vm.read_relative(-2, 496)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 500)?;
+    // 500: jt   [381], #518
+    if vm.read(381) != 0 {
+        return Ok(518);
+    }
+    // 503: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 503)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 503)?;
+    vm.write(address, vm.multiply(a, b, 503)?, 507)?;
+    // 507: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 507)?;
+    let b = vm.read_relative(-1, 507)?;
+    let address = vm.relative_address(-3, 507)?;
+    vm.write(address, vm.add(a, b, 507)?, 511)?;
+    // 511: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 511)?;
+    let b = vm.read_relative(-2, 511)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 515)?;
+    // 515: jf   [381], #507
+    if vm.read(381) == 0 {
+        return Ok(507);
+    }
+    // 518: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 518)?;
+    let b = vm.read_relative(-4, 518)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 522)?;
+    // 522: jt   [381], #540
+    if vm.read(381) != 0 {
+        return Ok(540);
+    }
+    // 525: mul  rb-4, #-1, rb-1
+    let a = vm.read_relative(-4, 525)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 525)?;
+    vm.write(address, vm.multiply(a, b, 525)?, 529)?;
+    // 529: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 529)?;
+    let b = vm.read_relative(-1, 529)?;
+    let address = vm.relative_address(-3, 529)?;
+    vm.write(address, vm.add(a, b, 529)?, 533)?;
+    // 533: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 533)?;
+    let b = vm.read_relative(-4, 533)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 537)?;
+    // 537: jf   [381], #529
+    if vm.read(381) == 0 {
+        return Ok(529);
+    }
+    // 540: mul  rb-3, #1, rb-7
+    let a = vm.read_relative(-3, 540)?;
+    let b = 1;
+    let address = vm.relative_address(-7, 540)?;
+    vm.write(address, vm.multiply(a, b, 540)?, 544)?;
+    // 544: arb  #-8
+    vm.adjust_relative_base(-8, 544)?;
+    // 546: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 546)?, 546)
+}
+
+fn block_503(vm: &mut Vm) -> Result<Address, Exit> {
+    // 503: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 503)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 503)?;
+    vm.write(address, vm.multiply(a, b, 503)?, 507)?;
+    // 507: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 507)?;
+    let b = vm.read_relative(-1, 507)?;
+    let address = vm.relative_address(-3, 507)?;
+    vm.write(address, vm.add(a, b, 507)?, 511)?;
+    // 511: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 511)?;
+    let b = vm.read_relative(-2, 511)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 515)?;
+    // 515: jf   [381], #507
+    if vm.read(381) == 0 {
+        return Ok(507);
+    }
+    // 518: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 518)?;
+    let b = vm.read_relative(-4, 518)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 522)?;
+    // 522: jt   [381], #540
+    if vm.read(381) != 0 {
+        return Ok(540);
+    }
+    // 525: mul  rb-4, #-1, rb-1
+    let a = vm.read_relative(-4, 525)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 525)?;
+    vm.write(address, vm.multiply(a, b, 525)?, 529)?;
+    // 529: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 529)?;
+    let b = vm.read_relative(-1, 529)?;
+    let address = vm.relative_address(-3, 529)?;
+    vm.write(address, vm.add(a, b, 529)?, 533)?;
+    // 533: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 533)?;
+    let b = vm.read_relative(-4, 533)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 537)?;
+    // 537: jf   [381], #529
+    if vm.read(381) == 0 {
+        return Ok(529);
+    }
+    // 540: mul  rb-3, #1, rb-7
+    let a = vm.read_relative(-3, 540)?;
+    let b = 1;
+    let address = vm.relative_address(-7, 540)?;
+    vm.write(address, vm.multiply(a, b, 540)?, 544)?;
+    // 544: arb  #-8
+    vm.adjust_relative_base(-8, 544)?;
+    // 546: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 546)?, 546)
+}
+
+fn block_507(vm: &mut Vm) -> Result<Address, Exit> {
+    loop {
+        // 507: add  rb-3, rb-1, rb-3
+        let a = vm.read_relative(-3, 507)?;
+        let b = vm.read_relative(-1, 507)?;
+        let address = vm.relative_address(-3, 507)?;
+        vm.write(address, vm.add(a, b, 507)?, 511)?;
+        // 511: lt   rb-3, rb-2, [381]
+        let a = vm.read_relative(-3, 511)?;
+        let b = vm.read_relative(-2, 511)?;
+        let address = 381;
+        vm.write(address, (a < b) as intcode::Value, 515)?;
+        // 515: jf   [381], #507
+        if vm.read(381) == 0 {
+            continue;
+        }
+        // 518: lt   rb-3, rb-4, [381]
+        let a = vm.read_relative(-3, 518)?;
+        let b = vm.read_relative(-4, 518)?;
+        let address = 381;
+        vm.write(address, (a < b) as intcode::Value, 522)?;
+        // 522: jt   [381], #540
+        if vm.read(381) != 0 {
+            return Ok(540);
+        }
+        // 525: mul  rb-4, #-1, rb-1
+        let a = vm.read_relative(-4, 525)?;
+        let b = -1;
+        let address = vm.relative_address(-1, 525)?;
+        vm.write(address, vm.multiply(a, b, 525)?, 529)?;
+        // 529: add  rb-3, rb-1, rb-3
+        let a = vm.read_relative(-3, 529)?;
+        let b = vm.read_relative(-1, 529)?;
+        let address = vm.relative_address(-3, 529)?;
+        vm.write(address, vm.add(a, b, 529)?, 533)?;
+        // 533: lt   rb-3, rb-4, [381]
+        let a = vm.read_relative(-3, 533)?;
+        let b = vm.read_relative(-4, 533)?;
+        let address = 381;
+        vm.write(address, (a < b) as intcode::Value, 537)?;
+        // 537: jf   [381], #529
+        if vm.read(381) == 0 {
+            return Ok(529);
+        }
+        // 540: mul  rb-3, #1, rb-7
+        let a = vm.read_relative(-3, 540)?;
+        let b = 1;
+        let address = vm.relative_address(-7, 540)?;
+        vm.write(address, vm.multiply(a, b, 540)?, 544)?;
+        // 544: arb  #-8
+        vm.adjust_relative_base(-8, 544)?;
+        // 546: jt   #1, rb+0
+        return vm.jump(vm.read_relative(0, 546)?, 546);
+    }
+}
+
+fn block_518(vm: &mut Vm) -> Result<Address, Exit> {
+    // 518: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 518)?;
+    let b = vm.read_relative(-4, 518)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 522)?;
+    // 522: jt   [381], #540
+    if vm.read(381) != 0 {
+        return Ok(540);
+    }
+    // 525: mul  rb-4, #-1, rb-1
+    let a = vm.read_relative(-4, 525)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 525)?;
+    vm.write(address, vm.multiply(a, b, 525)?, 529)?;
+    // 529: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 529)?;
+    let b = vm.read_relative(-1, 529)?;
+    let address = vm.relative_address(-3, 529)?;
+    vm.write(address, vm.add(a, b, 529)?, 533)?;
+    // 533: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 533)?;
+    let b = vm.read_relative(-4, 533)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 537)?;
+    // 537: jf   [381], #529
+    if vm.read(381) == 0 {
+        return Ok(529);
+    }
+    // 540: mul  rb-3, #1, rb-7
+    let a = vm.read_relative(-3, 540)?;
+    let b = 1;
+    let address = vm.relative_address(-7, 540)?;
+    vm.write(address, vm.multiply(a, b, 540)?, 544)?;
+    // 544: arb  #-8
+    vm.adjust_relative_base(-8, 544)?;
+    // 546: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 546)?, 546)
+}
+
+fn block_525(vm: &mut Vm) -> Result<Address, Exit> {
+    // 525: mul  rb-4, #-1, rb-1
+    let a = vm.read_relative(-4, 525)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 525)?;
+    vm.write(address, vm.multiply(a, b, 525)?, 529)?;
+    // 529: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 529)?;
+    let b = vm.read_relative(-1, 529)?;
+    let address = vm.relative_address(-3, 529)?;
+    vm.write(address, vm.add(a, b, 529)?, 533)?;
+    // 533: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 533)?;
+    let b = vm.read_relative(-4, 533)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 537)?;
+    // 537: jf   [381], #529
+    if vm.read(381) == 0 {
+        return Ok(529);
+    }
+    // 540: mul  rb-3, #1, rb-7
+    let a = vm.read_relative(-3, 540)?;
+    let b = 1;
+    let address = vm.relative_address(-7, 540)?;
+    vm.write(address, vm.multiply(a, b, 540)?, 544)?;
+    // 544: arb  #-8
+    vm.adjust_relative_base(-8, 544)?;
+    // 546: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 546)?, 546)
+}
+
+fn block_529(vm: &mut Vm) -> Result<Address, Exit> {
+    loop {
+        // 529: add  rb-3, rb-1, rb-3
+        let a = vm.read_relative(-3, 529)?;
+        let b = vm.read_relative(-1, 529)?;
+        let address = vm.relative_address(-3, 529)?;
+        vm.write(address, vm.add(a, b, 529)?, 533)?;
+        // 533: lt   rb-3, rb-4, [381]
+        let a = vm.read_relative(-3, 533)?;
+        let b = vm.read_relative(-4, 533)?;
+        let address = 381;
+        vm.write(address, (a < b) as intcode::Value, 537)?;
+        // 537: jf   [381], #529
+        if vm.read(381) == 0 {
+            continue;
+        }
+        // 540: mul  rb-3, #1, rb-7
+        let a = vm.read_relative(-3, 540)?;
+        let b = 1;
+        let address = vm.relative_address(-7, 540)?;
+        vm.write(address, vm.multiply(a, b, 540)?, 544)?;
+        // 544: arb  #-8
+        vm.adjust_relative_base(-8, 544)?;
+        // 546: jt   #1, rb+0
+        return vm.jump(vm.read_relative(0, 546)?, 546);
+    }
+}
+
+fn block_540(vm: &mut Vm) -> Result<Address, Exit> {
+    // 540: mul  rb-3, #1, rb-7
+    let a = vm.read_relative(-3, 540)?;
+    let b = 1;
+    let address = vm.relative_address(-7, 540)?;
+    vm.write(address, vm.multiply(a, b, 540)?, 544)?;
+    // 544: arb  #-8
+    vm.adjust_relative_base(-8, 544)?;
+    // 546: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 546)?, 546)
+}
+
+fn block_549(vm: &mut Vm) -> Result<Address, Exit> {
+    // 549: arb  #4
+    vm.adjust_relative_base(4, 549)?;
+    // 551: mul  rb-2, #42, [566]
+    let a = vm.read_relative(-2, 551)?;
+    let b = 42;
+    let address = 566;
+    vm.write(address, vm.multiply(a, b, 551)?, 555)?;
+    // 555: add  rb-3, [566], [566]
+    let a = vm.read_relative(-3, 555)?;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 555)?, 559)?;
+    // 559: add  #639, [566], [566]
+    let a = 639;
+    let b = vm.read(566);
+    let address = 566;
+    vm.write(address, vm.add(a, b, 559)?, 563)?;
+    // 563: add  #0, rb-1, [0]
+    let a = 0;
+    let b = vm.read_relative(-1, 563)?;
+    let address = 0;
+    vm.write(address, vm.add(a, b, 563)?, 567)?;
+    // 567: out  rb-3
+    Err(Exit::Output(vm.read_relative(-3, 567)?, 569))
+}
+
+fn block_569(vm: &mut Vm) -> Result<Address, Exit> {
+    // 569: out  rb-2
+    Err(Exit::Output(vm.read_relative(-2, 569)?, 571))
+}
+
+fn block_571(vm: &mut Vm) -> Result<Address, Exit> {
+    // 571: out  rb-1
+    Err(Exit::Output(vm.read_relative(-1, 571)?, 573))
+}
+
+fn block_573(vm: &mut Vm) -> Result<Address, Exit> {
+    // 573: arb  #-4
+    vm.adjust_relative_base(-4, 573)?;
+    // 575: jf   #0, rb+0
+    vm.jump(vm.read_relative(0, 575)?, 575)
+}
+
+fn block_578(vm: &mut Vm) -> Result<Address, Exit> {
+    // 578: arb  #3
+    vm.adjust_relative_base(3, 578)?;
+    // 580: mul  rb-1, #42, [594]
+    let a = vm.read_relative(-1, 580)?;
+    let b = 42;
+    let address = 594;
+    vm.write(address, vm.multiply(a, b, 580)?, 584)?;
+    // 584: add  rb-2, [594], [594]
+    let a = vm.read_relative(-2, 584)?;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 584)?, 588)?;
+    // 588: add  #639, [594], [594]
+    let a = 639;
+    let b = vm.read(594);
+    let address = 594;
+    vm.write(address, vm.add(a, b, 588)?, 592)?;
+    // 592: mul  #1, [0], rb-2
+    let a = 1;
+    let b = vm.read(0);
+    let address = vm.relative_address(-2, 592)?;
+    vm.write(address, vm.multiply(a, b, 592)?, 596)?;
+    // 596: arb  #-3
+    vm.adjust_relative_base(-3, 596)?;
+    // 598: jt   #1, rb+0
+    vm.jump(vm.read_relative(0, 598)?, 598)
+}
+
+fn block_601(vm: &mut Vm) -> Result<Address, Exit> {
+    // 601: arb  #3
+    vm.adjust_relative_base(3, 601)?;
+    // 603: mul  #24, rb-2, rb+1
+    let a = 24;
+    let b = vm.read_relative(-2, 603)?;
+    let address = vm.relative_address(1, 603)?;
+    vm.write(address, vm.multiply(a, b, 603)?, 607)?;
+    // 607: add  rb+1, rb-1, rb+1
+    let a = vm.read_relative(1, 607)?;
+    let b = vm.read_relative(-1, 607)?;
+    let address = vm.relative_address(1, 607)?;
+    vm.write(address, vm.add(a, b, 607)?, 611)?;
+    // 611: mul  #509, #1, rb+2
+    let a = 509;
+    let b = 1;
+    let address = vm.relative_address(2, 611)?;
+    vm.write(address, vm.multiply(a, b, 611)?, 615)?;
+    // 615: add  #480, #0, rb+3
+    let a = 480;
+    let b = 0;
+    let address = vm.relative_address(3, 615)?;
+    vm.write(address, vm.add(a, b, 615)?, 619)?;
+    // 619: mul  #1008, #1, rb+4
+    let a = 1008;
+    let b = 1;
+    let address = vm.relative_address(4, 619)?;
+    vm.write(address, vm.multiply(a, b, 619)?, 623)?;
+    // 623: mul  #1, #630, rb+0
+    let a = 1;
+    let b = 630;
+    let address = vm.relative_address(0, 623)?;
+    vm.write(address, vm.multiply(a, b, 623)?, 627)?;
+    // 627: jt   #1, #456
+    // 456: arb  #8
+    vm.adjust_relative_base(8, 456)?;
+    // 458: mul  rb-7, rb-6, rb-3
+    let a = vm.read_relative(-7, 458)?;
+    let b = vm.read_relative(-6, 458)?;
+    let address = vm.relative_address(-3, 458)?;
+    vm.write(address, vm.multiply(a, b, 458)?, 462)?;
+    // 462: add  rb-3, rb-5, rb-3
+    let a = vm.read_relative(-3, 462)?;
+    let b = vm.read_relative(-5, 462)?;
+    let address = vm.relative_address(-3, 462)?;
+    vm.write(address, vm.add(a, b, 462)?, 466)?;
+    // 466: mul  rb-4, #64, rb-2
+    let a = vm.read_relative(-4, 466)?;
+    let b = 64;
+    let address = vm.relative_address(-2, 466)?;
+    vm.write(address, vm.multiply(a, b, 466)?, 470)?;
+    // 470: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 470)?;
+    let b = vm.read_relative(-2, 470)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 474)?;
+    // 474: jt   [381], #492
+    if vm.read(381) != 0 {
+        return Ok(492);
+    }
+    // 477: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 477)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 477)?;
+    vm.write(address, vm.multiply(a, b, 477)?, 481)?;
+    // 481: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 481)?;
+    let b = vm.read_relative(-1, 481)?;
+    let address = vm.relative_address(-3, 481)?;
+    vm.write(address, vm.add(a, b, 481)?, 485)?;
+    // 485: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 485)?;
+    let b = vm.read_relative(-2, 485)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 489)?;
+    // 489: jf   [381], #481
+    if vm.read(381) == 0 {
+        return Ok(481);
+    }
+    // 492: mul  rb-4, #8, rb-2
+    let a = vm.read_relative(-4, 492)?;
+    let b = 8;
+    let address = vm.relative_address(-2, 492)?;
+    vm.write(address, vm.multiply(a, b, 492)?, 496)?;
+    // 496: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 496)?;
+    let b = vm.read_relative(-2, 496)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 500)?;
+    // 500: jt   [381], #518
+    if vm.read(381) != 0 {
+        return Ok(518);
+    }
+    // 503: mul  rb-2, #-1, rb-1
+    let a = vm.read_relative(-2, 503)?;
+    let b = -1;
+    let address = vm.relative_address(-1, 503)?;
+    vm.write(address, vm.multiply(a, b, 503)?, 507)?;
+    // 507: add  rb-3, rb-1, rb-3
+    let a = vm.read_relative(-3, 507)?;
+    let b = vm.read_relative(-1, 507)?;
+    let address = vm.relative_address(-3, 507)?;
+    vm.write(address, vm.add(a, b, 507)?, 511)?;
+    // 511: lt   rb-3, rb-2, [381]
+    let a = vm.read_relative(-3, 511)?;
+    let b = vm.read_relative(-2, 511)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 515)?;
+    // 515: jf   [381], #507
+    if vm.read(381) == 0 {
+        return Ok(507);
+    }
+    // 518: lt   rb-3, rb-4, [381]
+    let a = vm.read_relative(-3, 518)?;
+    let b = vm.read_relative(-4, 518)?;
+    let address = 381;
+    vm.write(address, (a < b) as intcode::Value, 522)?;
+    // 522: jt   [381], #540
+    if vm.read(381) != 0 {
+        return Ok(540);
+    }
+    Ok(525)
+}
+
+fn block_630(vm: &mut Vm) -> Result<Address, Exit> {
+    // 630: add  rb+1, #1647, rb-2
+    let a = vm.read_relative(1, 630)?;
+    let b = 1647;
+    let address = vm.relative_address(-2, 630)?;
+    vm.write(address, vm.add(a, b, 630)?, 634)?;
+    // 634: arb  #-3
+    vm.adjust_relative_base(-3, 634)?;
+    // 636: jf   #0, rb+0
+    vm.jump(vm.read_relative(0, 636)?, 636)
+}
+
+fn block_639(vm: &mut Vm) -> Result<Address, Exit> {
+    // 639: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 639)?, 643)?;
+    // 643: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 643)?, 647)?;
+    // 647: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 647)?, 651)?;
+    // 651: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 651)?, 655)?;
+    // 655: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 655)?, 659)?;
+    // 659: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 659)?, 663)?;
+    // 663: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 663)?, 667)?;
+    // 667: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 667)?, 671)?;
+    // 671: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 671)?, 675)?;
+    // 675: add  [1], [1], [1]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 1;
+    vm.write(address, vm.add(a, b, 675)?, 679)?;
+    // 679: add  [1], [1], [0]
+    let a = vm.read(1);
+    let b = vm.read(1);
+    let address = 0;
+    vm.write(address, vm.add(a, b, 679)?, 683)?;
+    Ok(683)
+}
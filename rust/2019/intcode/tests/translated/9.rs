@@ -0,0 +1,3804 @@
+// Generated by intcode::translate
+
+use intcode::{
+    translate::{Exit, Translation, Vm},
+    Address,
+};
+
+#[allow(clippy::single_range_in_vec_init)]
+pub static PROGRAM: Translation = Translation {
+    memory: &[1102, 34463338, 34463338, 63, 1007, 63, 34463338, 63, 1005, 63, 53, 1102, 3, 1, 1000, 109, 988, 209, 12, 9, 1000, 209, 6, 209, 3, 203, 0, 1008, 1000, 1, 63, 1005, 63, 65, 1008, 1000, 2, 63, 1005, 63, 904, 1008, 1000, 0, 63, 1005, 63, 58, 4, 25, 104, 0, 99, 4, 0, 104, 0, 99, 4, 17, 104, 0, 99, 0, 0, 1102, 1, 22, 1012, 1101, 309, 0, 1024, 1102, 1, 29, 1015, 1101, 0, 30, 1014, 1101, 0, 221, 1028, 1102, 24, 1, 1007, 1102, 32, 1, 1006, 1102, 1, 31, 1001, 1101, 0, 20, 1010, 1101, 34, 0, 1003, 1102, 899, 1, 1026, 1101, 304, 0, 1025, 1101, 0, 1, 1021, 1101, 892, 0, 1027, 1101, 0, 0, 1020, 1101, 0, 484, 1023, 1101, 25, 0, 1018, 1101, 0, 21, 1008, 1102, 491, 1, 1022, 1102, 212, 1, 1029, 1102, 1, 23, 1000, 1101, 0, 26, 1009, 1102, 36, 1, 1005, 1101, 27, 0, 1013, 1101, 35, 0, 1019, 1101, 38, 0, 1017, 1101, 0, 39, 1004, 1102, 37, 1, 1002, 1102, 33, 1, 1011, 1102, 28, 1, 1016, 109, 1, 1208, 5, 35, 63, 1005, 63, 201, 1001, 64, 1, 64, 1106, 0, 203, 4, 187, 1002, 64, 2, 64, 109, 36, 2106, 0, -9, 4, 209, 1001, 64, 1, 64, 1105, 1, 221, 1002, 64, 2, 64, 109, -30, 2101, 0, -4, 63, 1008, 63, 34, 63, 1005, 63, 247, 4, 227, 1001, 64, 1, 64, 1105, 1, 247, 1002, 64, 2, 64, 109, 1, 21108, 40, 40, 8, 1005, 1016, 265, 4, 253, 1106, 0, 269, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 10, 21101, 41, 0, -7, 1008, 1011, 41, 63, 1005, 63, 295, 4, 275, 1001, 64, 1, 64, 1105, 1, 295, 1002, 64, 2, 64, 109, 3, 2105, 1, 3, 4, 301, 1106, 0, 313, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -18, 2108, 38, 1, 63, 1005, 63, 329, 1105, 1, 335, 4, 319, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -11, 2108, 37, 10, 63, 1005, 63, 357, 4, 341, 1001, 64, 1, 64, 1106, 0, 357, 1002, 64, 2, 64, 109, 25, 21107, 42, 41, -6, 1005, 1011, 377, 1001, 64, 1, 64, 1106, 0, 379, 4, 363, 1002, 64, 2, 64, 109, -11, 1207, 3, 25, 63, 1005, 63, 395, 1105, 1, 401, 4, 385, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -4, 1202, 0, 1, 63, 1008, 63, 37, 63, 1005, 63, 423, 4, 407, 1105, 1, 427, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 8, 21102, 43, 1, 6, 1008, 1016, 43, 63, 1005, 63, 453, 4, 433, 1001, 64, 1, 64, 1106, 0, 453, 1002, 64, 2, 64, 109, -11, 1208, 6, 36, 63, 1005, 63, 471, 4, 459, 1105, 1, 475, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 21, 2105, 1, 3, 1001, 64, 1, 64, 1105, 1, 493, 4, 481, 1002, 64, 2, 64, 109, -15, 2107, 22, 3, 63, 1005, 63, 513, 1001, 64, 1, 64, 1106, 0, 515, 4, 499, 1002, 64, 2, 64, 109, -7, 2107, 35, 7, 63, 1005, 63, 537, 4, 521, 1001, 64, 1, 64, 1105, 1, 537, 1002, 64, 2, 64, 109, 23, 1205, 0, 551, 4, 543, 1105, 1, 555, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -4, 21101, 44, 0, -3, 1008, 1014, 45, 63, 1005, 63, 579, 1001, 64, 1, 64, 1105, 1, 581, 4, 561, 1002, 64, 2, 64, 109, -15, 2102, 1, 3, 63, 1008, 63, 33, 63, 1005, 63, 601, 1106, 0, 607, 4, 587, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 23, 1205, -5, 623, 1001, 64, 1, 64, 1106, 0, 625, 4, 613, 1002, 64, 2, 64, 109, -7, 21102, 45, 1, -8, 1008, 1010, 43, 63, 1005, 63, 645, 1105, 1, 651, 4, 631, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -11, 2102, 1, 1, 63, 1008, 63, 21, 63, 1005, 63, 677, 4, 657, 1001, 64, 1, 64, 1106, 0, 677, 1002, 64, 2, 64, 109, 3, 21107, 46, 47, 4, 1005, 1014, 695, 4, 683, 1106, 0, 699, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 7, 21108, 47, 48, -4, 1005, 1013, 715, 1106, 0, 721, 4, 705, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -14, 1201, 0, 0, 63, 1008, 63, 32, 63, 1005, 63, 741, 1106, 0, 747, 4, 727, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 4, 1201, 2, 0, 63, 1008, 63, 26, 63, 1005, 63, 769, 4, 753, 1105, 1, 773, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 5, 1207, -4, 22, 63, 1005, 63, 795, 4, 779, 1001, 64, 1, 64, 1106, 0, 795, 1002, 64, 2, 64, 109, 2, 2101, 0, -9, 63, 1008, 63, 34, 63, 1005, 63, 819, 1001, 64, 1, 64, 1106, 0, 821, 4, 801, 1002, 64, 2, 64, 109, -11, 1202, 1, 1, 63, 1008, 63, 38, 63, 1005, 63, 841, 1105, 1, 847, 4, 827, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 21, 1206, -4, 865, 4, 853, 1001, 64, 1, 64, 1105, 1, 865, 1002, 64, 2, 64, 109, 3, 1206, -6, 877, 1105, 1, 883, 4, 871, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 6, 2106, 0, -6, 1001, 64, 1, 64, 1105, 1, 901, 4, 889, 4, 64, 99, 21101, 0, 27, 1, 21101, 915, 0, 0, 1106, 0, 922, 21201, 1, 23692, 1, 204, 1, 99, 109, 3, 1207, -2, 3, 63, 1005, 63, 964, 21201, -2, -1, 1, 21102, 942, 1, 0, 1106, 0, 922, 21202, 1, 1, -1, 21201, -2, -3, 1, 21101, 0, 957, 0, 1106, 0, 922, 22201, 1, -1, -2, 1106, 0, 968, 22102, 1, -2, -2, 109, -3, 2106, 0, 0],
+    code: &[0..63, 65..973],
+    run,
+};
+
+fn run(vm: &mut Vm, mut ip: Address) -> Exit {
+    loop {
+        let next = match ip {
+            0 => block_0(vm),
+            11 => block_11(vm),
+            25 => block_25(vm),
+            34 => block_34(vm),
+            41 => block_41(vm),
+            48 => block_48(vm),
+            50 => block_50(vm),
+            52 => block_52(vm),
+            53 => block_53(vm),
+            55 => block_55(vm),
+            57 => block_57(vm),
+            58 => block_58(vm),
+            60 => block_60(vm),
+            62 => block_62(vm),
+            65 => block_65(vm),
+            194 => block_194(vm),
+            201 => block_201(vm),
+            203 => block_203(vm),
+            212 => block_212(vm),
+            214 => block_214(vm),
+            221 => block_221(vm),
+            238 => block_238(vm),
+            240 => block_240(vm),
+            247 => block_247(vm),
+            260 => block_260(vm),
+            262 => block_262(vm),
+            265 => block_265(vm),
+            269 => block_269(vm),
+            286 => block_286(vm),
+            288 => block_288(vm),
+            295 => block_295(vm),
+            304 => block_304(vm),
+            306 => block_306(vm),
+            309 => block_309(vm),
+            313 => block_313(vm),
+            326 => block_326(vm),
+            329 => block_329(vm),
+            331 => block_331(vm),
+            335 => block_335(vm),
+            348 => block_348(vm),
+            350 => block_350(vm),
+            357 => block_357(vm),
+            370 => block_370(vm),
+            377 => block_377(vm),
+            379 => block_379(vm),
+            392 => block_392(vm),
+            395 => block_395(vm),
+            397 => block_397(vm),
+            401 => block_401(vm),
+            418 => block_418(vm),
+            420 => block_420(vm),
+            423 => block_423(vm),
+            427 => block_427(vm),
+            444 => block_444(vm),
+            446 => block_446(vm),
+            453 => block_453(vm),
+            466 => block_466(vm),
+            468 => block_468(vm),
+            471 => block_471(vm),
+            475 => block_475(vm),
+            484 => block_484(vm),
+            491 => block_491(vm),
+            493 => block_493(vm),
+            506 => block_506(vm),
+            513 => block_513(vm),
+            515 => block_515(vm),
+            528 => block_528(vm),
+            530 => block_530(vm),
+            537 => block_537(vm),
+            546 => block_546(vm),
+            548 => block_548(vm),
+            551 => block_551(vm),
+            555 => block_555(vm),
+            572 => block_572(vm),
+            579 => block_579(vm),
+            581 => block_581(vm),
+            598 => block_598(vm),
+            601 => block_601(vm),
+            603 => block_603(vm),
+            607 => block_607(vm),
+            616 => block_616(vm),
+            623 => block_623(vm),
+            625 => block_625(vm),
+            642 => block_642(vm),
+            645 => block_645(vm),
+            647 => block_647(vm),
+            651 => block_651(vm),
+            668 => block_668(vm),
+            670 => block_670(vm),
+            677 => block_677(vm),
+            690 => block_690(vm),
+            692 => block_692(vm),
+            695 => block_695(vm),
+            699 => block_699(vm),
+            712 => block_712(vm),
+            715 => block_715(vm),
+            717 => block_717(vm),
+            721 => block_721(vm),
+            738 => block_738(vm),
+            741 => block_741(vm),
+            743 => block_743(vm),
+            747 => block_747(vm),
+            764 => block_764(vm),
+            766 => block_766(vm),
+            769 => block_769(vm),
+            773 => block_773(vm),
+            786 => block_786(vm),
+            788 => block_788(vm),
+            795 => block_795(vm),
+            812 => block_812(vm),
+            819 => block_819(vm),
+            821 => block_821(vm),
+            838 => block_838(vm),
+            841 => block_841(vm),
+            843 => block_843(vm),
+            847 => block_847(vm),
+            856 => block_856(vm),
+            858 => block_858(vm),
+            865 => block_865(vm),
+            874 => block_874(vm),
+            877 => block_877(vm),
+            879 => block_879(vm),
+            883 => block_883(vm),
+            892 => block_892(vm),
+            899 => block_899(vm),
+            901 => block_901(vm),
+            903 => block_903(vm),
+            904 => block_904(vm),
+            915 => block_915(vm),
+            921 => block_921(vm),
+            922 => block_922(vm),
+            931 => block_931(vm),
+            942 => block_942(vm),
+            957 => block_957(vm),
+            964 => block_964(vm),
+            968 => block_968(vm),
+            _ => return Exit::Interpret(ip),
+        };
+        match next {
+            Ok(next) => ip = next,
+            Err(exit) => return exit,
+        }
+    }
+}
+
+fn block_0(vm: &mut Vm) -> Result<Address, Exit> {
+    // 0: mul  #34463338, #34463338, [63]
+    let a = 34463338;
+    let b = 34463338;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 0)?, 4)?;
+    // 4: lt   [63], #34463338, [63]
+    let a = vm.read(63);
+    let b = 34463338;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 8)?;
+    // 8: jt   [63], #53
+    if vm.read(63) != 0 {
+        return Ok(53);
+    }
+    // 11: mul  #3, #1, [1000]
+    let a = 3;
+    let b = 1;
+    let address = 1000;
+    vm.write(address, vm.multiply(a, b, 11)?, 15)?;
+    // 15: arb  #988
+    vm.adjust_relative_base(988, 15)?;
+    // 17: arb  rb+12
+    vm.adjust_relative_base(vm.read_relative(12, 17)?, 17)?;
+    // 19: arb  [1000]
+    vm.adjust_relative_base(vm.read(1000), 19)?;
+    // 21: arb  rb+6
+    vm.adjust_relative_base(vm.read_relative(6, 21)?, 21)?;
+    // 23: arb  rb+3
+    vm.adjust_relative_base(vm.read_relative(3, 23)?, 23)?;
+    // 25: in   rb+0
+    let address = vm.relative_address(0, 25)?;
+    let value = vm.input(25)?;
+    vm.write(address, value, 27)?;
+    // 27: eq   [1000], #1, [63]
+    let a = vm.read(1000);
+    let b = 1;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 31)?;
+    // 31: jt   [63], #65
+    if vm.read(63) != 0 {
+        return Ok(65);
+    }
+    // 34: eq   [1000], #2, [63]
+    let a = vm.read(1000);
+    let b = 2;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 38)?;
+    // 38: jt   [63], #904
+    if vm.read(63) != 0 {
+        return Ok(904);
+    }
+    // 41: eq   [1000], #0, [63]
+    let a = vm.read(1000);
+    let b = 0;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 45)?;
+    // 45: jt   [63], #58
+    if vm.read(63) != 0 {
+        return Ok(58);
+    }
+    // 48: out  [25]
+    Err(Exit::Output(vm.read(25), 50))
+}
+
+fn block_11(vm: &mut Vm) -> Result<Address, Exit> {
+    // 11: mul  #3, #1, [1000]
+    let a = 3;
+    let b = 1;
+    let address = 1000;
+    vm.write(address, vm.multiply(a, b, 11)?, 15)?;
+    // 15: arb  #988
+    vm.adjust_relative_base(988, 15)?;
+    // 17: arb  rb+12
+    vm.adjust_relative_base(vm.read_relative(12, 17)?, 17)?;
+    // 19: arb  [1000]
+    vm.adjust_relative_base(vm.read(1000), 19)?;
+    // 21: arb  rb+6
+    vm.adjust_relative_base(vm.read_relative(6, 21)?, 21)?;
+    // 23: arb  rb+3
+    vm.adjust_relative_base(vm.read_relative(3, 23)?, 23)?;
+    // 25: in   rb+0
+    let address = vm.relative_address(0, 25)?;
+    let value = vm.input(25)?;
+    vm.write(address, value, 27)?;
+    // 27: eq   [1000], #1, [63]
+    let a = vm.read(1000);
+    let b = 1;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 31)?;
+    // 31: jt   [63], #65
+    if vm.read(63) != 0 {
+        return Ok(65);
+    }
+    // 34: eq   [1000], #2, [63]
+    let a = vm.read(1000);
+    let b = 2;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 38)?;
+    // 38: jt   [63], #904
+    if vm.read(63) != 0 {
+        return Ok(904);
+    }
+    // 41: eq   [1000], #0, [63]
+    let a = vm.read(1000);
+    let b = 0;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 45)?;
+    // 45: jt   [63], #58
+    if vm.read(63) != 0 {
+        return Ok(58);
+    }
+    // 48: out  [25]
+    Err(Exit::Output(vm.read(25), 50))
+}
+
+fn block_25(vm: &mut Vm) -> Result<Address, Exit> {
+    // 25: in   rb+0
+    let address = vm.relative_address(0, 25)?;
+    let value = vm.input(25)?;
+    vm.write(address, value, 27)?;
+    // 27: eq   [1000], #1, [63]
+    let a = vm.read(1000);
+    let b = 1;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 31)?;
+    // 31: jt   [63], #65
+    if vm.read(63) != 0 {
+        return Ok(65);
+    }
+    // 34: eq   [1000], #2, [63]
+    let a = vm.read(1000);
+    let b = 2;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 38)?;
+    // 38: jt   [63], #904
+    if vm.read(63) != 0 {
+        return Ok(904);
+    }
+    // 41: eq   [1000], #0, [63]
+    let a = vm.read(1000);
+    let b = 0;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 45)?;
+    // 45: jt   [63], #58
+    if vm.read(63) != 0 {
+        return Ok(58);
+    }
+    // 48: out  [25]
+    Err(Exit::Output(vm.read(25), 50))
+}
+
+fn block_34(vm: &mut Vm) -> Result<Address, Exit> {
+    // 34: eq   [1000], #2, [63]
+    let a = vm.read(1000);
+    let b = 2;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 38)?;
+    // 38: jt   [63], #904
+    if vm.read(63) != 0 {
+        return Ok(904);
+    }
+    // 41: eq   [1000], #0, [63]
+    let a = vm.read(1000);
+    let b = 0;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 45)?;
+    // 45: jt   [63], #58
+    if vm.read(63) != 0 {
+        return Ok(58);
+    }
+    // 48: out  [25]
+    Err(Exit::Output(vm.read(25), 50))
+}
+
+fn block_41(vm: &mut Vm) -> Result<Address, Exit> {
+    // 41: eq   [1000], #0, [63]
+    let a = vm.read(1000);
+    let b = 0;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 45)?;
+    // 45: jt   [63], #58
+    if vm.read(63) != 0 {
+        return Ok(58);
+    }
+    // 48: out  [25]
+    Err(Exit::Output(vm.read(25), 50))
+}
+
+fn block_48(vm: &mut Vm) -> Result<Address, Exit> {
+    // 48: out  [25]
+    Err(Exit::Output(vm.read(25), 50))
+}
+
+fn block_50(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 50: out  #0
+    Err(Exit::Output(0, 52))
+}
+
+fn block_52(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 52: hlt
+    Err(Exit::Interpret(52))
+}
+
+fn block_53(vm: &mut Vm) -> Result<Address, Exit> {
+    // 53: out  [0]
+    Err(Exit::Output(vm.read(0), 55))
+}
+
+fn block_55(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 55: out  #0
+    Err(Exit::Output(0, 57))
+}
+
+fn block_57(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 57: hlt
+    Err(Exit::Interpret(57))
+}
+
+fn block_58(vm: &mut Vm) -> Result<Address, Exit> {
+    // 58: out  [17]
+    Err(Exit::Output(vm.read(17), 60))
+}
+
+fn block_60(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 60: out  #0
+    Err(Exit::Output(0, 62))
+}
+
+fn block_62(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 62: hlt
+    Err(Exit::Interpret(62))
+}
+
+fn block_65(vm: &mut Vm) -> Result<Address, Exit> {
+    // 65: mul  #1, #22, [1012]
+    let a = 1;
+    let b = 22;
+    let address = 1012;
+    vm.write(address, vm.multiply(a, b, 65)?, 69)?;
+    // 69: add  #309, #0, [1024]
+    let a = 309;
+    let b = 0;
+    let address = 1024;
+    vm.write(address, vm.add(a, b, 69)?, 73)?;
+    // 73: mul  #1, #29, [1015]
+    let a = 1;
+    let b = 29;
+    let address = 1015;
+    vm.write(address, vm.multiply(a, b, 73)?, 77)?;
+    // 77: add  #0, #30, [1014]
+    let a = 0;
+    let b = 30;
+    let address = 1014;
+    vm.write(address, vm.add(a, b, 77)?, 81)?;
+    // 81: add  #0, #221, [1028]
+    let a = 0;
+    let b = 221;
+    let address = 1028;
+    vm.write(address, vm.add(a, b, 81)?, 85)?;
+    // 85: mul  #24, #1, [1007]
+    let a = 24;
+    let b = 1;
+    let address = 1007;
+    vm.write(address, vm.multiply(a, b, 85)?, 89)?;
+    // 89: mul  #32, #1, [1006]
+    let a = 32;
+    let b = 1;
+    let address = 1006;
+    vm.write(address, vm.multiply(a, b, 89)?, 93)?;
+    // 93: mul  #1, #31, [1001]
+    let a = 1;
+    let b = 31;
+    let address = 1001;
+    vm.write(address, vm.multiply(a, b, 93)?, 97)?;
+    // 97: add  #0, #20, [1010]
+    let a = 0;
+    let b = 20;
+    let address = 1010;
+    vm.write(address, vm.add(a, b, 97)?, 101)?;
+    // 101: add  #34, #0, [1003]
+    let a = 34;
+    let b = 0;
+    let address = 1003;
+    vm.write(address, vm.add(a, b, 101)?, 105)?;
+    // 105: mul  #899, #1, [1026]
+    let a = 899;
+    let b = 1;
+    let address = 1026;
+    vm.write(address, vm.multiply(a, b, 105)?, 109)?;
+    // 109: add  #304, #0, [1025]
+    let a = 304;
+    let b = 0;
+    let address = 1025;
+    vm.write(address, vm.add(a, b, 109)?, 113)?;
+    // 113: add  #0, #1, [1021]
+    let a = 0;
+    let b = 1;
+    let address = 1021;
+    vm.write(address, vm.add(a, b, 113)?, 117)?;
+    // 117: add  #892, #0, [1027]
+    let a = 892;
+    let b = 0;
+    let address = 1027;
+    vm.write(address, vm.add(a, b, 117)?, 121)?;
+    // 121: add  #0, #0, [1020]
+    let a = 0;
+    let b = 0;
+    let address = 1020;
+    vm.write(address, vm.add(a, b, 121)?, 125)?;
+    // 125: add  #0, #484, [1023]
+    let a = 0;
+    let b = 484;
+    let address = 1023;
+    vm.write(address, vm.add(a, b, 125)?, 129)?;
+    // 129: add  #25, #0, [1018]
+    let a = 25;
+    let b = 0;
+    let address = 1018;
+    vm.write(address, vm.add(a, b, 129)?, 133)?;
+    // 133: add  #0, #21, [1008]
+    let a = 0;
+    let b = 21;
+    let address = 1008;
+    vm.write(address, vm.add(a, b, 133)?, 137)?;
+    // 137: mul  #491, #1, [1022]
+    let a = 491;
+    let b = 1;
+    let address = 1022;
+    vm.write(address, vm.multiply(a, b, 137)?, 141)?;
+    // 141: mul  #212, #1, [1029]
+    let a = 212;
+    let b = 1;
+    let address = 1029;
+    vm.write(address, vm.multiply(a, b, 141)?, 145)?;
+    // 145: mul  #1, #23, [1000]
+    let a = 1;
+    let b = 23;
+    let address = 1000;
+    vm.write(address, vm.multiply(a, b, 145)?, 149)?;
+    // 149: add  #0, #26, [1009]
+    let a = 0;
+    let b = 26;
+    let address = 1009;
+    vm.write(address, vm.add(a, b, 149)?, 153)?;
+    // 153: mul  #36, #1, [1005]
+    let a = 36;
+    let b = 1;
+    let address = 1005;
+    vm.write(address, vm.multiply(a, b, 153)?, 157)?;
+    // 157: add  #27, #0, [1013]
+    let a = 27;
+    let b = 0;
+    let address = 1013;
+    vm.write(address, vm.add(a, b, 157)?, 161)?;
+    // 161: add  #35, #0, [1019]
+    let a = 35;
+    let b = 0;
+    let address = 1019;
+    vm.write(address, vm.add(a, b, 161)?, 165)?;
+    // 165: add  #38, #0, [1017]
+    let a = 38;
+    let b = 0;
+    let address = 1017;
+    vm.write(address, vm.add(a, b, 165)?, 169)?;
+    // 169: add  #0, #39, [1004]
+    let a = 0;
+    let b = 39;
+    let address = 1004;
+    vm.write(address, vm.add(a, b, 169)?, 173)?;
+    // 173: mul  #37, #1, [1002]
+    let a = 37;
+    let b = 1;
+    let address = 1002;
+    vm.write(address, vm.multiply(a, b, 173)?, 177)?;
+    // 177: mul  #33, #1, [1011]
+    let a = 33;
+    let b = 1;
+    let address = 1011;
+    vm.write(address, vm.multiply(a, b, 177)?, 181)?;
+    // 181: mul  #28, #1, [1016]
+    let a = 28;
+    let b = 1;
+    let address = 1016;
+    vm.write(address, vm.multiply(a, b, 181)?, 185)?;
+    // 185: arb  #1
+    vm.adjust_relative_base(1, 185)?;
+    // 187: eq   rb+5, #35, [63]
+    let a = vm.read_relative(5, 187)?;
+    let b = 35;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 191)?;
+    // 191: jt   [63], #201
+    if vm.read(63) != 0 {
+        return Ok(201);
+    }
+    // 194: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 194)?, 198)?;
+    // 198: jf   #0, #203
+    // 203: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 203)?, 207)?;
+    // 207: arb  #36
+    vm.adjust_relative_base(36, 207)?;
+    // 209: jf   #0, rb-9
+    vm.jump(vm.read_relative(-9, 209)?, 209)
+}
+
+fn block_194(vm: &mut Vm) -> Result<Address, Exit> {
+    // 194: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 194)?, 198)?;
+    // 198: jf   #0, #203
+    // 203: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 203)?, 207)?;
+    // 207: arb  #36
+    vm.adjust_relative_base(36, 207)?;
+    // 209: jf   #0, rb-9
+    vm.jump(vm.read_relative(-9, 209)?, 209)
+}
+
+fn block_201(vm: &mut Vm) -> Result<Address, Exit> {
+    // 201: out  [187]
+    Err(Exit::Output(vm.read(187), 203))
+}
+
+fn block_203(vm: &mut Vm) -> Result<Address, Exit> {
+    // 203: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 203)?, 207)?;
+    // 207: arb  #36
+    vm.adjust_relative_base(36, 207)?;
+    // 209: jf   #0, rb-9
+    vm.jump(vm.read_relative(-9, 209)?, 209)
+}
+
+fn block_212(vm: &mut Vm) -> Result<Address, Exit> {
+    // 212: out  [209]
+    Err(Exit::Output(vm.read(209), 214))
+}
+
+fn block_214(vm: &mut Vm) -> Result<Address, Exit> {
+    // 214: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 214)?, 218)?;
+    // 218: jt   #1, #221
+    // 221: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 221)?, 225)?;
+    // 225: arb  #-30
+    vm.adjust_relative_base(-30, 225)?;
+    // 227: add  #0, rb-4, [63]
+    let a = 0;
+    let b = vm.read_relative(-4, 227)?;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 227)?, 231)?;
+    // 231: eq   [63], #34, [63]
+    let a = vm.read(63);
+    let b = 34;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 235)?;
+    // 235: jt   [63], #247
+    if vm.read(63) != 0 {
+        return Ok(247);
+    }
+    // 238: out  [227]
+    Err(Exit::Output(vm.read(227), 240))
+}
+
+fn block_221(vm: &mut Vm) -> Result<Address, Exit> {
+    // 221: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 221)?, 225)?;
+    // 225: arb  #-30
+    vm.adjust_relative_base(-30, 225)?;
+    // 227: add  #0, rb-4, [63]
+    let a = 0;
+    let b = vm.read_relative(-4, 227)?;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 227)?, 231)?;
+    // 231: eq   [63], #34, [63]
+    let a = vm.read(63);
+    let b = 34;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 235)?;
+    // 235: jt   [63], #247
+    if vm.read(63) != 0 {
+        return Ok(247);
+    }
+    // 238: out  [227]
+    Err(Exit::Output(vm.read(227), 240))
+}
+
+fn block_238(vm: &mut Vm) -> Result<Address, Exit> {
+    // 238: out  [227]
+    Err(Exit::Output(vm.read(227), 240))
+}
+
+fn block_240(vm: &mut Vm) -> Result<Address, Exit> {
+    // 240: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 240)?, 244)?;
+    // 244: jt   #1, #247
+    // 247: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 247)?, 251)?;
+    // 251: arb  #1
+    vm.adjust_relative_base(1, 251)?;
+    // 253: eq   #40, #40, rb+8
+    let a = 40;
+    let b = 40;
+    let address = vm.relative_address(8, 253)?;
+    vm.write(address, (a == b) as intcode::Value, 257)?;
+    // 257: jt   [1016], #265
+    if vm.read(1016) != 0 {
+        return Ok(265);
+    }
+    // 260: out  [253]
+    Err(Exit::Output(vm.read(253), 262))
+}
+
+fn block_247(vm: &mut Vm) -> Result<Address, Exit> {
+    // 247: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 247)?, 251)?;
+    // 251: arb  #1
+    vm.adjust_relative_base(1, 251)?;
+    // 253: eq   #40, #40, rb+8
+    let a = 40;
+    let b = 40;
+    let address = vm.relative_address(8, 253)?;
+    vm.write(address, (a == b) as intcode::Value, 257)?;
+    // 257: jt   [1016], #265
+    if vm.read(1016) != 0 {
+        return Ok(265);
+    }
+    // 260: out  [253]
+    Err(Exit::Output(vm.read(253), 262))
+}
+
+fn block_260(vm: &mut Vm) -> Result<Address, Exit> {
+    // 260: out  [253]
+    Err(Exit::Output(vm.read(253), 262))
+}
+
+fn block_262(vm: &mut Vm) -> Result<Address, Exit> {
+    // 262: jf   #0, #269
+    // 269: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 269)?, 273)?;
+    // 273: arb  #10
+    vm.adjust_relative_base(10, 273)?;
+    // 275: add  #41, #0, rb-7
+    let a = 41;
+    let b = 0;
+    let address = vm.relative_address(-7, 275)?;
+    vm.write(address, vm.add(a, b, 275)?, 279)?;
+    // 279: eq   [1011], #41, [63]
+    let a = vm.read(1011);
+    let b = 41;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 283)?;
+    // 283: jt   [63], #295
+    if vm.read(63) != 0 {
+        return Ok(295);
+    }
+    // 286: out  [275]
+    Err(Exit::Output(vm.read(275), 288))
+}
+
+fn block_265(vm: &mut Vm) -> Result<Address, Exit> {
+    // 265: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 265)?, 269)?;
+    // 269: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 269)?, 273)?;
+    // 273: arb  #10
+    vm.adjust_relative_base(10, 273)?;
+    // 275: add  #41, #0, rb-7
+    let a = 41;
+    let b = 0;
+    let address = vm.relative_address(-7, 275)?;
+    vm.write(address, vm.add(a, b, 275)?, 279)?;
+    // 279: eq   [1011], #41, [63]
+    let a = vm.read(1011);
+    let b = 41;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 283)?;
+    // 283: jt   [63], #295
+    if vm.read(63) != 0 {
+        return Ok(295);
+    }
+    // 286: out  [275]
+    Err(Exit::Output(vm.read(275), 288))
+}
+
+fn block_269(vm: &mut Vm) -> Result<Address, Exit> {
+    // 269: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 269)?, 273)?;
+    // 273: arb  #10
+    vm.adjust_relative_base(10, 273)?;
+    // 275: add  #41, #0, rb-7
+    let a = 41;
+    let b = 0;
+    let address = vm.relative_address(-7, 275)?;
+    vm.write(address, vm.add(a, b, 275)?, 279)?;
+    // 279: eq   [1011], #41, [63]
+    let a = vm.read(1011);
+    let b = 41;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 283)?;
+    // 283: jt   [63], #295
+    if vm.read(63) != 0 {
+        return Ok(295);
+    }
+    // 286: out  [275]
+    Err(Exit::Output(vm.read(275), 288))
+}
+
+fn block_286(vm: &mut Vm) -> Result<Address, Exit> {
+    // 286: out  [275]
+    Err(Exit::Output(vm.read(275), 288))
+}
+
+fn block_288(vm: &mut Vm) -> Result<Address, Exit> {
+    // 288: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 288)?, 292)?;
+    // 292: jt   #1, #295
+    // 295: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 295)?, 299)?;
+    // 299: arb  #3
+    vm.adjust_relative_base(3, 299)?;
+    // 301: jt   #1, rb+3
+    vm.jump(vm.read_relative(3, 301)?, 301)
+}
+
+fn block_295(vm: &mut Vm) -> Result<Address, Exit> {
+    // 295: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 295)?, 299)?;
+    // 299: arb  #3
+    vm.adjust_relative_base(3, 299)?;
+    // 301: jt   #1, rb+3
+    vm.jump(vm.read_relative(3, 301)?, 301)
+}
+
+fn block_304(vm: &mut Vm) -> Result<Address, Exit> {
+    // 304: out  [301]
+    Err(Exit::Output(vm.read(301), 306))
+}
+
+fn block_306(vm: &mut Vm) -> Result<Address, Exit> {
+    // 306: jf   #0, #313
+    // 313: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 313)?, 317)?;
+    // 317: arb  #-18
+    vm.adjust_relative_base(-18, 317)?;
+    // 319: eq   #38, rb+1, [63]
+    let a = 38;
+    let b = vm.read_relative(1, 319)?;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 323)?;
+    // 323: jt   [63], #329
+    if vm.read(63) != 0 {
+        return Ok(329);
+    }
+    // 326: jt   #1, #335
+    // 335: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 335)?, 339)?;
+    // 339: arb  #-11
+    vm.adjust_relative_base(-11, 339)?;
+    // 341: eq   #37, rb+10, [63]
+    let a = 37;
+    let b = vm.read_relative(10, 341)?;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 345)?;
+    // 345: jt   [63], #357
+    if vm.read(63) != 0 {
+        return Ok(357);
+    }
+    // 348: out  [341]
+    Err(Exit::Output(vm.read(341), 350))
+}
+
+fn block_309(vm: &mut Vm) -> Result<Address, Exit> {
+    // 309: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 309)?, 313)?;
+    // 313: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 313)?, 317)?;
+    // 317: arb  #-18
+    vm.adjust_relative_base(-18, 317)?;
+    // 319: eq   #38, rb+1, [63]
+    let a = 38;
+    let b = vm.read_relative(1, 319)?;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 323)?;
+    // 323: jt   [63], #329
+    if vm.read(63) != 0 {
+        return Ok(329);
+    }
+    // 326: jt   #1, #335
+    // 335: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 335)?, 339)?;
+    // 339: arb  #-11
+    vm.adjust_relative_base(-11, 339)?;
+    // 341: eq   #37, rb+10, [63]
+    let a = 37;
+    let b = vm.read_relative(10, 341)?;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 345)?;
+    // 345: jt   [63], #357
+    if vm.read(63) != 0 {
+        return Ok(357);
+    }
+    // 348: out  [341]
+    Err(Exit::Output(vm.read(341), 350))
+}
+
+fn block_313(vm: &mut Vm) -> Result<Address, Exit> {
+    // 313: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 313)?, 317)?;
+    // 317: arb  #-18
+    vm.adjust_relative_base(-18, 317)?;
+    // 319: eq   #38, rb+1, [63]
+    let a = 38;
+    let b = vm.read_relative(1, 319)?;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 323)?;
+    // 323: jt   [63], #329
+    if vm.read(63) != 0 {
+        return Ok(329);
+    }
+    // 326: jt   #1, #335
+    // 335: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 335)?, 339)?;
+    // 339: arb  #-11
+    vm.adjust_relative_base(-11, 339)?;
+    // 341: eq   #37, rb+10, [63]
+    let a = 37;
+    let b = vm.read_relative(10, 341)?;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 345)?;
+    // 345: jt   [63], #357
+    if vm.read(63) != 0 {
+        return Ok(357);
+    }
+    // 348: out  [341]
+    Err(Exit::Output(vm.read(341), 350))
+}
+
+fn block_326(vm: &mut Vm) -> Result<Address, Exit> {
+    // 326: jt   #1, #335
+    // 335: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 335)?, 339)?;
+    // 339: arb  #-11
+    vm.adjust_relative_base(-11, 339)?;
+    // 341: eq   #37, rb+10, [63]
+    let a = 37;
+    let b = vm.read_relative(10, 341)?;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 345)?;
+    // 345: jt   [63], #357
+    if vm.read(63) != 0 {
+        return Ok(357);
+    }
+    // 348: out  [341]
+    Err(Exit::Output(vm.read(341), 350))
+}
+
+fn block_329(vm: &mut Vm) -> Result<Address, Exit> {
+    // 329: out  [319]
+    Err(Exit::Output(vm.read(319), 331))
+}
+
+fn block_331(vm: &mut Vm) -> Result<Address, Exit> {
+    // 331: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 331)?, 335)?;
+    // 335: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 335)?, 339)?;
+    // 339: arb  #-11
+    vm.adjust_relative_base(-11, 339)?;
+    // 341: eq   #37, rb+10, [63]
+    let a = 37;
+    let b = vm.read_relative(10, 341)?;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 345)?;
+    // 345: jt   [63], #357
+    if vm.read(63) != 0 {
+        return Ok(357);
+    }
+    // 348: out  [341]
+    Err(Exit::Output(vm.read(341), 350))
+}
+
+fn block_335(vm: &mut Vm) -> Result<Address, Exit> {
+    // 335: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 335)?, 339)?;
+    // 339: arb  #-11
+    vm.adjust_relative_base(-11, 339)?;
+    // 341: eq   #37, rb+10, [63]
+    let a = 37;
+    let b = vm.read_relative(10, 341)?;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 345)?;
+    // 345: jt   [63], #357
+    if vm.read(63) != 0 {
+        return Ok(357);
+    }
+    // 348: out  [341]
+    Err(Exit::Output(vm.read(341), 350))
+}
+
+fn block_348(vm: &mut Vm) -> Result<Address, Exit> {
+    // 348: out  [341]
+    Err(Exit::Output(vm.read(341), 350))
+}
+
+fn block_350(vm: &mut Vm) -> Result<Address, Exit> {
+    // 350: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 350)?, 354)?;
+    // 354: jf   #0, #357
+    // 357: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 357)?, 361)?;
+    // 361: arb  #25
+    vm.adjust_relative_base(25, 361)?;
+    // 363: lt   #42, #41, rb-6
+    let a = 42;
+    let b = 41;
+    let address = vm.relative_address(-6, 363)?;
+    vm.write(address, (a < b) as intcode::Value, 367)?;
+    // 367: jt   [1011], #377
+    if vm.read(1011) != 0 {
+        return Ok(377);
+    }
+    // 370: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 370)?, 374)?;
+    // 374: jf   #0, #379
+    // 379: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 379)?, 383)?;
+    // 383: arb  #-11
+    vm.adjust_relative_base(-11, 383)?;
+    // 385: lt   rb+3, #25, [63]
+    let a = vm.read_relative(3, 385)?;
+    let b = 25;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 389)?;
+    // 389: jt   [63], #395
+    if vm.read(63) != 0 {
+        return Ok(395);
+    }
+    // 392: jt   #1, #401
+    // 401: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 401)?, 405)?;
+    // 405: arb  #-4
+    vm.adjust_relative_base(-4, 405)?;
+    // 407: mul  rb+0, #1, [63]
+    let a = vm.read_relative(0, 407)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 407)?, 411)?;
+    // 411: eq   [63], #37, [63]
+    let a = vm.read(63);
+    let b = 37;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 415)?;
+    // 415: jt   [63], #423
+    if vm.read(63) != 0 {
+        return Ok(423);
+    }
+    // 418: out  [407]
+    Err(Exit::Output(vm.read(407), 420))
+}
+
+fn block_357(vm: &mut Vm) -> Result<Address, Exit> {
+    // 357: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 357)?, 361)?;
+    // 361: arb  #25
+    vm.adjust_relative_base(25, 361)?;
+    // 363: lt   #42, #41, rb-6
+    let a = 42;
+    let b = 41;
+    let address = vm.relative_address(-6, 363)?;
+    vm.write(address, (a < b) as intcode::Value, 367)?;
+    // 367: jt   [1011], #377
+    if vm.read(1011) != 0 {
+        return Ok(377);
+    }
+    // 370: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 370)?, 374)?;
+    // 374: jf   #0, #379
+    // 379: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 379)?, 383)?;
+    // 383: arb  #-11
+    vm.adjust_relative_base(-11, 383)?;
+    // 385: lt   rb+3, #25, [63]
+    let a = vm.read_relative(3, 385)?;
+    let b = 25;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 389)?;
+    // 389: jt   [63], #395
+    if vm.read(63) != 0 {
+        return Ok(395);
+    }
+    // 392: jt   #1, #401
+    // 401: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 401)?, 405)?;
+    // 405: arb  #-4
+    vm.adjust_relative_base(-4, 405)?;
+    // 407: mul  rb+0, #1, [63]
+    let a = vm.read_relative(0, 407)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 407)?, 411)?;
+    // 411: eq   [63], #37, [63]
+    let a = vm.read(63);
+    let b = 37;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 415)?;
+    // 415: jt   [63], #423
+    if vm.read(63) != 0 {
+        return Ok(423);
+    }
+    // 418: out  [407]
+    Err(Exit::Output(vm.read(407), 420))
+}
+
+fn block_370(vm: &mut Vm) -> Result<Address, Exit> {
+    // 370: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 370)?, 374)?;
+    // 374: jf   #0, #379
+    // 379: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 379)?, 383)?;
+    // 383: arb  #-11
+    vm.adjust_relative_base(-11, 383)?;
+    // 385: lt   rb+3, #25, [63]
+    let a = vm.read_relative(3, 385)?;
+    let b = 25;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 389)?;
+    // 389: jt   [63], #395
+    if vm.read(63) != 0 {
+        return Ok(395);
+    }
+    // 392: jt   #1, #401
+    // 401: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 401)?, 405)?;
+    // 405: arb  #-4
+    vm.adjust_relative_base(-4, 405)?;
+    // 407: mul  rb+0, #1, [63]
+    let a = vm.read_relative(0, 407)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 407)?, 411)?;
+    // 411: eq   [63], #37, [63]
+    let a = vm.read(63);
+    let b = 37;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 415)?;
+    // 415: jt   [63], #423
+    if vm.read(63) != 0 {
+        return Ok(423);
+    }
+    // 418: out  [407]
+    Err(Exit::Output(vm.read(407), 420))
+}
+
+fn block_377(vm: &mut Vm) -> Result<Address, Exit> {
+    // 377: out  [363]
+    Err(Exit::Output(vm.read(363), 379))
+}
+
+fn block_379(vm: &mut Vm) -> Result<Address, Exit> {
+    // 379: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 379)?, 383)?;
+    // 383: arb  #-11
+    vm.adjust_relative_base(-11, 383)?;
+    // 385: lt   rb+3, #25, [63]
+    let a = vm.read_relative(3, 385)?;
+    let b = 25;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 389)?;
+    // 389: jt   [63], #395
+    if vm.read(63) != 0 {
+        return Ok(395);
+    }
+    // 392: jt   #1, #401
+    // 401: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 401)?, 405)?;
+    // 405: arb  #-4
+    vm.adjust_relative_base(-4, 405)?;
+    // 407: mul  rb+0, #1, [63]
+    let a = vm.read_relative(0, 407)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 407)?, 411)?;
+    // 411: eq   [63], #37, [63]
+    let a = vm.read(63);
+    let b = 37;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 415)?;
+    // 415: jt   [63], #423
+    if vm.read(63) != 0 {
+        return Ok(423);
+    }
+    // 418: out  [407]
+    Err(Exit::Output(vm.read(407), 420))
+}
+
+fn block_392(vm: &mut Vm) -> Result<Address, Exit> {
+    // 392: jt   #1, #401
+    // 401: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 401)?, 405)?;
+    // 405: arb  #-4
+    vm.adjust_relative_base(-4, 405)?;
+    // 407: mul  rb+0, #1, [63]
+    let a = vm.read_relative(0, 407)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 407)?, 411)?;
+    // 411: eq   [63], #37, [63]
+    let a = vm.read(63);
+    let b = 37;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 415)?;
+    // 415: jt   [63], #423
+    if vm.read(63) != 0 {
+        return Ok(423);
+    }
+    // 418: out  [407]
+    Err(Exit::Output(vm.read(407), 420))
+}
+
+fn block_395(vm: &mut Vm) -> Result<Address, Exit> {
+    // 395: out  [385]
+    Err(Exit::Output(vm.read(385), 397))
+}
+
+fn block_397(vm: &mut Vm) -> Result<Address, Exit> {
+    // 397: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 397)?, 401)?;
+    // 401: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 401)?, 405)?;
+    // 405: arb  #-4
+    vm.adjust_relative_base(-4, 405)?;
+    // 407: mul  rb+0, #1, [63]
+    let a = vm.read_relative(0, 407)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 407)?, 411)?;
+    // 411: eq   [63], #37, [63]
+    let a = vm.read(63);
+    let b = 37;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 415)?;
+    // 415: jt   [63], #423
+    if vm.read(63) != 0 {
+        return Ok(423);
+    }
+    // 418: out  [407]
+    Err(Exit::Output(vm.read(407), 420))
+}
+
+fn block_401(vm: &mut Vm) -> Result<Address, Exit> {
+    // 401: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 401)?, 405)?;
+    // 405: arb  #-4
+    vm.adjust_relative_base(-4, 405)?;
+    // 407: mul  rb+0, #1, [63]
+    let a = vm.read_relative(0, 407)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 407)?, 411)?;
+    // 411: eq   [63], #37, [63]
+    let a = vm.read(63);
+    let b = 37;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 415)?;
+    // 415: jt   [63], #423
+    if vm.read(63) != 0 {
+        return Ok(423);
+    }
+    // 418: out  [407]
+    Err(Exit::Output(vm.read(407), 420))
+}
+
+fn block_418(vm: &mut Vm) -> Result<Address, Exit> {
+    // 418: out  [407]
+    Err(Exit::Output(vm.read(407), 420))
+}
+
+fn block_420(vm: &mut Vm) -> Result<Address, Exit> {
+    // 420: jt   #1, #427
+    // 427: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 427)?, 431)?;
+    // 431: arb  #8
+    vm.adjust_relative_base(8, 431)?;
+    // 433: mul  #43, #1, rb+6
+    let a = 43;
+    let b = 1;
+    let address = vm.relative_address(6, 433)?;
+    vm.write(address, vm.multiply(a, b, 433)?, 437)?;
+    // 437: eq   [1016], #43, [63]
+    let a = vm.read(1016);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 441)?;
+    // 441: jt   [63], #453
+    if vm.read(63) != 0 {
+        return Ok(453);
+    }
+    // 444: out  [433]
+    Err(Exit::Output(vm.read(433), 446))
+}
+
+fn block_423(vm: &mut Vm) -> Result<Address, Exit> {
+    // 423: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 423)?, 427)?;
+    // 427: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 427)?, 431)?;
+    // 431: arb  #8
+    vm.adjust_relative_base(8, 431)?;
+    // 433: mul  #43, #1, rb+6
+    let a = 43;
+    let b = 1;
+    let address = vm.relative_address(6, 433)?;
+    vm.write(address, vm.multiply(a, b, 433)?, 437)?;
+    // 437: eq   [1016], #43, [63]
+    let a = vm.read(1016);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 441)?;
+    // 441: jt   [63], #453
+    if vm.read(63) != 0 {
+        return Ok(453);
+    }
+    // 444: out  [433]
+    Err(Exit::Output(vm.read(433), 446))
+}
+
+fn block_427(vm: &mut Vm) -> Result<Address, Exit> {
+    // 427: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 427)?, 431)?;
+    // 431: arb  #8
+    vm.adjust_relative_base(8, 431)?;
+    // 433: mul  #43, #1, rb+6
+    let a = 43;
+    let b = 1;
+    let address = vm.relative_address(6, 433)?;
+    vm.write(address, vm.multiply(a, b, 433)?, 437)?;
+    // 437: eq   [1016], #43, [63]
+    let a = vm.read(1016);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 441)?;
+    // 441: jt   [63], #453
+    if vm.read(63) != 0 {
+        return Ok(453);
+    }
+    // 444: out  [433]
+    Err(Exit::Output(vm.read(433), 446))
+}
+
+fn block_444(vm: &mut Vm) -> Result<Address, Exit> {
+    // 444: out  [433]
+    Err(Exit::Output(vm.read(433), 446))
+}
+
+fn block_446(vm: &mut Vm) -> Result<Address, Exit> {
+    // 446: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 446)?, 450)?;
+    // 450: jf   #0, #453
+    // 453: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 453)?, 457)?;
+    // 457: arb  #-11
+    vm.adjust_relative_base(-11, 457)?;
+    // 459: eq   rb+6, #36, [63]
+    let a = vm.read_relative(6, 459)?;
+    let b = 36;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 463)?;
+    // 463: jt   [63], #471
+    if vm.read(63) != 0 {
+        return Ok(471);
+    }
+    // 466: out  [459]
+    Err(Exit::Output(vm.read(459), 468))
+}
+
+fn block_453(vm: &mut Vm) -> Result<Address, Exit> {
+    // 453: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 453)?, 457)?;
+    // 457: arb  #-11
+    vm.adjust_relative_base(-11, 457)?;
+    // 459: eq   rb+6, #36, [63]
+    let a = vm.read_relative(6, 459)?;
+    let b = 36;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 463)?;
+    // 463: jt   [63], #471
+    if vm.read(63) != 0 {
+        return Ok(471);
+    }
+    // 466: out  [459]
+    Err(Exit::Output(vm.read(459), 468))
+}
+
+fn block_466(vm: &mut Vm) -> Result<Address, Exit> {
+    // 466: out  [459]
+    Err(Exit::Output(vm.read(459), 468))
+}
+
+fn block_468(vm: &mut Vm) -> Result<Address, Exit> {
+    // 468: jt   #1, #475
+    // 475: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 475)?, 479)?;
+    // 479: arb  #21
+    vm.adjust_relative_base(21, 479)?;
+    // 481: jt   #1, rb+3
+    vm.jump(vm.read_relative(3, 481)?, 481)
+}
+
+fn block_471(vm: &mut Vm) -> Result<Address, Exit> {
+    // 471: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 471)?, 475)?;
+    // 475: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 475)?, 479)?;
+    // 479: arb  #21
+    vm.adjust_relative_base(21, 479)?;
+    // 481: jt   #1, rb+3
+    vm.jump(vm.read_relative(3, 481)?, 481)
+}
+
+fn block_475(vm: &mut Vm) -> Result<Address, Exit> {
+    // 475: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 475)?, 479)?;
+    // 479: arb  #21
+    vm.adjust_relative_base(21, 479)?;
+    // 481: jt   #1, rb+3
+    vm.jump(vm.read_relative(3, 481)?, 481)
+}
+
+fn block_484(vm: &mut Vm) -> Result<Address, Exit> {
+    // 484: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 484)?, 488)?;
+    // 488: jt   #1, #493
+    // 493: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 493)?, 497)?;
+    // 497: arb  #-15
+    vm.adjust_relative_base(-15, 497)?;
+    // 499: lt   #22, rb+3, [63]
+    let a = 22;
+    let b = vm.read_relative(3, 499)?;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 503)?;
+    // 503: jt   [63], #513
+    if vm.read(63) != 0 {
+        return Ok(513);
+    }
+    // 506: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 506)?, 510)?;
+    // 510: jf   #0, #515
+    // 515: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 515)?, 519)?;
+    // 519: arb  #-7
+    vm.adjust_relative_base(-7, 519)?;
+    // 521: lt   #35, rb+7, [63]
+    let a = 35;
+    let b = vm.read_relative(7, 521)?;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 525)?;
+    // 525: jt   [63], #537
+    if vm.read(63) != 0 {
+        return Ok(537);
+    }
+    // 528: out  [521]
+    Err(Exit::Output(vm.read(521), 530))
+}
+
+fn block_491(vm: &mut Vm) -> Result<Address, Exit> {
+    // 491: out  [481]
+    Err(Exit::Output(vm.read(481), 493))
+}
+
+fn block_493(vm: &mut Vm) -> Result<Address, Exit> {
+    // 493: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 493)?, 497)?;
+    // 497: arb  #-15
+    vm.adjust_relative_base(-15, 497)?;
+    // 499: lt   #22, rb+3, [63]
+    let a = 22;
+    let b = vm.read_relative(3, 499)?;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 503)?;
+    // 503: jt   [63], #513
+    if vm.read(63) != 0 {
+        return Ok(513);
+    }
+    // 506: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 506)?, 510)?;
+    // 510: jf   #0, #515
+    // 515: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 515)?, 519)?;
+    // 519: arb  #-7
+    vm.adjust_relative_base(-7, 519)?;
+    // 521: lt   #35, rb+7, [63]
+    let a = 35;
+    let b = vm.read_relative(7, 521)?;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 525)?;
+    // 525: jt   [63], #537
+    if vm.read(63) != 0 {
+        return Ok(537);
+    }
+    // 528: out  [521]
+    Err(Exit::Output(vm.read(521), 530))
+}
+
+fn block_506(vm: &mut Vm) -> Result<Address, Exit> {
+    // 506: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 506)?, 510)?;
+    // 510: jf   #0, #515
+    // 515: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 515)?, 519)?;
+    // 519: arb  #-7
+    vm.adjust_relative_base(-7, 519)?;
+    // 521: lt   #35, rb+7, [63]
+    let a = 35;
+    let b = vm.read_relative(7, 521)?;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 525)?;
+    // 525: jt   [63], #537
+    if vm.read(63) != 0 {
+        return Ok(537);
+    }
+    // 528: out  [521]
+    Err(Exit::Output(vm.read(521), 530))
+}
+
+fn block_513(vm: &mut Vm) -> Result<Address, Exit> {
+    // 513: out  [499]
+    Err(Exit::Output(vm.read(499), 515))
+}
+
+fn block_515(vm: &mut Vm) -> Result<Address, Exit> {
+    // 515: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 515)?, 519)?;
+    // 519: arb  #-7
+    vm.adjust_relative_base(-7, 519)?;
+    // 521: lt   #35, rb+7, [63]
+    let a = 35;
+    let b = vm.read_relative(7, 521)?;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 525)?;
+    // 525: jt   [63], #537
+    if vm.read(63) != 0 {
+        return Ok(537);
+    }
+    // 528: out  [521]
+    Err(Exit::Output(vm.read(521), 530))
+}
+
+fn block_528(vm: &mut Vm) -> Result<Address, Exit> {
+    // 528: out  [521]
+    Err(Exit::Output(vm.read(521), 530))
+}
+
+fn block_530(vm: &mut Vm) -> Result<Address, Exit> {
+    // 530: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 530)?, 534)?;
+    // 534: jt   #1, #537
+    // 537: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 537)?, 541)?;
+    // 541: arb  #23
+    vm.adjust_relative_base(23, 541)?;
+    // 543: jt   rb+0, #551
+    if vm.read_relative(0, 543)? != 0 {
+        return Ok(551);
+    }
+    // 546: out  [543]
+    Err(Exit::Output(vm.read(543), 548))
+}
+
+fn block_537(vm: &mut Vm) -> Result<Address, Exit> {
+    // 537: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 537)?, 541)?;
+    // 541: arb  #23
+    vm.adjust_relative_base(23, 541)?;
+    // 543: jt   rb+0, #551
+    if vm.read_relative(0, 543)? != 0 {
+        return Ok(551);
+    }
+    // 546: out  [543]
+    Err(Exit::Output(vm.read(543), 548))
+}
+
+fn block_546(vm: &mut Vm) -> Result<Address, Exit> {
+    // 546: out  [543]
+    Err(Exit::Output(vm.read(543), 548))
+}
+
+fn block_548(vm: &mut Vm) -> Result<Address, Exit> {
+    // 548: jt   #1, #555
+    // 555: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 555)?, 559)?;
+    // 559: arb  #-4
+    vm.adjust_relative_base(-4, 559)?;
+    // 561: add  #44, #0, rb-3
+    let a = 44;
+    let b = 0;
+    let address = vm.relative_address(-3, 561)?;
+    vm.write(address, vm.add(a, b, 561)?, 565)?;
+    // 565: eq   [1014], #45, [63]
+    let a = vm.read(1014);
+    let b = 45;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 569)?;
+    // 569: jt   [63], #579
+    if vm.read(63) != 0 {
+        return Ok(579);
+    }
+    // 572: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 572)?, 576)?;
+    // 576: jt   #1, #581
+    // 581: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 581)?, 585)?;
+    // 585: arb  #-15
+    vm.adjust_relative_base(-15, 585)?;
+    // 587: mul  #1, rb+3, [63]
+    let a = 1;
+    let b = vm.read_relative(3, 587)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 587)?, 591)?;
+    // 591: eq   [63], #33, [63]
+    let a = vm.read(63);
+    let b = 33;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 595)?;
+    // 595: jt   [63], #601
+    if vm.read(63) != 0 {
+        return Ok(601);
+    }
+    // 598: jf   #0, #607
+    // 607: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 607)?, 611)?;
+    // 611: arb  #23
+    vm.adjust_relative_base(23, 611)?;
+    // 613: jt   rb-5, #623
+    if vm.read_relative(-5, 613)? != 0 {
+        return Ok(623);
+    }
+    // 616: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 616)?, 620)?;
+    // 620: jf   #0, #625
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    Ok(642)
+}
+
+fn block_551(vm: &mut Vm) -> Result<Address, Exit> {
+    // 551: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 551)?, 555)?;
+    // 555: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 555)?, 559)?;
+    // 559: arb  #-4
+    vm.adjust_relative_base(-4, 559)?;
+    // 561: add  #44, #0, rb-3
+    let a = 44;
+    let b = 0;
+    let address = vm.relative_address(-3, 561)?;
+    vm.write(address, vm.add(a, b, 561)?, 565)?;
+    // 565: eq   [1014], #45, [63]
+    let a = vm.read(1014);
+    let b = 45;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 569)?;
+    // 569: jt   [63], #579
+    if vm.read(63) != 0 {
+        return Ok(579);
+    }
+    // 572: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 572)?, 576)?;
+    // 576: jt   #1, #581
+    // 581: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 581)?, 585)?;
+    // 585: arb  #-15
+    vm.adjust_relative_base(-15, 585)?;
+    // 587: mul  #1, rb+3, [63]
+    let a = 1;
+    let b = vm.read_relative(3, 587)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 587)?, 591)?;
+    // 591: eq   [63], #33, [63]
+    let a = vm.read(63);
+    let b = 33;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 595)?;
+    // 595: jt   [63], #601
+    if vm.read(63) != 0 {
+        return Ok(601);
+    }
+    // 598: jf   #0, #607
+    // 607: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 607)?, 611)?;
+    // 611: arb  #23
+    vm.adjust_relative_base(23, 611)?;
+    // 613: jt   rb-5, #623
+    if vm.read_relative(-5, 613)? != 0 {
+        return Ok(623);
+    }
+    // 616: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 616)?, 620)?;
+    // 620: jf   #0, #625
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    Ok(642)
+}
+
+fn block_555(vm: &mut Vm) -> Result<Address, Exit> {
+    // 555: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 555)?, 559)?;
+    // 559: arb  #-4
+    vm.adjust_relative_base(-4, 559)?;
+    // 561: add  #44, #0, rb-3
+    let a = 44;
+    let b = 0;
+    let address = vm.relative_address(-3, 561)?;
+    vm.write(address, vm.add(a, b, 561)?, 565)?;
+    // 565: eq   [1014], #45, [63]
+    let a = vm.read(1014);
+    let b = 45;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 569)?;
+    // 569: jt   [63], #579
+    if vm.read(63) != 0 {
+        return Ok(579);
+    }
+    // 572: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 572)?, 576)?;
+    // 576: jt   #1, #581
+    // 581: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 581)?, 585)?;
+    // 585: arb  #-15
+    vm.adjust_relative_base(-15, 585)?;
+    // 587: mul  #1, rb+3, [63]
+    let a = 1;
+    let b = vm.read_relative(3, 587)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 587)?, 591)?;
+    // 591: eq   [63], #33, [63]
+    let a = vm.read(63);
+    let b = 33;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 595)?;
+    // 595: jt   [63], #601
+    if vm.read(63) != 0 {
+        return Ok(601);
+    }
+    // 598: jf   #0, #607
+    // 607: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 607)?, 611)?;
+    // 611: arb  #23
+    vm.adjust_relative_base(23, 611)?;
+    // 613: jt   rb-5, #623
+    if vm.read_relative(-5, 613)? != 0 {
+        return Ok(623);
+    }
+    // 616: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 616)?, 620)?;
+    // 620: jf   #0, #625
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    // 642: jt   #1, #651
+    Ok(651)
+}
+
+fn block_572(vm: &mut Vm) -> Result<Address, Exit> {
+    // 572: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 572)?, 576)?;
+    // 576: jt   #1, #581
+    // 581: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 581)?, 585)?;
+    // 585: arb  #-15
+    vm.adjust_relative_base(-15, 585)?;
+    // 587: mul  #1, rb+3, [63]
+    let a = 1;
+    let b = vm.read_relative(3, 587)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 587)?, 591)?;
+    // 591: eq   [63], #33, [63]
+    let a = vm.read(63);
+    let b = 33;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 595)?;
+    // 595: jt   [63], #601
+    if vm.read(63) != 0 {
+        return Ok(601);
+    }
+    // 598: jf   #0, #607
+    // 607: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 607)?, 611)?;
+    // 611: arb  #23
+    vm.adjust_relative_base(23, 611)?;
+    // 613: jt   rb-5, #623
+    if vm.read_relative(-5, 613)? != 0 {
+        return Ok(623);
+    }
+    // 616: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 616)?, 620)?;
+    // 620: jf   #0, #625
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    // 642: jt   #1, #651
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    Ok(668)
+}
+
+fn block_579(vm: &mut Vm) -> Result<Address, Exit> {
+    // 579: out  [561]
+    Err(Exit::Output(vm.read(561), 581))
+}
+
+fn block_581(vm: &mut Vm) -> Result<Address, Exit> {
+    // 581: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 581)?, 585)?;
+    // 585: arb  #-15
+    vm.adjust_relative_base(-15, 585)?;
+    // 587: mul  #1, rb+3, [63]
+    let a = 1;
+    let b = vm.read_relative(3, 587)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 587)?, 591)?;
+    // 591: eq   [63], #33, [63]
+    let a = vm.read(63);
+    let b = 33;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 595)?;
+    // 595: jt   [63], #601
+    if vm.read(63) != 0 {
+        return Ok(601);
+    }
+    // 598: jf   #0, #607
+    // 607: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 607)?, 611)?;
+    // 611: arb  #23
+    vm.adjust_relative_base(23, 611)?;
+    // 613: jt   rb-5, #623
+    if vm.read_relative(-5, 613)? != 0 {
+        return Ok(623);
+    }
+    // 616: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 616)?, 620)?;
+    // 620: jf   #0, #625
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    // 642: jt   #1, #651
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_598(vm: &mut Vm) -> Result<Address, Exit> {
+    // 598: jf   #0, #607
+    // 607: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 607)?, 611)?;
+    // 611: arb  #23
+    vm.adjust_relative_base(23, 611)?;
+    // 613: jt   rb-5, #623
+    if vm.read_relative(-5, 613)? != 0 {
+        return Ok(623);
+    }
+    // 616: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 616)?, 620)?;
+    // 620: jf   #0, #625
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    // 642: jt   #1, #651
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_601(vm: &mut Vm) -> Result<Address, Exit> {
+    // 601: out  [587]
+    Err(Exit::Output(vm.read(587), 603))
+}
+
+fn block_603(vm: &mut Vm) -> Result<Address, Exit> {
+    // 603: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 603)?, 607)?;
+    // 607: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 607)?, 611)?;
+    // 611: arb  #23
+    vm.adjust_relative_base(23, 611)?;
+    // 613: jt   rb-5, #623
+    if vm.read_relative(-5, 613)? != 0 {
+        return Ok(623);
+    }
+    // 616: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 616)?, 620)?;
+    // 620: jf   #0, #625
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    // 642: jt   #1, #651
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_607(vm: &mut Vm) -> Result<Address, Exit> {
+    // 607: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 607)?, 611)?;
+    // 611: arb  #23
+    vm.adjust_relative_base(23, 611)?;
+    // 613: jt   rb-5, #623
+    if vm.read_relative(-5, 613)? != 0 {
+        return Ok(623);
+    }
+    // 616: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 616)?, 620)?;
+    // 620: jf   #0, #625
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    // 642: jt   #1, #651
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_616(vm: &mut Vm) -> Result<Address, Exit> {
+    // 616: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 616)?, 620)?;
+    // 620: jf   #0, #625
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    // 642: jt   #1, #651
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_623(vm: &mut Vm) -> Result<Address, Exit> {
+    // 623: out  [613]
+    Err(Exit::Output(vm.read(613), 625))
+}
+
+fn block_625(vm: &mut Vm) -> Result<Address, Exit> {
+    // 625: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 625)?, 629)?;
+    // 629: arb  #-7
+    vm.adjust_relative_base(-7, 629)?;
+    // 631: mul  #45, #1, rb-8
+    let a = 45;
+    let b = 1;
+    let address = vm.relative_address(-8, 631)?;
+    vm.write(address, vm.multiply(a, b, 631)?, 635)?;
+    // 635: eq   [1010], #43, [63]
+    let a = vm.read(1010);
+    let b = 43;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 639)?;
+    // 639: jt   [63], #645
+    if vm.read(63) != 0 {
+        return Ok(645);
+    }
+    // 642: jt   #1, #651
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_642(vm: &mut Vm) -> Result<Address, Exit> {
+    // 642: jt   #1, #651
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_645(vm: &mut Vm) -> Result<Address, Exit> {
+    // 645: out  [631]
+    Err(Exit::Output(vm.read(631), 647))
+}
+
+fn block_647(vm: &mut Vm) -> Result<Address, Exit> {
+    // 647: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 647)?, 651)?;
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_651(vm: &mut Vm) -> Result<Address, Exit> {
+    // 651: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 651)?, 655)?;
+    // 655: arb  #-11
+    vm.adjust_relative_base(-11, 655)?;
+    // 657: mul  #1, rb+1, [63]
+    let a = 1;
+    let b = vm.read_relative(1, 657)?;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 657)?, 661)?;
+    // 661: eq   [63], #21, [63]
+    let a = vm.read(63);
+    let b = 21;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 665)?;
+    // 665: jt   [63], #677
+    if vm.read(63) != 0 {
+        return Ok(677);
+    }
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_668(vm: &mut Vm) -> Result<Address, Exit> {
+    // 668: out  [657]
+    Err(Exit::Output(vm.read(657), 670))
+}
+
+fn block_670(vm: &mut Vm) -> Result<Address, Exit> {
+    // 670: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 670)?, 674)?;
+    // 674: jf   #0, #677
+    // 677: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 677)?, 681)?;
+    // 681: arb  #3
+    vm.adjust_relative_base(3, 681)?;
+    // 683: lt   #46, #47, rb+4
+    let a = 46;
+    let b = 47;
+    let address = vm.relative_address(4, 683)?;
+    vm.write(address, (a < b) as intcode::Value, 687)?;
+    // 687: jt   [1014], #695
+    if vm.read(1014) != 0 {
+        return Ok(695);
+    }
+    // 690: out  [683]
+    Err(Exit::Output(vm.read(683), 692))
+}
+
+fn block_677(vm: &mut Vm) -> Result<Address, Exit> {
+    // 677: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 677)?, 681)?;
+    // 681: arb  #3
+    vm.adjust_relative_base(3, 681)?;
+    // 683: lt   #46, #47, rb+4
+    let a = 46;
+    let b = 47;
+    let address = vm.relative_address(4, 683)?;
+    vm.write(address, (a < b) as intcode::Value, 687)?;
+    // 687: jt   [1014], #695
+    if vm.read(1014) != 0 {
+        return Ok(695);
+    }
+    // 690: out  [683]
+    Err(Exit::Output(vm.read(683), 692))
+}
+
+fn block_690(vm: &mut Vm) -> Result<Address, Exit> {
+    // 690: out  [683]
+    Err(Exit::Output(vm.read(683), 692))
+}
+
+fn block_692(vm: &mut Vm) -> Result<Address, Exit> {
+    // 692: jf   #0, #699
+    // 699: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 699)?, 703)?;
+    // 703: arb  #7
+    vm.adjust_relative_base(7, 703)?;
+    // 705: eq   #47, #48, rb-4
+    let a = 47;
+    let b = 48;
+    let address = vm.relative_address(-4, 705)?;
+    vm.write(address, (a == b) as intcode::Value, 709)?;
+    // 709: jt   [1013], #715
+    if vm.read(1013) != 0 {
+        return Ok(715);
+    }
+    // 712: jf   #0, #721
+    // 721: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 721)?, 725)?;
+    // 725: arb  #-14
+    vm.adjust_relative_base(-14, 725)?;
+    // 727: add  rb+0, #0, [63]
+    let a = vm.read_relative(0, 727)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 727)?, 731)?;
+    // 731: eq   [63], #32, [63]
+    let a = vm.read(63);
+    let b = 32;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 735)?;
+    // 735: jt   [63], #741
+    if vm.read(63) != 0 {
+        return Ok(741);
+    }
+    // 738: jf   #0, #747
+    // 747: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 747)?, 751)?;
+    // 751: arb  #4
+    vm.adjust_relative_base(4, 751)?;
+    // 753: add  rb+2, #0, [63]
+    let a = vm.read_relative(2, 753)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 753)?, 757)?;
+    // 757: eq   [63], #26, [63]
+    let a = vm.read(63);
+    let b = 26;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 761)?;
+    // 761: jt   [63], #769
+    if vm.read(63) != 0 {
+        return Ok(769);
+    }
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_695(vm: &mut Vm) -> Result<Address, Exit> {
+    // 695: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 695)?, 699)?;
+    // 699: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 699)?, 703)?;
+    // 703: arb  #7
+    vm.adjust_relative_base(7, 703)?;
+    // 705: eq   #47, #48, rb-4
+    let a = 47;
+    let b = 48;
+    let address = vm.relative_address(-4, 705)?;
+    vm.write(address, (a == b) as intcode::Value, 709)?;
+    // 709: jt   [1013], #715
+    if vm.read(1013) != 0 {
+        return Ok(715);
+    }
+    // 712: jf   #0, #721
+    // 721: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 721)?, 725)?;
+    // 725: arb  #-14
+    vm.adjust_relative_base(-14, 725)?;
+    // 727: add  rb+0, #0, [63]
+    let a = vm.read_relative(0, 727)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 727)?, 731)?;
+    // 731: eq   [63], #32, [63]
+    let a = vm.read(63);
+    let b = 32;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 735)?;
+    // 735: jt   [63], #741
+    if vm.read(63) != 0 {
+        return Ok(741);
+    }
+    // 738: jf   #0, #747
+    // 747: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 747)?, 751)?;
+    // 751: arb  #4
+    vm.adjust_relative_base(4, 751)?;
+    // 753: add  rb+2, #0, [63]
+    let a = vm.read_relative(2, 753)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 753)?, 757)?;
+    // 757: eq   [63], #26, [63]
+    let a = vm.read(63);
+    let b = 26;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 761)?;
+    // 761: jt   [63], #769
+    if vm.read(63) != 0 {
+        return Ok(769);
+    }
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_699(vm: &mut Vm) -> Result<Address, Exit> {
+    // 699: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 699)?, 703)?;
+    // 703: arb  #7
+    vm.adjust_relative_base(7, 703)?;
+    // 705: eq   #47, #48, rb-4
+    let a = 47;
+    let b = 48;
+    let address = vm.relative_address(-4, 705)?;
+    vm.write(address, (a == b) as intcode::Value, 709)?;
+    // 709: jt   [1013], #715
+    if vm.read(1013) != 0 {
+        return Ok(715);
+    }
+    // 712: jf   #0, #721
+    // 721: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 721)?, 725)?;
+    // 725: arb  #-14
+    vm.adjust_relative_base(-14, 725)?;
+    // 727: add  rb+0, #0, [63]
+    let a = vm.read_relative(0, 727)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 727)?, 731)?;
+    // 731: eq   [63], #32, [63]
+    let a = vm.read(63);
+    let b = 32;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 735)?;
+    // 735: jt   [63], #741
+    if vm.read(63) != 0 {
+        return Ok(741);
+    }
+    // 738: jf   #0, #747
+    // 747: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 747)?, 751)?;
+    // 751: arb  #4
+    vm.adjust_relative_base(4, 751)?;
+    // 753: add  rb+2, #0, [63]
+    let a = vm.read_relative(2, 753)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 753)?, 757)?;
+    // 757: eq   [63], #26, [63]
+    let a = vm.read(63);
+    let b = 26;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 761)?;
+    // 761: jt   [63], #769
+    if vm.read(63) != 0 {
+        return Ok(769);
+    }
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_712(vm: &mut Vm) -> Result<Address, Exit> {
+    // 712: jf   #0, #721
+    // 721: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 721)?, 725)?;
+    // 725: arb  #-14
+    vm.adjust_relative_base(-14, 725)?;
+    // 727: add  rb+0, #0, [63]
+    let a = vm.read_relative(0, 727)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 727)?, 731)?;
+    // 731: eq   [63], #32, [63]
+    let a = vm.read(63);
+    let b = 32;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 735)?;
+    // 735: jt   [63], #741
+    if vm.read(63) != 0 {
+        return Ok(741);
+    }
+    // 738: jf   #0, #747
+    // 747: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 747)?, 751)?;
+    // 751: arb  #4
+    vm.adjust_relative_base(4, 751)?;
+    // 753: add  rb+2, #0, [63]
+    let a = vm.read_relative(2, 753)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 753)?, 757)?;
+    // 757: eq   [63], #26, [63]
+    let a = vm.read(63);
+    let b = 26;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 761)?;
+    // 761: jt   [63], #769
+    if vm.read(63) != 0 {
+        return Ok(769);
+    }
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_715(vm: &mut Vm) -> Result<Address, Exit> {
+    // 715: out  [705]
+    Err(Exit::Output(vm.read(705), 717))
+}
+
+fn block_717(vm: &mut Vm) -> Result<Address, Exit> {
+    // 717: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 717)?, 721)?;
+    // 721: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 721)?, 725)?;
+    // 725: arb  #-14
+    vm.adjust_relative_base(-14, 725)?;
+    // 727: add  rb+0, #0, [63]
+    let a = vm.read_relative(0, 727)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 727)?, 731)?;
+    // 731: eq   [63], #32, [63]
+    let a = vm.read(63);
+    let b = 32;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 735)?;
+    // 735: jt   [63], #741
+    if vm.read(63) != 0 {
+        return Ok(741);
+    }
+    // 738: jf   #0, #747
+    // 747: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 747)?, 751)?;
+    // 751: arb  #4
+    vm.adjust_relative_base(4, 751)?;
+    // 753: add  rb+2, #0, [63]
+    let a = vm.read_relative(2, 753)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 753)?, 757)?;
+    // 757: eq   [63], #26, [63]
+    let a = vm.read(63);
+    let b = 26;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 761)?;
+    // 761: jt   [63], #769
+    if vm.read(63) != 0 {
+        return Ok(769);
+    }
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_721(vm: &mut Vm) -> Result<Address, Exit> {
+    // 721: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 721)?, 725)?;
+    // 725: arb  #-14
+    vm.adjust_relative_base(-14, 725)?;
+    // 727: add  rb+0, #0, [63]
+    let a = vm.read_relative(0, 727)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 727)?, 731)?;
+    // 731: eq   [63], #32, [63]
+    let a = vm.read(63);
+    let b = 32;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 735)?;
+    // 735: jt   [63], #741
+    if vm.read(63) != 0 {
+        return Ok(741);
+    }
+    // 738: jf   #0, #747
+    // 747: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 747)?, 751)?;
+    // 751: arb  #4
+    vm.adjust_relative_base(4, 751)?;
+    // 753: add  rb+2, #0, [63]
+    let a = vm.read_relative(2, 753)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 753)?, 757)?;
+    // 757: eq   [63], #26, [63]
+    let a = vm.read(63);
+    let b = 26;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 761)?;
+    // 761: jt   [63], #769
+    if vm.read(63) != 0 {
+        return Ok(769);
+    }
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_738(vm: &mut Vm) -> Result<Address, Exit> {
+    // 738: jf   #0, #747
+    // 747: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 747)?, 751)?;
+    // 751: arb  #4
+    vm.adjust_relative_base(4, 751)?;
+    // 753: add  rb+2, #0, [63]
+    let a = vm.read_relative(2, 753)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 753)?, 757)?;
+    // 757: eq   [63], #26, [63]
+    let a = vm.read(63);
+    let b = 26;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 761)?;
+    // 761: jt   [63], #769
+    if vm.read(63) != 0 {
+        return Ok(769);
+    }
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_741(vm: &mut Vm) -> Result<Address, Exit> {
+    // 741: out  [727]
+    Err(Exit::Output(vm.read(727), 743))
+}
+
+fn block_743(vm: &mut Vm) -> Result<Address, Exit> {
+    // 743: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 743)?, 747)?;
+    // 747: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 747)?, 751)?;
+    // 751: arb  #4
+    vm.adjust_relative_base(4, 751)?;
+    // 753: add  rb+2, #0, [63]
+    let a = vm.read_relative(2, 753)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 753)?, 757)?;
+    // 757: eq   [63], #26, [63]
+    let a = vm.read(63);
+    let b = 26;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 761)?;
+    // 761: jt   [63], #769
+    if vm.read(63) != 0 {
+        return Ok(769);
+    }
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_747(vm: &mut Vm) -> Result<Address, Exit> {
+    // 747: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 747)?, 751)?;
+    // 751: arb  #4
+    vm.adjust_relative_base(4, 751)?;
+    // 753: add  rb+2, #0, [63]
+    let a = vm.read_relative(2, 753)?;
+    let b = 0;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 753)?, 757)?;
+    // 757: eq   [63], #26, [63]
+    let a = vm.read(63);
+    let b = 26;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 761)?;
+    // 761: jt   [63], #769
+    if vm.read(63) != 0 {
+        return Ok(769);
+    }
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_764(vm: &mut Vm) -> Result<Address, Exit> {
+    // 764: out  [753]
+    Err(Exit::Output(vm.read(753), 766))
+}
+
+fn block_766(vm: &mut Vm) -> Result<Address, Exit> {
+    // 766: jt   #1, #773
+    // 773: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 773)?, 777)?;
+    // 777: arb  #5
+    vm.adjust_relative_base(5, 777)?;
+    // 779: lt   rb-4, #22, [63]
+    let a = vm.read_relative(-4, 779)?;
+    let b = 22;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 783)?;
+    // 783: jt   [63], #795
+    if vm.read(63) != 0 {
+        return Ok(795);
+    }
+    // 786: out  [779]
+    Err(Exit::Output(vm.read(779), 788))
+}
+
+fn block_769(vm: &mut Vm) -> Result<Address, Exit> {
+    // 769: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 769)?, 773)?;
+    // 773: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 773)?, 777)?;
+    // 777: arb  #5
+    vm.adjust_relative_base(5, 777)?;
+    // 779: lt   rb-4, #22, [63]
+    let a = vm.read_relative(-4, 779)?;
+    let b = 22;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 783)?;
+    // 783: jt   [63], #795
+    if vm.read(63) != 0 {
+        return Ok(795);
+    }
+    // 786: out  [779]
+    Err(Exit::Output(vm.read(779), 788))
+}
+
+fn block_773(vm: &mut Vm) -> Result<Address, Exit> {
+    // 773: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 773)?, 777)?;
+    // 777: arb  #5
+    vm.adjust_relative_base(5, 777)?;
+    // 779: lt   rb-4, #22, [63]
+    let a = vm.read_relative(-4, 779)?;
+    let b = 22;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 783)?;
+    // 783: jt   [63], #795
+    if vm.read(63) != 0 {
+        return Ok(795);
+    }
+    // 786: out  [779]
+    Err(Exit::Output(vm.read(779), 788))
+}
+
+fn block_786(vm: &mut Vm) -> Result<Address, Exit> {
+    // 786: out  [779]
+    Err(Exit::Output(vm.read(779), 788))
+}
+
+fn block_788(vm: &mut Vm) -> Result<Address, Exit> {
+    // 788: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 788)?, 792)?;
+    // 792: jf   #0, #795
+    // 795: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 795)?, 799)?;
+    // 799: arb  #2
+    vm.adjust_relative_base(2, 799)?;
+    // 801: add  #0, rb-9, [63]
+    let a = 0;
+    let b = vm.read_relative(-9, 801)?;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 801)?, 805)?;
+    // 805: eq   [63], #34, [63]
+    let a = vm.read(63);
+    let b = 34;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 809)?;
+    // 809: jt   [63], #819
+    if vm.read(63) != 0 {
+        return Ok(819);
+    }
+    // 812: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 812)?, 816)?;
+    // 816: jf   #0, #821
+    // 821: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 821)?, 825)?;
+    // 825: arb  #-11
+    vm.adjust_relative_base(-11, 825)?;
+    // 827: mul  rb+1, #1, [63]
+    let a = vm.read_relative(1, 827)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 827)?, 831)?;
+    // 831: eq   [63], #38, [63]
+    let a = vm.read(63);
+    let b = 38;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 835)?;
+    // 835: jt   [63], #841
+    if vm.read(63) != 0 {
+        return Ok(841);
+    }
+    // 838: jt   #1, #847
+    // 847: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 847)?, 851)?;
+    // 851: arb  #21
+    vm.adjust_relative_base(21, 851)?;
+    // 853: jf   rb-4, #865
+    if vm.read_relative(-4, 853)? == 0 {
+        return Ok(865);
+    }
+    // 856: out  [853]
+    Err(Exit::Output(vm.read(853), 858))
+}
+
+fn block_795(vm: &mut Vm) -> Result<Address, Exit> {
+    // 795: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 795)?, 799)?;
+    // 799: arb  #2
+    vm.adjust_relative_base(2, 799)?;
+    // 801: add  #0, rb-9, [63]
+    let a = 0;
+    let b = vm.read_relative(-9, 801)?;
+    let address = 63;
+    vm.write(address, vm.add(a, b, 801)?, 805)?;
+    // 805: eq   [63], #34, [63]
+    let a = vm.read(63);
+    let b = 34;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 809)?;
+    // 809: jt   [63], #819
+    if vm.read(63) != 0 {
+        return Ok(819);
+    }
+    // 812: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 812)?, 816)?;
+    // 816: jf   #0, #821
+    // 821: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 821)?, 825)?;
+    // 825: arb  #-11
+    vm.adjust_relative_base(-11, 825)?;
+    // 827: mul  rb+1, #1, [63]
+    let a = vm.read_relative(1, 827)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 827)?, 831)?;
+    // 831: eq   [63], #38, [63]
+    let a = vm.read(63);
+    let b = 38;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 835)?;
+    // 835: jt   [63], #841
+    if vm.read(63) != 0 {
+        return Ok(841);
+    }
+    // 838: jt   #1, #847
+    // 847: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 847)?, 851)?;
+    // 851: arb  #21
+    vm.adjust_relative_base(21, 851)?;
+    // 853: jf   rb-4, #865
+    if vm.read_relative(-4, 853)? == 0 {
+        return Ok(865);
+    }
+    // 856: out  [853]
+    Err(Exit::Output(vm.read(853), 858))
+}
+
+fn block_812(vm: &mut Vm) -> Result<Address, Exit> {
+    // 812: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 812)?, 816)?;
+    // 816: jf   #0, #821
+    // 821: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 821)?, 825)?;
+    // 825: arb  #-11
+    vm.adjust_relative_base(-11, 825)?;
+    // 827: mul  rb+1, #1, [63]
+    let a = vm.read_relative(1, 827)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 827)?, 831)?;
+    // 831: eq   [63], #38, [63]
+    let a = vm.read(63);
+    let b = 38;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 835)?;
+    // 835: jt   [63], #841
+    if vm.read(63) != 0 {
+        return Ok(841);
+    }
+    // 838: jt   #1, #847
+    // 847: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 847)?, 851)?;
+    // 851: arb  #21
+    vm.adjust_relative_base(21, 851)?;
+    // 853: jf   rb-4, #865
+    if vm.read_relative(-4, 853)? == 0 {
+        return Ok(865);
+    }
+    // 856: out  [853]
+    Err(Exit::Output(vm.read(853), 858))
+}
+
+fn block_819(vm: &mut Vm) -> Result<Address, Exit> {
+    // 819: out  [801]
+    Err(Exit::Output(vm.read(801), 821))
+}
+
+fn block_821(vm: &mut Vm) -> Result<Address, Exit> {
+    // 821: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 821)?, 825)?;
+    // 825: arb  #-11
+    vm.adjust_relative_base(-11, 825)?;
+    // 827: mul  rb+1, #1, [63]
+    let a = vm.read_relative(1, 827)?;
+    let b = 1;
+    let address = 63;
+    vm.write(address, vm.multiply(a, b, 827)?, 831)?;
+    // 831: eq   [63], #38, [63]
+    let a = vm.read(63);
+    let b = 38;
+    let address = 63;
+    vm.write(address, (a == b) as intcode::Value, 835)?;
+    // 835: jt   [63], #841
+    if vm.read(63) != 0 {
+        return Ok(841);
+    }
+    // 838: jt   #1, #847
+    // 847: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 847)?, 851)?;
+    // 851: arb  #21
+    vm.adjust_relative_base(21, 851)?;
+    // 853: jf   rb-4, #865
+    if vm.read_relative(-4, 853)? == 0 {
+        return Ok(865);
+    }
+    // 856: out  [853]
+    Err(Exit::Output(vm.read(853), 858))
+}
+
+fn block_838(vm: &mut Vm) -> Result<Address, Exit> {
+    // 838: jt   #1, #847
+    // 847: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 847)?, 851)?;
+    // 851: arb  #21
+    vm.adjust_relative_base(21, 851)?;
+    // 853: jf   rb-4, #865
+    if vm.read_relative(-4, 853)? == 0 {
+        return Ok(865);
+    }
+    // 856: out  [853]
+    Err(Exit::Output(vm.read(853), 858))
+}
+
+fn block_841(vm: &mut Vm) -> Result<Address, Exit> {
+    // 841: out  [827]
+    Err(Exit::Output(vm.read(827), 843))
+}
+
+fn block_843(vm: &mut Vm) -> Result<Address, Exit> {
+    // 843: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 843)?, 847)?;
+    // 847: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 847)?, 851)?;
+    // 851: arb  #21
+    vm.adjust_relative_base(21, 851)?;
+    // 853: jf   rb-4, #865
+    if vm.read_relative(-4, 853)? == 0 {
+        return Ok(865);
+    }
+    // 856: out  [853]
+    Err(Exit::Output(vm.read(853), 858))
+}
+
+fn block_847(vm: &mut Vm) -> Result<Address, Exit> {
+    // 847: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 847)?, 851)?;
+    // 851: arb  #21
+    vm.adjust_relative_base(21, 851)?;
+    // 853: jf   rb-4, #865
+    if vm.read_relative(-4, 853)? == 0 {
+        return Ok(865);
+    }
+    // 856: out  [853]
+    Err(Exit::Output(vm.read(853), 858))
+}
+
+fn block_856(vm: &mut Vm) -> Result<Address, Exit> {
+    // 856: out  [853]
+    Err(Exit::Output(vm.read(853), 858))
+}
+
+fn block_858(vm: &mut Vm) -> Result<Address, Exit> {
+    // 858: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 858)?, 862)?;
+    // 862: jt   #1, #865
+    // 865: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 865)?, 869)?;
+    // 869: arb  #3
+    vm.adjust_relative_base(3, 869)?;
+    // 871: jf   rb-6, #877
+    if vm.read_relative(-6, 871)? == 0 {
+        return Ok(877);
+    }
+    // 874: jt   #1, #883
+    // 883: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 883)?, 887)?;
+    // 887: arb  #6
+    vm.adjust_relative_base(6, 887)?;
+    // 889: jf   #0, rb-6
+    vm.jump(vm.read_relative(-6, 889)?, 889)
+}
+
+fn block_865(vm: &mut Vm) -> Result<Address, Exit> {
+    // 865: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 865)?, 869)?;
+    // 869: arb  #3
+    vm.adjust_relative_base(3, 869)?;
+    // 871: jf   rb-6, #877
+    if vm.read_relative(-6, 871)? == 0 {
+        return Ok(877);
+    }
+    // 874: jt   #1, #883
+    // 883: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 883)?, 887)?;
+    // 887: arb  #6
+    vm.adjust_relative_base(6, 887)?;
+    // 889: jf   #0, rb-6
+    vm.jump(vm.read_relative(-6, 889)?, 889)
+}
+
+fn block_874(vm: &mut Vm) -> Result<Address, Exit> {
+    // 874: jt   #1, #883
+    // 883: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 883)?, 887)?;
+    // 887: arb  #6
+    vm.adjust_relative_base(6, 887)?;
+    // 889: jf   #0, rb-6
+    vm.jump(vm.read_relative(-6, 889)?, 889)
+}
+
+fn block_877(vm: &mut Vm) -> Result<Address, Exit> {
+    // 877: out  [871]
+    Err(Exit::Output(vm.read(871), 879))
+}
+
+fn block_879(vm: &mut Vm) -> Result<Address, Exit> {
+    // 879: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 879)?, 883)?;
+    // 883: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 883)?, 887)?;
+    // 887: arb  #6
+    vm.adjust_relative_base(6, 887)?;
+    // 889: jf   #0, rb-6
+    vm.jump(vm.read_relative(-6, 889)?, 889)
+}
+
+fn block_883(vm: &mut Vm) -> Result<Address, Exit> {
+    // 883: mul  [64], #2, [64]
+    let a = vm.read(64);
+    let b = 2;
+    let address = 64;
+    vm.write(address, vm.multiply(a, b, 883)?, 887)?;
+    // 887: arb  #6
+    vm.adjust_relative_base(6, 887)?;
+    // 889: jf   #0, rb-6
+    vm.jump(vm.read_relative(-6, 889)?, 889)
+}
+
+fn block_892(vm: &mut Vm) -> Result<Address, Exit> {
+    // 892: add  [64], #1, [64]
+    let a = vm.read(64);
+    let b = 1;
+    let address = 64;
+    vm.write(address, vm.add(a, b, 892)?, 896)?;
+    // 896: jt   #1, #901
+    // 901: out  [64]
+    Err(Exit::Output(vm.read(64), 903))
+}
+
+fn block_899(vm: &mut Vm) -> Result<Address, Exit> {
+    // 899: out  [889]
+    Err(Exit::Output(vm.read(889), 901))
+}
+
+fn block_901(vm: &mut Vm) -> Result<Address, Exit> {
+    // 901: out  [64]
+    Err(Exit::Output(vm.read(64), 903))
+}
+
+fn block_903(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 903: hlt
+    Err(Exit::Interpret(903))
+}
+
+fn block_904(vm: &mut Vm) -> Result<Address, Exit> {
+    // 904: add  #0, #27, rb+1
+    let a = 0;
+    let b = 27;
+    let address = vm.relative_address(1, 904)?;
+    vm.write(address, vm.add(a, b, 904)?, 908)?;
+    // 908: add  #915, #0, rb+0
+    let a = 915;
+    let b = 0;
+    let address = vm.relative_address(0, 908)?;
+    vm.write(address, vm.add(a, b, 908)?, 912)?;
+    // 912: jf   #0, #922
+    // 922: arb  #3
+    vm.adjust_relative_base(3, 922)?;
+    // 924: lt   rb-2, #3, [63]
+    let a = vm.read_relative(-2, 924)?;
+    let b = 3;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 928)?;
+    // 928: jt   [63], #964
+    if vm.read(63) != 0 {
+        return Ok(964);
+    }
+    // 931: add  rb-2, #-1, rb+1
+    let a = vm.read_relative(-2, 931)?;
+    let b = -1;
+    let address = vm.relative_address(1, 931)?;
+    vm.write(address, vm.add(a, b, 931)?, 935)?;
+    // 935: mul  #942, #1, rb+0
+    let a = 942;
+    let b = 1;
+    let address = vm.relative_address(0, 935)?;
+    vm.write(address, vm.multiply(a, b, 935)?, 939)?;
+    // 939: jf   #0, #922
+    Ok(922)
+}
+
+fn block_915(vm: &mut Vm) -> Result<Address, Exit> {
+    // 915: add  rb+1, #23692, rb+1
+    let a = vm.read_relative(1, 915)?;
+    let b = 23692;
+    let address = vm.relative_address(1, 915)?;
+    vm.write(address, vm.add(a, b, 915)?, 919)?;
+    // 919: out  rb+1
+    Err(Exit::Output(vm.read_relative(1, 919)?, 921))
+}
+
+fn block_921(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 921: hlt
+    Err(Exit::Interpret(921))
+}
+
+fn block_922(vm: &mut Vm) -> Result<Address, Exit> {
+    loop {
+        // 922: arb  #3
+        vm.adjust_relative_base(3, 922)?;
+        // 924: lt   rb-2, #3, [63]
+        let a = vm.read_relative(-2, 924)?;
+        let b = 3;
+        let address = 63;
+        vm.write(address, (a < b) as intcode::Value, 928)?;
+        // 928: jt   [63], #964
+        if vm.read(63) != 0 {
+            return Ok(964);
+        }
+        // 931: add  rb-2, #-1, rb+1
+        let a = vm.read_relative(-2, 931)?;
+        let b = -1;
+        let address = vm.relative_address(1, 931)?;
+        vm.write(address, vm.add(a, b, 931)?, 935)?;
+        // 935: mul  #942, #1, rb+0
+        let a = 942;
+        let b = 1;
+        let address = vm.relative_address(0, 935)?;
+        vm.write(address, vm.multiply(a, b, 935)?, 939)?;
+        // 939: jf   #0, #922
+    }
+}
+
+fn block_931(vm: &mut Vm) -> Result<Address, Exit> {
+    loop {
+        // 931: add  rb-2, #-1, rb+1
+        let a = vm.read_relative(-2, 931)?;
+        let b = -1;
+        let address = vm.relative_address(1, 931)?;
+        vm.write(address, vm.add(a, b, 931)?, 935)?;
+        // 935: mul  #942, #1, rb+0
+        let a = 942;
+        let b = 1;
+        let address = vm.relative_address(0, 935)?;
+        vm.write(address, vm.multiply(a, b, 935)?, 939)?;
+        // 939: jf   #0, #922
+        // 922: arb  #3
+        vm.adjust_relative_base(3, 922)?;
+        // 924: lt   rb-2, #3, [63]
+        let a = vm.read_relative(-2, 924)?;
+        let b = 3;
+        let address = 63;
+        vm.write(address, (a < b) as intcode::Value, 928)?;
+        // 928: jt   [63], #964
+        if vm.read(63) != 0 {
+            return Ok(964);
+        }
+    }
+}
+
+fn block_942(vm: &mut Vm) -> Result<Address, Exit> {
+    // 942: mul  rb+1, #1, rb-1
+    let a = vm.read_relative(1, 942)?;
+    let b = 1;
+    let address = vm.relative_address(-1, 942)?;
+    vm.write(address, vm.multiply(a, b, 942)?, 946)?;
+    // 946: add  rb-2, #-3, rb+1
+    let a = vm.read_relative(-2, 946)?;
+    let b = -3;
+    let address = vm.relative_address(1, 946)?;
+    vm.write(address, vm.add(a, b, 946)?, 950)?;
+    // 950: add  #0, #957, rb+0
+    let a = 0;
+    let b = 957;
+    let address = vm.relative_address(0, 950)?;
+    vm.write(address, vm.add(a, b, 950)?, 954)?;
+    // 954: jf   #0, #922
+    // 922: arb  #3
+    vm.adjust_relative_base(3, 922)?;
+    // 924: lt   rb-2, #3, [63]
+    let a = vm.read_relative(-2, 924)?;
+    let b = 3;
+    let address = 63;
+    vm.write(address, (a < b) as intcode::Value, 928)?;
+    // 928: jt   [63], #964
+    if vm.read(63) != 0 {
+        return Ok(964);
+    }
+    // 931: add  rb-2, #-1, rb+1
+    let a = vm.read_relative(-2, 931)?;
+    let b = -1;
+    let address = vm.relative_address(1, 931)?;
+    vm.write(address, vm.add(a, b, 931)?, 935)?;
+    // 935: mul  #942, #1, rb+0
+    let a = 942;
+    let b = 1;
+    let address = vm.relative_address(0, 935)?;
+    vm.write(address, vm.multiply(a, b, 935)?, 939)?;
+    // 939: jf   #0, #922
+    Ok(922)
+}
+
+fn block_957(vm: &mut Vm) -> Result<Address, Exit> {
+    // 957: add  rb+1, rb-1, rb-2
+    let a = vm.read_relative(1, 957)?;
+    let b = vm.read_relative(-1, 957)?;
+    let address = vm.relative_address(-2, 957)?;
+    vm.write(address, vm.add(a, b, 957)?, 961)?;
+    // 961: jf   #0, #968
+    // 968: arb  #-3
+    vm.adjust_relative_base(-3, 968)?;
+    // 970: jf   #0, rb+0
+    vm.jump(vm.read_relative(0, 970)?, 970)
+}
+
+fn block_964(vm: &mut Vm) -> Result<Address, Exit> {
+    // 964: mul  #1, rb-2, rb-2
+    let a = 1;
+    let b = vm.read_relative(-2, 964)?;
+    let address = vm.relative_address(-2, 964)?;
+    vm.write(address, vm.multiply(a, b, 964)?, 968)?;
+    // 968: arb  #-3
+    vm.adjust_relative_base(-3, 968)?;
+    // 970: jf   #0, rb+0
+    vm.jump(vm.read_relative(0, 970)?, 970)
+}
+
+fn block_968(vm: &mut Vm) -> Result<Address, Exit> {
+    // 968: arb  #-3
+    vm.adjust_relative_base(-3, 968)?;
+    // 970: jf   #0, rb+0
+    vm.jump(vm.read_relative(0, 970)?, 970)
+}
@@ -0,0 +1,49 @@
+// Generated by intcode::translate
+
+use intcode::{
+    translate::{Exit, Translation, Vm},
+    Address,
+};
+
+#[allow(clippy::single_range_in_vec_init)]
+pub static PROGRAM: Translation = Translation {
+    memory: &[1101, 0, 99, 8, 1105, 1, 8, 0, 104, 7, 99],
+    code: &[0..7, 8..11],
+    run,
+};
+
+fn run(vm: &mut Vm, mut ip: Address) -> Exit {
+    loop {
+        let next = match ip {
+            0 => block_0(vm),
+            8 => block_8(vm),
+            10 => block_10(vm),
+            _ => return Exit::Interpret(ip),
+        };
+        match next {
+            Ok(next) => ip = next,
+            Err(exit) => return exit,
+        }
+    }
+}
+
+fn block_0(vm: &mut Vm) -> Result<Address, Exit> {
+    // 0: add  #0, #99, [8]
+    let a = 0;
+    let b = 99;
+    let address = 8;
+    vm.write(address, vm.add(a, b, 0)?, 4)?;
+    // 4: jt   #1, #8
+    // 8: out  #7
+    Err(Exit::Output(7, 10))
+}
+
+fn block_8(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 8: out  #7
+    Err(Exit::Output(7, 10))
+}
+
+fn block_10(_vm: &mut Vm) -> Result<Address, Exit> {
+    // 10: hlt
+    Err(Exit::Interpret(10))
+}
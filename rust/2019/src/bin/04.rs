@@ -90,23 +90,23 @@ mod day_4 {
 
     #[test]
     fn test_is_valid_password_part_one() {
-        assert!(is_valid_password_part_one(&vec![1, 1, 1, 1, 1, 1]));
-        assert!(is_valid_password_part_one(&vec![1, 1, 1, 1, 2, 2]));
-        assert!(is_valid_password_part_one(&vec![1, 1, 1, 1, 2, 3]));
-        assert!(is_valid_password_part_one(&vec![1, 2, 2, 3, 4, 5]));
-        assert!(!is_valid_password_part_one(&vec![2, 2, 3, 4, 5, 0]));
-        assert!(!is_valid_password_part_one(&vec![1, 2, 3, 7, 8, 9]));
+        assert!(is_valid_password_part_one(&[1, 1, 1, 1, 1, 1]));
+        assert!(is_valid_password_part_one(&[1, 1, 1, 1, 2, 2]));
+        assert!(is_valid_password_part_one(&[1, 1, 1, 1, 2, 3]));
+        assert!(is_valid_password_part_one(&[1, 2, 2, 3, 4, 5]));
+        assert!(!is_valid_password_part_one(&[2, 2, 3, 4, 5, 0]));
+        assert!(!is_valid_password_part_one(&[1, 2, 3, 7, 8, 9]));
     }
 
     #[test]
     fn test_is_valid_password_part_two() {
-        assert!(!is_valid_password_part_two(&vec![1, 1, 1, 1, 1, 1]));
-        assert!(is_valid_password_part_two(&vec![1, 1, 2, 2, 3, 3]));
-        assert!(is_valid_password_part_two(&vec![1, 1, 1, 1, 2, 2]));
-        assert!(!is_valid_password_part_two(&vec![1, 1, 1, 1, 2, 3]));
-        assert!(!is_valid_password_part_two(&vec![1, 2, 3, 4, 4, 4]));
-        assert!(is_valid_password_part_two(&vec![1, 2, 2, 3, 4, 5]));
-        assert!(!is_valid_password_part_two(&vec![2, 2, 3, 4, 5, 0]));
-        assert!(!is_valid_password_part_two(&vec![1, 2, 3, 7, 8, 9]));
+        assert!(!is_valid_password_part_two(&[1, 1, 1, 1, 1, 1]));
+        assert!(is_valid_password_part_two(&[1, 1, 2, 2, 3, 3]));
+        assert!(is_valid_password_part_two(&[1, 1, 1, 1, 2, 2]));
+        assert!(!is_valid_password_part_two(&[1, 1, 1, 1, 2, 3]));
+        assert!(!is_valid_password_part_two(&[1, 2, 3, 4, 4, 4]));
+        assert!(is_valid_password_part_two(&[1, 2, 2, 3, 4, 5]));
+        assert!(!is_valid_password_part_two(&[2, 2, 3, 4, 5, 0]));
+        assert!(!is_valid_password_part_two(&[1, 2, 3, 7, 8, 9]));
     }
 }
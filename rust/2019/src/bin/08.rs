@@ -46,7 +46,7 @@ impl Image {
         layer.iter().filter(|&&x| x == 1).count() * layer.iter().filter(|&&x| x == 2).count()
     }
 
-    fn iter(&self) -> ImageIter {
+    fn iter(&self) -> ImageIter<'_> {
         ImageIter {
             image: self,
             position: 0,
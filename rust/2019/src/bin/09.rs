@@ -1,6 +1,5 @@
 // https://adventofcode.com/2019/day/9
 
-mod intcode;
 use intcode::Program;
 
 fn main() {
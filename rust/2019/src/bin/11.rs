@@ -1,7 +1,9 @@
 // https://adventofcode.com/2019/day/11
 
-mod intcode;
-use intcode::Program;
+use intcode::{
+    device::{Bus, Control, Device},
+    Program, Value,
+};
 
 use std::{collections::HashMap, fmt};
 
@@ -15,61 +17,61 @@ enum Direction {
     Left,
 }
 
+/// A robot that paints the panel it's on and then turns, reporting the colour of each panel
 struct Painter {
-    program: Program,
     panels: HashMap<Position, u8>,
     position: Position,
     direction: Direction,
 }
 
 impl Painter {
-    fn new(input: &str, initial_color: u8) -> Self {
+    fn new(initial_color: u8) -> Self {
         let position = (0, 0);
         let mut panels = HashMap::new();
         panels.insert(position, initial_color);
 
         Self {
-            program: Program::new(input),
             panels,
             position,
             direction: Direction::Up,
         }
     }
+}
+
+impl Device for Painter {
+    // A colour to paint followed by a turn
+    fn frame_size(&self) -> usize {
+        2
+    }
 
-    fn paint(&mut self) {
+    fn output(&mut self, frame: &[Value]) -> Control {
         use Direction::*;
 
-        loop {
-            let panel_color = *self.panels.entry(self.position).or_insert(0);
-            self.program.set_input(&[panel_color as intcode::Value]);
-            if let Some(color) = self.program.next() {
-                if let Some(turn) = self.program.next() {
-                    self.panels.insert(self.position, color as u8);
-
-                    self.direction = match (turn, self.direction) {
-                        (0, Up) => Left,
-                        (0, Right) => Up,
-                        (0, Down) => Right,
-                        (0, Left) => Down,
-                        (1, Up) => Right,
-                        (1, Right) => Down,
-                        (1, Down) => Left,
-                        (1, Left) => Up,
-                        _ => panic!("Unexpected turn instruction"),
-                    };
-                    match self.direction {
-                        Up => self.position.1 += 1,
-                        Right => self.position.0 += 1,
-                        Down => self.position.1 -= 1,
-                        Left => self.position.0 -= 1,
-                    };
-                } else {
-                    break;
-                }
-            } else {
-                break;
-            }
-        }
+        let (color, turn) = (frame[0], frame[1]);
+        self.panels.insert(self.position, color as u8);
+
+        self.direction = match (turn, self.direction) {
+            (0, Up) => Left,
+            (0, Right) => Up,
+            (0, Down) => Right,
+            (0, Left) => Down,
+            (1, Up) => Right,
+            (1, Right) => Down,
+            (1, Down) => Left,
+            (1, Left) => Up,
+            _ => panic!("Unexpected turn instruction"),
+        };
+        match self.direction {
+            Up => self.position.1 += 1,
+            Right => self.position.0 += 1,
+            Down => self.position.1 -= 1,
+            Left => self.position.0 -= 1,
+        };
+        Control::Continue
+    }
+
+    fn input(&mut self) -> Option<Value> {
+        Some(*self.panels.entry(self.position).or_insert(0) as Value)
     }
 }
 
@@ -100,22 +102,44 @@ impl fmt::Display for Painter {
     }
 }
 
+fn paint(initial_color: u8) -> Painter {
+    let mut bus = Bus::new(
+        Program::new(include_str!("input/11")),
+        Painter::new(initial_color),
+    );
+    bus.run().unwrap();
+    bus.into_parts().1
+}
+
 fn main() {
-    let input = include_str!("input/11");
-    {
-        let mut painter = Painter::new(input, 0);
-        painter.paint();
-        println!(
-            "Number of panels painted when starting on a black panel: {}",
-            painter.panels.len()
-        );
-    }
-    {
-        let mut painter = Painter::new(input, 1);
-        painter.paint();
-        println!(
-            "Result of painting after starting with white panel:\n{}",
-            painter
+    println!(
+        "Number of panels painted when starting on a black panel: {}",
+        paint(0).panels.len()
+    );
+    println!(
+        "Result of painting after starting with white panel:\n{}",
+        paint(1)
+    );
+}
+
+#[cfg(test)]
+mod day_11 {
+    use {super::*, intcode::device::replay};
+
+    #[test]
+    fn test_example() {
+        let mut painter = Painter::new(0);
+        assert_eq!(Some(0), painter.input());
+        replay(&mut painter, &[1, 0, 0, 0, 1, 0, 1, 0]).unwrap();
+        // Back at the start, which is now white
+        assert_eq!((0, 0), painter.position);
+        assert_eq!(Some(1), painter.input());
+
+        replay(&mut painter, &[0, 1, 1, 0, 1, 0]).unwrap();
+        assert_eq!(6, painter.panels.len());
+        assert_eq!(
+            4,
+            painter.panels.values().filter(|&&color| color == 1).count()
         );
     }
 }
@@ -1,9 +1,10 @@
 // https://adventofcode.com/2019/day/13
 
-mod intcode;
-
 use {
-    intcode::Program,
+    intcode::{
+        device::{Bus, Control, Device},
+        Program, Value,
+    },
     std::{
         collections::HashSet,
         io::{self, Write},
@@ -31,38 +32,70 @@ fn move_cursor(x: i64, y: i64) {
     print!("\x1b[{};{}f", y, x);
 }
 
-fn main() {
-    clear_screen();
-    hide_cursor();
+/// An arcade cabinet that draws tiles and scores, with a joystick that follows the ball
+#[derive(Default)]
+struct Arcade {
+    paddle_x: i64,
+    ball_x: i64,
+    score: Value,
+    game_playing: bool,
+    blocks: HashSet<(i64, i64)>,
+    initial_block_count: usize,
+}
 
-    let mut program = Program::new(include_str!("input/13"));
-    program.write(0, 2);
-    program.set_input(&[0]);
-
-    let mut paddle_x = 0;
-    let mut ball_x = 0;
-    let mut game_playing = false;
-    let mut blocks = HashSet::new();
-    let mut initial_block_count = 0;
-    loop {
-        let x = program.next();
-        let y = program.next();
-        let id = program.next();
-
-        if x.is_none() || y.is_none() || id.is_none() {
-            break;
-        }
+impl Device for Arcade {
+    // The x and y coordinates of a tile followed by its id, or the score at (-1, 0)
+    fn frame_size(&self) -> usize {
+        3
+    }
 
-        let x = x.unwrap();
-        let y = y.unwrap();
-        let id = id.unwrap();
+    fn output(&mut self, frame: &[Value]) -> Control {
+        let (x, y, id) = (frame[0], frame[1], frame[2]);
 
         if x == -1 && y == 0 {
-            if !game_playing {
-                initial_block_count = blocks.len();
+            if !self.game_playing {
+                self.initial_block_count = self.blocks.len();
+            }
+            self.game_playing = true;
+            self.score = id;
+        } else {
+            match id {
+                0 => {
+                    self.blocks.remove(&(x, y));
+                }
+                1 => {}
+                2 => {
+                    self.blocks.insert((x, y));
+                }
+                3 => self.paddle_x = x,
+                4 => self.ball_x = x,
+                _ => panic!("Unexpected tile id: {id}"),
             }
-            game_playing = true;
+        }
+
+        Control::Continue
+    }
+
+    fn input(&mut self) -> Option<Value> {
+        // Tilt the joystick towards the ball
+        Some((self.ball_x - self.paddle_x).signum())
+    }
+}
+
+/// Draws the arcade's output to the terminal as the game is played
+struct Screen(Arcade);
+
+impl Device for Screen {
+    fn frame_size(&self) -> usize {
+        self.0.frame_size()
+    }
+
+    fn output(&mut self, frame: &[Value]) -> Control {
+        let control = self.0.output(frame);
+        let arcade = &self.0;
+        let (x, y, id) = (frame[0], frame[1], frame[2]);
 
+        if x == -1 && y == 0 {
             move_cursor(4, 1);
             clear_line();
             print!("Score: {}", id);
@@ -70,52 +103,63 @@ fn main() {
             move_cursor(25, 1);
             print!(
                 "Blocks: {}/{}",
-                initial_block_count - blocks.len(),
-                initial_block_count
+                arcade.initial_block_count - arcade.blocks.len(),
+                arcade.initial_block_count
             );
         } else {
-            let mut update_input = false;
-
             move_cursor(x + 1, y + 2);
             match id {
-                0 => {
-                    print!(" ");
-                    blocks.remove(&(x, y));
-                }
                 1 => print!("█"),
-                2 => {
-                    print!("▒");
-                    blocks.insert((x, y));
-                }
-                3 => {
-                    print!("═");
-                    paddle_x = x;
-                    update_input = true;
-                }
-                4 => {
-                    print!("○");
-                    ball_x = x;
-                    update_input = true;
-                }
-                _ => panic!(),
-            }
-
-            if update_input {
-                program.set_input(&[match (paddle_x, ball_x) {
-                    (p, b) if p > b => -1,
-                    (p, b) if p < b => 1,
-                    _ => 0,
-                }]);
+                2 => print!("▒"),
+                3 => print!("═"),
+                4 => print!("○"),
+                _ => print!(" "),
             }
         }
 
         io::stdout().flush().unwrap();
 
-        if game_playing {
+        if arcade.game_playing {
             thread::sleep(time::Duration::from_millis(2));
         }
+        control
     }
 
+    fn input(&mut self) -> Option<Value> {
+        self.0.input()
+    }
+}
+
+fn main() {
+    clear_screen();
+    hide_cursor();
+
+    let mut program = Program::new(include_str!("input/13"));
+    // Insert quarters to play for free
+    program.poke_range(0, &[2]);
+    Bus::new(program, Screen(Arcade::default())).run().unwrap();
+
     move_cursor(0, 26);
     show_cursor();
 }
+
+#[cfg(test)]
+mod day_13 {
+    use {super::*, intcode::device::replay};
+
+    #[test]
+    fn test_recorded_output() {
+        let mut arcade = Arcade::default();
+        replay(&mut arcade, &[1, 2, 3, 6, 5, 4, 3, 3, 2, 4, 3, 2]).unwrap();
+        assert_eq!((6, 1), (arcade.ball_x, arcade.paddle_x));
+        assert_eq!(Some(1), arcade.input());
+        assert_eq!(2, arcade.blocks.len());
+
+        // The score starts the game, then a block is broken and the ball moves left
+        replay(&mut arcade, &[-1, 0, 12, 3, 3, 0, 0, 5, 4, -1, 0, 50]).unwrap();
+        assert!(arcade.game_playing);
+        assert_eq!(50, arcade.score);
+        assert_eq!((2, 1), (arcade.initial_block_count, arcade.blocks.len()));
+        assert_eq!(Some(-1), arcade.input());
+    }
+}
@@ -1,7 +1,8 @@
-mod intcode;
-
 use {
-    intcode::Program,
+    intcode::{
+        device::{Bus, Control, Device},
+        Program, Value,
+    },
     std::{collections::HashMap, fmt, thread, time},
 };
 
@@ -131,13 +132,13 @@ impl Room {
 
     fn direction_of_unknown_space(&self, p: Position) -> Option<Direction> {
         use Direction::*;
-        if self.grid.get(&position_in_direction(p, North)).is_none() {
+        if !self.grid.contains_key(&position_in_direction(p, North)) {
             Some(North)
-        } else if self.grid.get(&position_in_direction(p, East)).is_none() {
+        } else if !self.grid.contains_key(&position_in_direction(p, East)) {
             Some(East)
-        } else if self.grid.get(&position_in_direction(p, South)).is_none() {
+        } else if !self.grid.contains_key(&position_in_direction(p, South)) {
             Some(South)
-        } else if self.grid.get(&position_in_direction(p, West)).is_none() {
+        } else if !self.grid.contains_key(&position_in_direction(p, West)) {
             Some(West)
         } else {
             None
@@ -187,44 +188,53 @@ impl fmt::Display for Room {
     }
 }
 
-fn main() {
-    use Direction::*;
-
-    clear_screen();
-    hide_cursor();
+/// A repair droid that explores the room, backtracking once there's no unknown space around it
+struct Droid {
+    room: Room,
+    direction: Direction,
+    journey_back: Vec<Direction>,
+    backtracking: bool,
+    distance_to_oxygen_system: usize,
+}
 
-    let mut program = Program::new(include_str!("input/15"));
+impl Droid {
+    fn new() -> Self {
+        Self {
+            room: Room::new(),
+            direction: Direction::North,
+            journey_back: Vec::new(),
+            backtracking: false,
+            distance_to_oxygen_system: 0,
+        }
+    }
+}
 
-    let mut room = Room::new();
-    let mut direction = North;
-    let mut journey_back = Vec::new();
-    let mut backtracking = false;
-    let mut distance_to_oxygen_system = 0;
+impl Device for Droid {
+    // The status of each movement command
+    fn frame_size(&self) -> usize {
+        1
+    }
 
-    loop {
-        program.set_input(&[match direction {
-            North => 1,
-            East => 4,
-            South => 2,
-            West => 3,
-        }]);
+    fn output(&mut self, frame: &[Value]) -> Control {
+        use Direction::*;
 
-        let target = position_in_direction(room.droid.unwrap(), direction);
+        let room = &mut self.room;
+        let target = position_in_direction(room.droid.unwrap(), self.direction);
 
-        let moved = match program.next() {
-            Some(0) => {
+        let moved = match frame[0] {
+            0 => {
                 // Hit wall
                 room.set_wall(target);
                 false
             }
-            Some(1) => {
+            1 => {
                 // Moved one step in direction
                 true
             }
-            Some(2) => {
+            2 => {
                 // Moved in direction and found oxygen system
                 room.set_oxygen_system(target);
-                distance_to_oxygen_system = journey_back.len();
+                self.distance_to_oxygen_system = self.journey_back.len();
                 true
             }
             _ => panic!("Unexpected program output"),
@@ -232,8 +242,8 @@ fn main() {
 
         if moved {
             room.move_droid(target);
-            if !backtracking {
-                journey_back.push(match direction {
+            if !self.backtracking {
+                self.journey_back.push(match self.direction {
                     North => South,
                     South => North,
                     East => West,
@@ -243,19 +253,64 @@ fn main() {
         }
 
         if let Some(d) = room.direction_of_unknown_space(room.droid.unwrap()) {
-            direction = d;
-            backtracking = false;
-        } else if let Some(d) = journey_back.pop() {
-            direction = d;
-            backtracking = true;
+            self.direction = d;
+            self.backtracking = false;
+        } else if let Some(d) = self.journey_back.pop() {
+            self.direction = d;
+            self.backtracking = true;
         } else {
             // Nowhere left to go
-            break;
+            return Control::Stop;
         }
 
-        thread::sleep(time::Duration::from_millis(2));
-        println!("\n{}", room);
+        Control::Continue
+    }
+
+    fn input(&mut self) -> Option<Value> {
+        use Direction::*;
+
+        Some(match self.direction {
+            North => 1,
+            East => 4,
+            South => 2,
+            West => 3,
+        })
+    }
+}
+
+/// Draws the room to the terminal after each of the droid's moves
+struct Screen(Droid);
+
+impl Device for Screen {
+    fn frame_size(&self) -> usize {
+        self.0.frame_size()
+    }
+
+    fn output(&mut self, frame: &[Value]) -> Control {
+        let control = self.0.output(frame);
+        if control == Control::Continue {
+            thread::sleep(time::Duration::from_millis(2));
+            println!("\n{}", self.0.room);
+        }
+        control
+    }
+
+    fn input(&mut self) -> Option<Value> {
+        self.0.input()
     }
+}
+
+fn main() {
+    clear_screen();
+    hide_cursor();
+
+    let mut bus = Bus::new(Program::new(include_str!("input/15")), Screen(Droid::new()));
+    bus.run().unwrap();
+    let Screen(Droid {
+        mut room,
+        distance_to_oxygen_system,
+        ..
+    }) = bus.into_parts().1;
 
     room.droid = None;
     room.start_filling_room_with_oxygen();
@@ -277,3 +332,38 @@ fn main() {
 
     show_cursor();
 }
+
+#[cfg(test)]
+mod day_15 {
+    use {super::*, intcode::device::replay};
+
+    #[test]
+    fn test_walled_in() {
+        let mut droid = Droid::new();
+        assert_eq!(Some(1), droid.input());
+        assert_eq!(Ok(Control::Continue), replay(&mut droid, &[0, 0, 0]));
+        assert_eq!(Some(3), droid.input());
+        assert_eq!(Ok(Control::Stop), replay(&mut droid, &[0]));
+        assert_eq!(
+            4,
+            droid
+                .room
+                .grid
+                .values()
+                .filter(|&state| *state == GridState::Wall)
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_backtracking() {
+        // Move north into a dead end, then head back south
+        let mut droid = Droid::new();
+        replay(&mut droid, &[1, 0, 0, 0]).unwrap();
+        assert!(droid.backtracking);
+        assert_eq!(Some(2), droid.input());
+        replay(&mut droid, &[1]).unwrap();
+        assert!(droid.room.droid == Some(Position::default()));
+        assert_eq!(Some(4), droid.input());
+    }
+}
@@ -1,7 +1,8 @@
-mod intcode;
-
 use {
-    intcode::Program,
+    intcode::{
+        ascii::{AsciiOutput, AsciiProgram},
+        Program,
+    },
     std::{
         collections::{HashMap, HashSet},
         fmt::{self, Debug, Display},
@@ -228,7 +229,7 @@ struct MovementProgram {
 }
 
 impl MovementProgram {
-    fn compress_route(route: &Vec<Command>) -> Self {
+    fn compress_route(route: &[Command]) -> Self {
         // We want to split the route into 3 movement functions;
         // each function can have between 1 and 5 commands
         let function_min = 1;
@@ -323,12 +324,6 @@ impl Display for MovementProgram {
     }
 }
 
-fn string_to_intcode(s: &str) -> Vec<intcode::Value> {
-    s.chars()
-        .map(|c| c as u8 as intcode::Value)
-        .collect::<Vec<intcode::Value>>()
-}
-
 fn main() {
     use {Direction::*, Object::*};
 
@@ -400,18 +395,23 @@ fn main() {
         let compressed_route = MovementProgram::compress_route(&route);
 
         let mut program = Program::new(include_str!("input/17"));
-        program.write(0, 2);
+        // Wake the vacuum robot up
+        program.poke_range(0, &[2]);
+        let mut program = AsciiProgram::new(program);
 
         let serialized = format!("{}", compressed_route);
         println!("\nMovement commands:\n{}", serialized);
 
-        let input = string_to_intcode(&(serialized + "n\n"));
-        program.set_input(&input);
-
-        let mut result = 0;
-        for x in program {
-            result = x;
+        for line in serialized.lines() {
+            program.send_line(line);
         }
+        // Disable the continuous video feed
+        program.send_line("n");
+
+        let output = program.read_until_prompt().unwrap();
+        let Some(AsciiOutput::Value(result)) = output.last() else {
+            panic!("Missing dust collection result");
+        };
 
         println!("Dust collected: {}", result);
     }